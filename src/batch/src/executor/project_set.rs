@@ -342,4 +342,91 @@ mod tests {
             ),
         );
     }
+
+    /// The projected row id in the first output column is the 0-based position within each
+    /// input row's series; `WITH ORDINALITY` projects `row_id + 1` above the project set, so
+    /// this is the contract the 1-based ordinality numbering relies on.
+    #[tokio::test]
+    async fn test_project_set_ordinality_numbering() -> Result<()> {
+        let chunk = DataChunk::from_pretty(
+            "i
+             10
+             20",
+        );
+
+        let series = repeat(
+            LiteralExpression::new(DataType::Int32, Some(7_i32.into())).boxed(),
+            3,
+        );
+        let select_list: Vec<ProjectSetSelectItem> = vec![
+            InputRefExpression::new(DataType::Int32, 0).boxed().into(),
+            series.into(),
+        ];
+
+        let mut mock_executor = MockExecutor::new(schema_unnamed! { DataType::Int32 });
+        mock_executor.add(chunk);
+
+        let proj_executor = Box::new(ProjectSetExecutor {
+            select_list,
+            child: Box::new(mock_executor),
+            schema: schema_unnamed!(DataType::Int32, DataType::Int32),
+            identity: "ProjectSetExecutor".to_string(),
+            chunk_size: CHUNK_SIZE,
+        });
+
+        // The numbering restarts at 0 for every input row.
+        let expected = DataChunk::from_pretty(
+            "I i  i
+             0 10 7
+             1 10 7
+             2 10 7
+             0 20 7
+             1 20 7
+             2 20 7",
+        );
+        #[for_await]
+        for result_chunk in proj_executor.execute() {
+            assert_eq!(result_chunk?, expected);
+        }
+        Ok(())
+    }
+
+    /// An empty series contributes zero output rows for the input row, rather than a row of
+    /// NULLs — so `generate_series(...) WITH ORDINALITY` over an empty range yields no rows.
+    #[tokio::test]
+    async fn test_project_set_empty_series() -> Result<()> {
+        let chunk = DataChunk::from_pretty(
+            "i
+             10
+             20",
+        );
+
+        let empty_series = repeat(
+            LiteralExpression::new(DataType::Int32, Some(7_i32.into())).boxed(),
+            0,
+        );
+        let select_list: Vec<ProjectSetSelectItem> = vec![
+            InputRefExpression::new(DataType::Int32, 0).boxed().into(),
+            empty_series.into(),
+        ];
+
+        let mut mock_executor = MockExecutor::new(schema_unnamed! { DataType::Int32 });
+        mock_executor.add(chunk);
+
+        let proj_executor = Box::new(ProjectSetExecutor {
+            select_list,
+            child: Box::new(mock_executor),
+            schema: schema_unnamed!(DataType::Int32, DataType::Int32),
+            identity: "ProjectSetExecutor".to_string(),
+            chunk_size: CHUNK_SIZE,
+        });
+
+        let mut total_rows = 0;
+        #[for_await]
+        for result_chunk in proj_executor.execute() {
+            total_rows += result_chunk?.cardinality();
+        }
+        assert_eq!(total_rows, 0);
+        Ok(())
+    }
 }
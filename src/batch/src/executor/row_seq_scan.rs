@@ -14,11 +14,12 @@
 use std::ops::{Bound, Deref};
 use std::sync::Arc;
 
+use futures::future::Either;
 use futures::{pin_mut, StreamExt};
 use futures_async_stream::try_stream;
 use itertools::Itertools;
 use prometheus::Histogram;
-use risingwave_common::array::DataChunk;
+use risingwave_common::array::{ArrayImpl, DataChunk};
 use risingwave_common::bitmap::Bitmap;
 use risingwave_common::catalog::{ColumnId, Schema};
 use risingwave_common::hash::VnodeCountCompat;
@@ -26,6 +27,7 @@ use risingwave_common::row::{OwnedRow, Row};
 use risingwave_common::types::{DataType, Datum};
 use risingwave_common::util::chunk_coalesce::DataChunkBuilder;
 use risingwave_common::util::value_encoding::deserialize_datum;
+use risingwave_expr::expr::{build_from_prost, BoxedExpression};
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::{scan_range, PbScanRange};
 use risingwave_pb::common::BatchQueryEpoch;
@@ -54,9 +56,18 @@ pub struct RowSeqScanExecutor<S: StateStore> {
     table: StorageTable<S>,
     scan_ranges: Vec<ScanRange>,
     ordered: bool,
+    /// Whether to iterate the scan range backward, yielding rows in descending primary-key
+    /// order. Only set together with `ordered` and a single scan range.
+    reversed: bool,
     epoch: BatchQueryEpoch,
     limit: Option<u64>,
+    /// Whether the scan may stop reading as soon as `limit` rows have been returned. When set,
+    /// the boundary chunk is truncated so that exactly `limit` rows are emitted.
+    stop_after_limit: bool,
     as_of: Option<AsOf>,
+    /// The residual (non-key) predicate pushed down into the scan. Rows not satisfying it are
+    /// filtered out before being returned.
+    residual_filter: Option<BoxedExpression>,
 }
 
 /// Range for batch scan.
@@ -161,12 +172,15 @@ impl<S: StateStore> RowSeqScanExecutor<S> {
         table: StorageTable<S>,
         scan_ranges: Vec<ScanRange>,
         ordered: bool,
+        reversed: bool,
         epoch: BatchQueryEpoch,
         chunk_size: usize,
         identity: String,
         limit: Option<u64>,
+        stop_after_limit: bool,
         metrics: Option<BatchMetrics>,
         as_of: Option<AsOf>,
+        residual_filter: Option<BoxedExpression>,
     ) -> Self {
         Self {
             chunk_size,
@@ -175,9 +189,12 @@ impl<S: StateStore> RowSeqScanExecutor<S> {
             table,
             scan_ranges,
             ordered,
+            reversed,
             epoch,
             limit,
+            stop_after_limit,
             as_of,
+            residual_filter,
         }
     }
 }
@@ -236,18 +253,39 @@ impl BoxedExecutorBuilder for RowSeqScanExecutorBuilder {
         };
 
         let ordered = seq_scan_node.ordered;
-
-        let epoch = source.epoch;
+        let reversed = seq_scan_node.reversed;
+
+        // A backfill-pinned epoch reads the snapshot exactly at that epoch, overriding the
+        // scheduler-assigned one. The frontend never sets it together with `as_of`.
+        let epoch = match seq_scan_node.backfill_epoch {
+            Some(backfill_epoch) => BatchQueryEpoch {
+                epoch: Some(risingwave_pb::common::batch_query_epoch::Epoch::TimeTravel(
+                    backfill_epoch,
+                )),
+            },
+            None => source.epoch,
+        };
         let limit = seq_scan_node.limit;
+        let stop_after_limit = seq_scan_node.stop_after_limit;
         let as_of = seq_scan_node
             .as_of
             .as_ref()
             .map(AsOf::try_from)
             .transpose()?;
+        let residual_filter = seq_scan_node
+            .residual_filter
+            .as_ref()
+            .map(build_from_prost)
+            .transpose()?;
+        // The planner's chunk-size hint, derived from the estimated row width, overrides the
+        // configured chunk size so wide-row tables produce smaller chunks.
+        let default_chunk_size = seq_scan_node
+            .chunk_size_hint
+            .unwrap_or(source.context.get_config().developer.chunk_size as u32);
         let chunk_size = if let Some(limit) = seq_scan_node.limit {
-            (limit as u32).min(source.context.get_config().developer.chunk_size as u32)
+            (limit as u32).min(default_chunk_size)
         } else {
-            source.context.get_config().developer.chunk_size as u32
+            default_chunk_size
         };
         let metrics = source.context().batch_metrics();
 
@@ -257,12 +295,15 @@ impl BoxedExecutorBuilder for RowSeqScanExecutorBuilder {
                 table,
                 scan_ranges,
                 ordered,
+                reversed,
                 epoch,
                 chunk_size as usize,
                 source.plan_node().get_identity().clone(),
                 limit,
+                stop_after_limit,
                 metrics,
                 as_of,
+                residual_filter,
             )))
         })
     }
@@ -292,9 +333,12 @@ impl<S: StateStore> RowSeqScanExecutor<S> {
             table,
             scan_ranges,
             ordered,
+            reversed,
             epoch,
             limit,
+            stop_after_limit,
             as_of,
+            residual_filter,
         } = *self;
         let table = Arc::new(table);
         // as_of takes precedence
@@ -321,6 +365,8 @@ impl<S: StateStore> RowSeqScanExecutor<S> {
             // TODO: reserve the order for multiple ranges.
             assert_eq!(scan_ranges.len(), 1);
         }
+        // A reverse scan is only planned for a single ordered range.
+        assert!(!reversed || ordered);
 
         let (point_gets, range_scans): (Vec<ScanRange>, Vec<ScanRange>) = scan_ranges
             .into_iter()
@@ -341,6 +387,15 @@ impl<S: StateStore> RowSeqScanExecutor<S> {
                 Self::execute_point_get(table, point_get, query_epoch, histogram).await?
             {
                 if let Some(chunk) = data_chunk_builder.append_one_row(row) {
+                    let chunk = Self::apply_residual_filter(chunk, &residual_filter).await?;
+                    if chunk.cardinality() == 0 {
+                        continue;
+                    }
+                    let chunk = if stop_after_limit && let Some(limit) = &limit {
+                        Self::truncate_to_limit(chunk, *limit - returned)
+                    } else {
+                        chunk
+                    };
                     returned += chunk.cardinality() as u64;
                     yield chunk;
                     if let Some(limit) = &limit
@@ -352,12 +407,20 @@ impl<S: StateStore> RowSeqScanExecutor<S> {
             }
         }
         if let Some(chunk) = data_chunk_builder.consume_all() {
-            returned += chunk.cardinality() as u64;
-            yield chunk;
-            if let Some(limit) = &limit
-                && returned >= *limit
-            {
-                return Ok(());
+            let chunk = Self::apply_residual_filter(chunk, &residual_filter).await?;
+            if chunk.cardinality() > 0 {
+                let chunk = if stop_after_limit && let Some(limit) = &limit {
+                    Self::truncate_to_limit(chunk, *limit - returned)
+                } else {
+                    chunk
+                };
+                returned += chunk.cardinality() as u64;
+                yield chunk;
+                if let Some(limit) = &limit
+                    && returned >= *limit
+                {
+                    return Ok(());
+                }
             }
         }
 
@@ -369,6 +432,7 @@ impl<S: StateStore> RowSeqScanExecutor<S> {
                 table.clone(),
                 range,
                 ordered,
+                reversed,
                 query_epoch,
                 chunk_size,
                 limit,
@@ -376,7 +440,15 @@ impl<S: StateStore> RowSeqScanExecutor<S> {
             );
             #[for_await]
             for chunk in stream {
-                let chunk = chunk?;
+                let chunk = Self::apply_residual_filter(chunk?, &residual_filter).await?;
+                if chunk.cardinality() == 0 {
+                    continue;
+                }
+                let chunk = if stop_after_limit && let Some(limit) = &limit {
+                    Self::truncate_to_limit(chunk, *limit - returned)
+                } else {
+                    chunk
+                };
                 returned += chunk.cardinality() as u64;
                 yield chunk;
                 if let Some(limit) = &limit
@@ -388,6 +460,46 @@ impl<S: StateStore> RowSeqScanExecutor<S> {
         }
     }
 
+    /// Truncates `chunk` to at most `remaining` visible rows, so that a `stop_after_limit` scan
+    /// emits exactly `limit` rows in total rather than a full boundary chunk.
+    fn truncate_to_limit(chunk: DataChunk, remaining: u64) -> DataChunk {
+        if chunk.cardinality() as u64 <= remaining {
+            return chunk;
+        }
+        let mut kept = 0;
+        let visibility = chunk
+            .visibility()
+            .iter()
+            .map(|vis| {
+                if vis && kept < remaining {
+                    kept += 1;
+                    true
+                } else {
+                    false
+                }
+            })
+            .collect::<Bitmap>();
+        chunk.with_visibility(visibility)
+    }
+
+    /// Evaluate the residual filter, if any, and mask out the non-matching rows.
+    async fn apply_residual_filter(
+        chunk: DataChunk,
+        residual_filter: &Option<BoxedExpression>,
+    ) -> Result<DataChunk> {
+        let Some(expr) = residual_filter else {
+            return Ok(chunk);
+        };
+        let chunk = chunk.compact();
+        let vis = expr.eval(&chunk).await?;
+        let ArrayImpl::Bool(vis) = vis.as_ref() else {
+            return Err(BatchError::Internal(anyhow::anyhow!(
+                "residual filter must return a bool array"
+            )));
+        };
+        Ok(chunk.with_visibility(vis.to_bitmap()))
+    }
+
     async fn execute_point_get(
         table: Arc<StorageTable<S>>,
         scan_range: ScanRange,
@@ -414,6 +526,7 @@ impl<S: StateStore> RowSeqScanExecutor<S> {
         table: Arc<StorageTable<S>>,
         scan_range: ScanRange,
         ordered: bool,
+        reversed: bool,
         epoch: BatchQueryEpoch,
         chunk_size: usize,
         limit: Option<u64>,
@@ -436,43 +549,62 @@ impl<S: StateStore> RowSeqScanExecutor<S> {
 
         // Range Scan.
         assert!(pk_prefix.len() < table.pk_indices().len());
-        let iter = table
-            .batch_chunk_iter_with_pk_bounds(
-                epoch.into(),
-                &pk_prefix,
-                (
-                    match start_bound {
-                        Bound::Unbounded => {
-                            if end_bound_is_bounded && order_type.nulls_are_first() {
-                                // `NULL`s are at the start bound side, we should exclude them to meet SQL semantics.
-                                Bound::Excluded(OwnedRow::new(vec![None]))
-                            } else {
-                                // Both start and end are unbounded, so we need to select all rows.
-                                Bound::Unbounded
-                            }
-                        }
-                        Bound::Included(x) => Bound::Included(OwnedRow::new(vec![x])),
-                        Bound::Excluded(x) => Bound::Excluded(OwnedRow::new(vec![x])),
-                    },
-                    match end_bound {
-                        Bound::Unbounded => {
-                            if start_bound_is_bounded && order_type.nulls_are_last() {
-                                // `NULL`s are at the end bound side, we should exclude them to meet SQL semantics.
-                                Bound::Excluded(OwnedRow::new(vec![None]))
-                            } else {
-                                // Both start and end are unbounded, so we need to select all rows.
-                                Bound::Unbounded
-                            }
-                        }
-                        Bound::Included(x) => Bound::Included(OwnedRow::new(vec![x])),
-                        Bound::Excluded(x) => Bound::Excluded(OwnedRow::new(vec![x])),
-                    },
-                ),
-                ordered,
-                chunk_size,
-                PrefetchOptions::new(limit.is_none(), true),
+        let range_bounds = (
+            match start_bound {
+                Bound::Unbounded => {
+                    if end_bound_is_bounded && order_type.nulls_are_first() {
+                        // `NULL`s are at the start bound side, we should exclude them to meet SQL semantics.
+                        Bound::Excluded(OwnedRow::new(vec![None]))
+                    } else {
+                        // Both start and end are unbounded, so we need to select all rows.
+                        Bound::Unbounded
+                    }
+                }
+                Bound::Included(x) => Bound::Included(OwnedRow::new(vec![x])),
+                Bound::Excluded(x) => Bound::Excluded(OwnedRow::new(vec![x])),
+            },
+            match end_bound {
+                Bound::Unbounded => {
+                    if start_bound_is_bounded && order_type.nulls_are_last() {
+                        // `NULL`s are at the end bound side, we should exclude them to meet SQL semantics.
+                        Bound::Excluded(OwnedRow::new(vec![None]))
+                    } else {
+                        // Both start and end are unbounded, so we need to select all rows.
+                        Bound::Unbounded
+                    }
+                }
+                Bound::Included(x) => Bound::Included(OwnedRow::new(vec![x])),
+                Bound::Excluded(x) => Bound::Excluded(OwnedRow::new(vec![x])),
+            },
+        );
+        let prefetch_options = PrefetchOptions::new(limit.is_none(), true);
+        let iter = if reversed {
+            Either::Right(
+                table
+                    .batch_rev_chunk_iter_with_pk_bounds(
+                        epoch.into(),
+                        &pk_prefix,
+                        range_bounds,
+                        ordered,
+                        chunk_size,
+                        prefetch_options,
+                    )
+                    .await?,
+            )
+        } else {
+            Either::Left(
+                table
+                    .batch_chunk_iter_with_pk_bounds(
+                        epoch.into(),
+                        &pk_prefix,
+                        range_bounds,
+                        ordered,
+                        chunk_size,
+                        prefetch_options,
+                    )
+                    .await?,
             )
-            .await?;
+        };
 
         pin_mut!(iter);
         loop {
@@ -12,13 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use anyhow::anyhow;
 use futures_async_stream::try_stream;
 use futures_util::stream::StreamExt;
 use parquet::arrow::ProjectionMask;
-use risingwave_common::array::arrow::IcebergArrowConvert;
+use risingwave_common::array::arrow::{
+    arrow_array_iceberg, arrow_cast_iceberg, arrow_schema_iceberg, IcebergArrowConvert,
+    IcebergToArrow,
+};
+use risingwave_common::bitmap::Bitmap;
 use risingwave_common::catalog::{Field, Schema};
-use risingwave_connector::source::iceberg::parquet_file_reader::create_parquet_stream_builder;
+use risingwave_common::types::{DataType, Datum, JsonbVal, Scalar, ScalarImpl};
+use risingwave_common::util::iter_util::ZipEqFast;
+use risingwave_connector::source::iceberg::parquet_file_reader::{
+    create_parquet_stream_builder, read_text_file,
+};
 use risingwave_pb::batch_plan::file_scan_node;
 use risingwave_pb::batch_plan::file_scan_node::StorageType;
 use risingwave_pb::batch_plan::plan_node::NodeBody;
@@ -30,15 +40,33 @@ use crate::task::BatchTaskContext;
 #[derive(PartialEq, Debug)]
 pub enum FileFormat {
     Parquet,
+    /// Newline-delimited JSON (NDJSON): one JSON object per line.
+    Jsonl,
 }
 
-/// S3 file scan executor. Currently only support parquet file format.
+/// Name of the pseudo-column carrying the source S3 key. Must match the name the frontend binds
+/// for the `file_scan` table function.
+const FILE_PATH_COLUMN_NAME: &str = "_rw_file_path";
+
+/// S3 file scan executor. Supports parquet and newline-delimited JSON files.
 pub struct S3FileScanExecutor {
     file_format: FileFormat,
     file_location: Vec<String>,
     s3_region: String,
     s3_access_key: String,
     s3_secret_key: String,
+    s3_endpoint: Option<String>,
+    /// Whether to address the endpoint path-style (`endpoint/bucket/key`) instead of
+    /// virtual-hosted-style (`bucket.endpoint/key`). Only meaningful with an endpoint override.
+    path_style_access: bool,
+    /// Whether the schema carries the `_rw_file_path` pseudo-column, populated with the source
+    /// S3 key per file instead of being read from the file. The column is located by name:
+    /// column pruning may have moved it or dropped it from the schema entirely.
+    include_file_path: bool,
+    /// Stop the scan after this many rows in total, for quick previews. Which rows are returned
+    /// is non-deterministic across files: files are read in order and the cutoff falls wherever
+    /// the budget runs out.
+    sample_rows: Option<usize>,
     batch_size: usize,
     schema: Schema,
     identity: String,
@@ -65,6 +93,10 @@ impl S3FileScanExecutor {
         s3_region: String,
         s3_access_key: String,
         s3_secret_key: String,
+        s3_endpoint: Option<String>,
+        path_style_access: bool,
+        include_file_path: bool,
+        sample_rows: Option<usize>,
         batch_size: usize,
         schema: Schema,
         identity: String,
@@ -75,6 +107,10 @@ impl S3FileScanExecutor {
             s3_region,
             s3_access_key,
             s3_secret_key,
+            s3_endpoint,
+            path_style_access,
+            include_file_path,
+            sample_rows,
             batch_size,
             schema,
             identity,
@@ -83,26 +119,99 @@ impl S3FileScanExecutor {
 
     #[try_stream(ok = DataChunk, error = BatchError)]
     async fn do_execute(self: Box<Self>) {
-        assert_eq!(self.file_format, FileFormat::Parquet);
+        // The `_rw_file_path` pseudo-column is synthesized per file below, not read from the
+        // file. It is located by name rather than by position: column pruning may have moved
+        // it or dropped it from the output schema while leaving `include_file_path` set.
+        let file_path_pos = self
+            .include_file_path
+            .then(|| (self.schema.fields.iter()).position(|f| f.name == FILE_PATH_COLUMN_NAME))
+            .flatten();
+        let data_schema = match file_path_pos {
+            Some(pos) => {
+                let mut fields = self.schema.fields.clone();
+                fields.remove(pos);
+                Schema::new(fields)
+            }
+            None => self.schema.clone(),
+        };
+        let mut remaining = self.sample_rows;
         for file in self.file_location {
+            if remaining == Some(0) {
+                break;
+            }
+
+            if self.file_format == FileFormat::Jsonl {
+                // NDJSON is line-oriented with no footer or row groups, so the whole object is
+                // fetched and cut into chunks here; `take_sample_rows` still trims the output.
+                let content = read_text_file(
+                    self.s3_region.clone(),
+                    self.s3_access_key.clone(),
+                    self.s3_secret_key.clone(),
+                    self.s3_endpoint.clone(),
+                    self.path_style_access,
+                    file.clone(),
+                )
+                .await?;
+                for chunk in jsonl_chunks(&content, &data_schema, self.batch_size)? {
+                    let chunk = match file_path_pos {
+                        Some(pos) => insert_file_path_column(chunk, pos, &file),
+                        None => chunk,
+                    };
+                    let Some(chunk) = take_sample_rows(chunk, &mut remaining) else {
+                        break;
+                    };
+                    debug_assert_eq!(chunk.data_types(), self.schema.data_types());
+                    yield chunk;
+                    if remaining == Some(0) {
+                        break;
+                    }
+                }
+                continue;
+            }
+
             let mut batch_stream_builder = create_parquet_stream_builder(
                 self.s3_region.clone(),
                 self.s3_access_key.clone(),
                 self.s3_secret_key.clone(),
-                file,
+                self.s3_endpoint.clone(),
+                self.path_style_access,
+                file.clone(),
             )
             .await?;
 
-            let arrow_schema = batch_stream_builder.schema();
-            assert_eq!(arrow_schema.fields.len(), self.schema.fields.len());
-            for (field, arrow_field) in self.schema.fields.iter().zip(arrow_schema.fields.iter()) {
-                assert_eq!(*field.name, *arrow_field.name());
-            }
+            let arrow_schema = batch_stream_builder.schema().clone();
+            let file_columns: Vec<&str> = arrow_schema
+                .fields
+                .iter()
+                .map(|f| f.name().as_str())
+                .collect();
+            let (projection, mapping) = projection_and_mapping(&data_schema, &file_columns)?;
+            let identity_order = mapping
+                .iter()
+                .enumerate()
+                .all(|(i, m)| matches!(m, Some(p) if *p == i));
+
+            // A bind-time schema override may declare a type the file does not physically
+            // store (e.g. a parquet int32 column scanned as bigint); cast such columns per
+            // batch below. In the common case of matching types this stays empty.
+            let cast_targets =
+                projection_cast_targets(&data_schema, &mapping, &arrow_schema, &projection)?;
+            let needs_cast = cast_targets.iter().any(Option::is_some);
 
-            batch_stream_builder = batch_stream_builder.with_projection(ProjectionMask::all());
+            let projection_mask = ProjectionMask::roots(
+                batch_stream_builder.parquet_schema(),
+                projection.iter().copied(),
+            );
+            batch_stream_builder = batch_stream_builder.with_projection(projection_mask);
 
             batch_stream_builder = batch_stream_builder.with_batch_size(self.batch_size);
 
+            if let Some(remaining) = remaining {
+                // Push the remaining budget into the reader so only the needed row groups and
+                // pages are fetched from S3; `take_sample_rows` below trims the last chunk.
+                batch_stream_builder = batch_stream_builder.with_limit(remaining);
+            }
+
             let record_batch_stream = batch_stream_builder
                 .build()
                 .map_err(|e| anyhow!(e).context("fail to build arrow stream builder"))?;
@@ -110,11 +219,478 @@ impl S3FileScanExecutor {
             #[for_await]
             for record_batch in record_batch_stream {
                 let record_batch = record_batch.map_err(BatchError::Parquet)?;
+                let record_batch = if needs_cast {
+                    cast_record_batch(&record_batch, &cast_targets)?
+                } else {
+                    record_batch
+                };
                 let chunk = IcebergArrowConvert.chunk_from_record_batch(&record_batch)?;
+                // The projected columns come back in file order; restore the output order and
+                // fill columns absent from this file with NULLs.
+                let chunk = if identity_order {
+                    chunk
+                } else {
+                    map_file_chunk(&chunk, &mapping, &data_schema)
+                };
+                let chunk = match file_path_pos {
+                    Some(pos) => insert_file_path_column(chunk, pos, &file),
+                    None => chunk,
+                };
+                let Some(chunk) = take_sample_rows(chunk, &mut remaining) else {
+                    break;
+                };
                 debug_assert_eq!(chunk.data_types(), self.schema.data_types());
                 yield chunk;
+                if remaining == Some(0) {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Maps the executor's output columns to column indices in the parquet file by name, so that
+/// only the projected column chunks are fetched from S3.
+///
+/// Output columns absent from the file map to `None` and are filled with NULLs at read time,
+/// so that files written before the schema gained a column remain readable. At least one
+/// column must be present in the file.
+///
+/// Returns the column indices to read in file order and, per output column, its index among
+/// the projected columns (or `None` for a NULL fill).
+fn projection_and_mapping(
+    schema: &Schema,
+    file_columns: &[&str],
+) -> Result<(Vec<usize>, Vec<Option<usize>>), BatchError> {
+    let file_indices: Vec<Option<usize>> = schema
+        .fields
+        .iter()
+        .map(|field| file_columns.iter().position(|name| *name == field.name))
+        .collect();
+    let mut projection: Vec<usize> = file_indices.iter().copied().flatten().collect();
+    projection.sort_unstable();
+    if projection.is_empty() {
+        return Err(anyhow!("none of the output columns are present in the parquet file").into());
+    }
+    let mapping = file_indices
+        .iter()
+        .map(|i| i.map(|i| projection.binary_search(&i).unwrap()))
+        .collect();
+    Ok((projection, mapping))
+}
+
+/// Returns, per projected column, the arrow type to cast it to because the declared output
+/// type differs from the file's physical type, or `None` where the two already agree.
+///
+/// A differing type can only come from a bind-time schema override, which has already checked
+/// that an assignment cast from the inferred type exists.
+fn projection_cast_targets(
+    schema: &Schema,
+    mapping: &[Option<usize>],
+    file_schema: &arrow_schema_iceberg::Schema,
+    projection: &[usize],
+) -> Result<Vec<Option<arrow_schema_iceberg::DataType>>, BatchError> {
+    let mut targets = vec![None; projection.len()];
+    for (output, projected) in mapping.iter().enumerate() {
+        let Some(projected) = projected else {
+            continue;
+        };
+        let field = &schema.fields[output];
+        let target = IcebergArrowConvert.to_arrow_field(&field.name, &field.data_type)?;
+        if file_schema.field(projection[*projected]).data_type() != target.data_type() {
+            targets[*projected] = Some(target.data_type().clone());
+        }
+    }
+    Ok(targets)
+}
+
+/// Casts the columns of `batch` that have a cast target to their declared type, keeping the
+/// rest untouched. The cast is non-safe, so e.g. an int64 value overflowing a declared int
+/// column is an error rather than a silent NULL.
+fn cast_record_batch(
+    batch: &arrow_array_iceberg::RecordBatch,
+    targets: &[Option<arrow_schema_iceberg::DataType>],
+) -> Result<arrow_array_iceberg::RecordBatch, BatchError> {
+    let options = arrow_cast_iceberg::CastOptions {
+        safe: false,
+        ..Default::default()
+    };
+    let mut fields = Vec::with_capacity(batch.num_columns());
+    let mut columns = Vec::with_capacity(batch.num_columns());
+    for (i, column) in batch.columns().iter().enumerate() {
+        let field = batch.schema().field(i).clone();
+        match &targets[i] {
+            Some(target) => {
+                let column = arrow_cast_iceberg::cast_with_options(column, target, &options)
+                    .map_err(|e| {
+                        anyhow!(e).context(format!(
+                            "failed to cast parquet column \"{}\" to the declared type",
+                            field.name()
+                        ))
+                    })?;
+                fields.push(field.with_data_type(target.clone()));
+                columns.push(column);
+            }
+            None => {
+                fields.push(field);
+                columns.push(column.clone());
+            }
+        }
+    }
+    arrow_array_iceberg::RecordBatch::try_new(
+        Arc::new(arrow_schema_iceberg::Schema::new(fields)),
+        columns,
+    )
+    .map_err(|e| anyhow!(e).context("failed to rebuild the casted record batch").into())
+}
+
+/// Inserts the `_rw_file_path` pseudo-column at position `at`: a varchar column repeating the
+/// S3 key the chunk was read from.
+fn insert_file_path_column(chunk: DataChunk, at: usize, file_path: &str) -> DataChunk {
+    let cardinality = chunk.cardinality();
+    let mut builder = DataType::Varchar.create_array_builder(cardinality);
+    builder.append_n(cardinality, Some(ScalarImpl::Utf8(file_path.into())));
+    let (mut columns, visibility) = chunk.into_parts();
+    columns.insert(at, builder.finish().into_ref());
+    DataChunk::new(columns, visibility)
+}
+
+/// Charges `chunk` against the remaining `sample_rows` budget: a `None` budget passes the chunk
+/// through untouched, a sufficient budget is decremented, and a smaller budget trims the chunk
+/// to its first `remaining` visible rows (leaving the budget at zero). Returns `None` once the
+/// budget is exhausted.
+fn take_sample_rows(chunk: DataChunk, remaining: &mut Option<usize>) -> Option<DataChunk> {
+    let Some(remaining) = remaining else {
+        return Some(chunk);
+    };
+    if *remaining == 0 {
+        return None;
+    }
+    let cardinality = chunk.cardinality();
+    if cardinality <= *remaining {
+        *remaining -= cardinality;
+        return Some(chunk);
+    }
+    let mut kept = 0;
+    let visibility: Bitmap = chunk
+        .visibility()
+        .iter()
+        .map(|visible| {
+            if visible && kept < *remaining {
+                kept += 1;
+                true
+            } else {
+                false
+            }
+        })
+        .collect();
+    *remaining = 0;
+    let (columns, _) = chunk.into_parts();
+    Some(DataChunk::new(columns, visibility))
+}
+
+/// Parses NDJSON `content` into chunks of at most `batch_size` rows of `schema`.
+///
+/// Each line is converted field by field into the inferred column types, reusing the jsonb
+/// conversion rules ([`JsonbRef::to_datum`](risingwave_common::types::JsonbRef::to_datum)).
+/// Keys absent from a line read as NULL, keys not in the schema are ignored, and blank lines
+/// are skipped. A field whose value does not fit the column type — e.g. a string showing up
+/// past the sampled lines in a column inferred as bigint — is a clear error rather than a NULL.
+fn jsonl_chunks(
+    content: &str,
+    schema: &Schema,
+    batch_size: usize,
+) -> Result<Vec<DataChunk>, BatchError> {
+    let new_builders = || {
+        schema
+            .fields
+            .iter()
+            .map(|f| f.data_type.create_array_builder(batch_size))
+            .collect::<Vec<_>>()
+    };
+    let mut chunks = vec![];
+    let mut builders = new_builders();
+    let mut rows = 0;
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let value: JsonbVal = line
+            .parse()
+            .map_err(|e| anyhow!("failed to parse NDJSON line as JSON: {}", e))?;
+        let object = value.as_scalar_ref();
+        if !object.is_object() {
+            return Err(anyhow!(
+                "each NDJSON line must be a JSON object, got a JSON {}",
+                object.type_name()
+            )
+            .into());
+        }
+        for (field, builder) in schema.fields.iter().zip_eq_fast(builders.iter_mut()) {
+            match object.access_object_field(&field.name) {
+                Some(v) => builder.append(
+                    v.to_datum(&field.data_type)
+                        .map_err(|e| anyhow!("NDJSON field \"{}\": {}", field.name, e))?,
+                ),
+                None => builder.append_null(),
+            }
+        }
+        rows += 1;
+        if rows == batch_size {
+            let columns = std::mem::replace(&mut builders, new_builders())
+                .into_iter()
+                .map(|b| b.finish().into_ref())
+                .collect();
+            chunks.push(DataChunk::new(columns, rows));
+            rows = 0;
+        }
+    }
+    if rows > 0 {
+        let columns = builders
+            .into_iter()
+            .map(|b| b.finish().into_ref())
+            .collect();
+        chunks.push(DataChunk::new(columns, rows));
+    }
+    Ok(chunks)
+}
+
+/// Rebuilds a chunk read from one file into the executor's output schema: present columns are
+/// reordered per `mapping`, absent ones become all-NULL columns of the same cardinality.
+fn map_file_chunk(chunk: &DataChunk, mapping: &[Option<usize>], schema: &Schema) -> DataChunk {
+    let cardinality = chunk.cardinality();
+    let columns = mapping
+        .iter()
+        .zip_eq_fast(&schema.fields)
+        .map(|(m, field)| match m {
+            Some(i) => chunk.column_at(*i).clone(),
+            None => {
+                let mut builder = field.data_type.create_array_builder(cardinality);
+                builder.append_n(cardinality, Datum::None);
+                builder.finish().into_ref()
+            }
+        })
+        .collect();
+    DataChunk::new(columns, cardinality)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_projection_and_mapping() {
+        let file_columns = ["a", "b", "c", "d"];
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "c"),
+            Field::with_name(DataType::Int32, "a"),
+        ]);
+
+        let (projection, mapping) = projection_and_mapping(&schema, &file_columns).unwrap();
+        // Only the requested columns are read, in file order.
+        assert_eq!(projection, vec![0, 2]);
+        // The mapping restores the output order (c, a).
+        assert_eq!(mapping, vec![Some(1), Some(0)]);
+
+        // A column absent from the file (schema evolution) maps to a NULL fill.
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "added_later"),
+        ]);
+        let (projection, mapping) = projection_and_mapping(&schema, &file_columns).unwrap();
+        assert_eq!(projection, vec![0]);
+        assert_eq!(mapping, vec![Some(0), None]);
+
+        // ... but a file sharing no column with the output schema is an error.
+        let schema = Schema::new(vec![Field::with_name(DataType::Int32, "missing")]);
+        assert!(projection_and_mapping(&schema, &file_columns).is_err());
+    }
+
+    #[test]
+    fn test_projection_cast_targets() {
+        // The declared schema widens `a` to bigint (a bind-time schema override); `b` matches
+        // the file type exactly and `added_later` is absent from the file.
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int64, "a"),
+            Field::with_name(DataType::Int32, "b"),
+            Field::with_name(DataType::Varchar, "added_later"),
+        ]);
+        let file_schema = arrow_schema_iceberg::Schema::new(vec![
+            arrow_schema_iceberg::Field::new("a", arrow_schema_iceberg::DataType::Int32, true),
+            arrow_schema_iceberg::Field::new("b", arrow_schema_iceberg::DataType::Int32, true),
+        ]);
+        let file_columns = ["a", "b"];
+        let (projection, mapping) = projection_and_mapping(&schema, &file_columns).unwrap();
+        let targets =
+            projection_cast_targets(&schema, &mapping, &file_schema, &projection).unwrap();
+        // Only the overridden column gets a cast target.
+        assert_eq!(
+            targets,
+            vec![Some(arrow_schema_iceberg::DataType::Int64), None]
+        );
+
+        let batch = arrow_array_iceberg::RecordBatch::try_new(
+            Arc::new(file_schema),
+            vec![
+                Arc::new(arrow_array_iceberg::Int32Array::from(vec![1, 2])),
+                Arc::new(arrow_array_iceberg::Int32Array::from(vec![3, 4])),
+            ],
+        )
+        .unwrap();
+        let casted = cast_record_batch(&batch, &targets).unwrap();
+        let chunk = IcebergArrowConvert.chunk_from_record_batch(&casted).unwrap();
+        assert_eq!(chunk.data_types(), vec![DataType::Int64, DataType::Int32]);
+    }
+
+    #[test]
+    fn test_insert_file_path_column() {
+        use risingwave_common::row::Row;
+        use risingwave_common::test_prelude::DataChunkTestExt;
+        use risingwave_common::types::ScalarRefImpl;
+
+        // Chunks from two different files of the same directory each carry their own path.
+        let files = ["s3://bucket/dir/a.parquet", "s3://bucket/dir/b.parquet"];
+        for file in files {
+            let chunk = DataChunk::from_pretty(
+                "i
+                 1
+                 2",
+            );
+            let chunk = insert_file_path_column(chunk, 1, file);
+            assert_eq!(
+                chunk.data_types(),
+                vec![DataType::Int32, DataType::Varchar]
+            );
+            assert_eq!(chunk.cardinality(), 2);
+            for row in chunk.rows() {
+                assert_eq!(row.datum_at(1), Some(ScalarRefImpl::Utf8(file)));
             }
         }
+
+        // Column pruning may have moved the pseudo-column before the data columns; the column
+        // goes wherever the schema puts it rather than always last.
+        let chunk = DataChunk::from_pretty(
+            "i
+             1",
+        );
+        let chunk = insert_file_path_column(chunk, 0, "s3://bucket/dir/a.parquet");
+        assert_eq!(
+            chunk.data_types(),
+            vec![DataType::Varchar, DataType::Int32]
+        );
+        assert_eq!(
+            chunk.rows().next().unwrap().datum_at(0),
+            Some(ScalarRefImpl::Utf8("s3://bucket/dir/a.parquet"))
+        );
+    }
+
+    #[test]
+    fn test_take_sample_rows_stops_early() {
+        use risingwave_common::test_prelude::DataChunkTestExt;
+
+        // Mock a reader producing 2-row chunks; a budget of 3 rows stops mid-second-chunk.
+        let chunks = || {
+            DataChunk::from_pretty(
+                "i
+                 1
+                 2",
+            )
+        };
+        let mut remaining = Some(3);
+
+        let first = take_sample_rows(chunks(), &mut remaining).unwrap();
+        assert_eq!(first.cardinality(), 2);
+        assert_eq!(remaining, Some(1));
+
+        let second = take_sample_rows(chunks(), &mut remaining).unwrap();
+        assert_eq!(second.cardinality(), 1);
+        assert_eq!(remaining, Some(0));
+
+        // The budget is exhausted; the caller stops pulling from the reader.
+        assert!(take_sample_rows(chunks(), &mut remaining).is_none());
+
+        // Without a budget, chunks pass through untouched.
+        let mut unlimited = None;
+        let chunk = take_sample_rows(chunks(), &mut unlimited).unwrap();
+        assert_eq!(chunk.cardinality(), 2);
+        assert_eq!(unlimited, None);
+    }
+
+    #[test]
+    fn test_jsonl_chunks() {
+        use risingwave_common::test_prelude::DataChunkTestExt;
+
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int64, "id"),
+            Field::with_name(DataType::Varchar, "name"),
+            Field::with_name(DataType::Float64, "score"),
+        ]);
+        // Heterogeneous lines: missing keys read as NULL, extra keys are ignored, an integer
+        // feeds a double column, and blank lines are skipped.
+        let content = r#"
+            {"id": 1, "name": "a", "score": 1.5}
+            {"id": 2, "score": 2, "extra": true}
+
+            {"name": "c", "id": 3}
+        "#;
+        let chunks = jsonl_chunks(content, &schema, 2).unwrap();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(
+            chunks[0],
+            DataChunk::from_pretty(
+                "I T F
+                 1 a 1.5
+                 2 . 2",
+            )
+        );
+        assert_eq!(
+            chunks[1],
+            DataChunk::from_pretty(
+                "I T F
+                 3 c .",
+            )
+        );
+
+        // A jsonb column takes the field value as-is, whatever its JSON type per line.
+        let schema = Schema::new(vec![Field::with_name(DataType::Jsonb, "v")]);
+        let chunks = jsonl_chunks("{\"v\": {\"k\": 1}}\n{\"v\": \"s\"}", &schema, 1024).unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].cardinality(), 2);
+
+        // Non-object lines and malformed JSON are rejected, as is a value that does not fit
+        // the inferred column type.
+        let schema = Schema::new(vec![Field::with_name(DataType::Int64, "id")]);
+        jsonl_chunks("[1]", &schema, 1024).unwrap_err();
+        jsonl_chunks("{\"id\": ", &schema, 1024).unwrap_err();
+        jsonl_chunks("{\"id\": \"not a number\"}", &schema, 1024).unwrap_err();
+    }
+
+    #[test]
+    fn test_map_file_chunk_fills_missing_with_null() {
+        use risingwave_common::test_prelude::DataChunkTestExt;
+
+        let schema = Schema::new(vec![
+            Field::with_name(DataType::Int32, "a"),
+            Field::with_name(DataType::Varchar, "added_later"),
+        ]);
+        // The file only carries column `a`.
+        let file_chunk = DataChunk::from_pretty(
+            "i
+             1
+             2",
+        );
+
+        let chunk = map_file_chunk(&file_chunk, &[Some(0), None], &schema);
+        assert_eq!(chunk.data_types(), schema.data_types());
+        assert_eq!(
+            chunk,
+            DataChunk::from_pretty(
+                "i T
+                 1 .
+                 2 .",
+            )
+        );
     }
 }
 
@@ -136,12 +712,17 @@ impl BoxedExecutorBuilder for FileScanExecutorBuilder {
         Ok(Box::new(S3FileScanExecutor::new(
             match file_scan_node::FileFormat::try_from(file_scan_node.file_format).unwrap() {
                 file_scan_node::FileFormat::Parquet => FileFormat::Parquet,
+                file_scan_node::FileFormat::Jsonl => FileFormat::Jsonl,
                 file_scan_node::FileFormat::Unspecified => unreachable!(),
             },
             file_scan_node.file_location.clone(),
             file_scan_node.s3_region.clone(),
             file_scan_node.s3_access_key.clone(),
             file_scan_node.s3_secret_key.clone(),
+            Some(file_scan_node.s3_endpoint.clone()).filter(|e| !e.is_empty()),
+            file_scan_node.path_style_access,
+            file_scan_node.include_file_path,
+            (file_scan_node.sample_rows > 0).then_some(file_scan_node.sample_rows as usize),
             source.context.get_config().developer.chunk_size,
             Schema::from_iter(file_scan_node.columns.iter().map(Field::from)),
             source.plan_node().get_identity().clone(),
@@ -38,4 +38,56 @@ impl IntGauge {
 
         Guard::create(self)
     }
+
+    /// Set the gauge to `value`, and return a guard that will restore the previous value when
+    /// dropped. Useful for gauges encoding a "current phase" code, especially nested ones.
+    ///
+    /// Note that the restore is absolute, so guards must be dropped in reverse order of
+    /// creation (the natural scoping) for the unwinding to be meaningful.
+    #[must_use]
+    pub fn set_guard(&self, value: i64) -> impl Drop + '_ {
+        struct Guard<'a> {
+            gauge: &'a IntGauge,
+            prev: i64,
+        }
+
+        impl<'a> Guard<'a> {
+            fn create(gauge: &'a IntGauge, value: i64) -> Self {
+                let prev = gauge.get();
+                gauge.set(value);
+                Self { gauge, prev }
+            }
+        }
+
+        impl<'a> Drop for Guard<'a> {
+            fn drop(&mut self) {
+                self.gauge.set(self.prev);
+            }
+        }
+
+        Guard::create(self, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_guard_nested() {
+        let gauge = IntGauge::new("test_phase", "current phase code").unwrap();
+        gauge.set(1);
+
+        {
+            let _outer = gauge.set_guard(2);
+            assert_eq!(gauge.get(), 2);
+            {
+                let _inner = gauge.set_guard(3);
+                assert_eq!(gauge.get(), 3);
+            }
+            // The inner guard restored the outer value.
+            assert_eq!(gauge.get(), 2);
+        }
+        assert_eq!(gauge.get(), 1);
+    }
 }
@@ -24,17 +24,36 @@ use risingwave_pb::catalog::PbSecret;
 use risingwave_pb::secret::secret_ref::RefAsType;
 use risingwave_pb::secret::PbSecretRef;
 use thiserror_ext::AsReport;
+use zeroize::Zeroizing;
 
 use super::error::{SecretError, SecretResult};
-use super::SecretId;
+use super::{SecretEncryption, SecretId};
 
 static INSTANCE: std::sync::OnceLock<LocalSecretManager> = std::sync::OnceLock::new();
 
-#[derive(Debug)]
+/// Callback invoked whenever a secret is resolved, e.g. to record secret usage for auditing.
+pub type SecretAuditCallback = Box<dyn Fn(SecretId, RefAsType) + Send + Sync>;
+
 pub struct LocalSecretManager {
     secrets: RwLock<HashMap<SecretId, Vec<u8>>>,
+    /// When set, the values in `secrets` are held encrypted under this key (via
+    /// [`SecretEncryption`]) instead of as plain bytes, so a dump of the process memory does
+    /// not readily expose them. Activated and rolled over through
+    /// [`Self::rotate_encryption_key`].
+    encryption_key: RwLock<Option<Vec<u8>>>,
     /// The local directory used to write secrets into file, so that it can be passed into some libararies
     secret_file_dir: PathBuf,
+    /// Invoked once for every secret ref resolved in [`Self::fill_secrets`].
+    audit_callback: RwLock<Option<SecretAuditCallback>>,
+}
+
+impl std::fmt::Debug for LocalSecretManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LocalSecretManager")
+            .field("secrets", &self.secrets)
+            .field("secret_file_dir", &self.secret_file_dir)
+            .finish_non_exhaustive()
+    }
 }
 
 impl LocalSecretManager {
@@ -56,7 +75,9 @@ impl LocalSecretManager {
 
             Self {
                 secrets: RwLock::new(HashMap::new()),
+                encryption_key: RwLock::new(None),
                 secret_file_dir,
+                audit_callback: RwLock::new(None),
             }
         });
     }
@@ -74,7 +95,20 @@ impl LocalSecretManager {
 
     pub fn add_secret(&self, secret_id: SecretId, secret: Vec<u8>) {
         let mut secret_guard = self.secrets.write();
-        secret_guard.insert(secret_id, secret);
+        let key_guard = self.encryption_key.read();
+        secret_guard.insert(secret_id, Self::seal(key_guard.as_deref(), secret));
+    }
+
+    /// Add a batch of secrets while taking the write lock only once. Unlike
+    /// [`Self::init_secrets`], existing entries are kept.
+    pub fn add_secrets(&self, secrets: impl IntoIterator<Item = (SecretId, Vec<u8>)>) {
+        let mut secret_guard = self.secrets.write();
+        let key_guard = self.encryption_key.read();
+        secret_guard.extend(
+            secrets
+                .into_iter()
+                .map(|(id, value)| (id, Self::seal(key_guard.as_deref(), value))),
+        );
     }
 
     pub fn init_secrets(&self, secrets: Vec<PbSecret>) {
@@ -95,48 +129,140 @@ impl LocalSecretManager {
         #[cfg(not(madsim))]
         std::fs::create_dir_all(&self.secret_file_dir).unwrap();
 
+        let key_guard = self.encryption_key.read();
         for secret in secrets {
-            secret_guard.insert(secret.id, secret.value);
+            secret_guard.insert(secret.id, Self::seal(key_guard.as_deref(), secret.value));
         }
     }
 
     pub fn get_secret(&self, secret_id: SecretId) -> Option<Vec<u8>> {
         let secret_guard = self.secrets.read();
-        secret_guard.get(&secret_id).cloned()
+        let key_guard = self.encryption_key.read();
+        let stored = secret_guard.get(&secret_id)?;
+        Self::unseal(key_guard.as_deref(), stored)
+            .inspect_err(|e| {
+                tracing::error!(
+                    error = %e.as_report(),
+                    secret_id,
+                    "failed to decrypt stored secret value")
+            })
+            .ok()
     }
 
+    /// Like [`Self::get_secret`], but the returned buffer is zeroed out on drop so the
+    /// plaintext does not linger in freed heap memory.
+    ///
+    /// TODO: without an active encryption key, the copy stored in the internal map is still a
+    /// plain `Vec<u8>`; wrapping the stored value in `Zeroizing` as well is left as a
+    /// follow-up.
+    pub fn get_secret_zeroizing(&self, secret_id: SecretId) -> Option<Zeroizing<Vec<u8>>> {
+        self.get_secret(secret_id).map(Zeroizing::new)
+    }
+
+    /// Removes the secret from the in-memory map and deletes its on-disk file, if any.
+    ///
+    /// The file cleanup does not depend on the map entry being present, so a file orphaned by
+    /// an interrupted prior run (e.g. a crash between writing the file and registering the
+    /// secret) is still removed when the drop is replayed.
     pub fn remove_secret(&self, secret_id: SecretId) {
         let mut secret_guard = self.secrets.write();
         secret_guard.remove(&secret_id);
         self.remove_secret_file_if_exist(&secret_id);
     }
 
+    /// Re-encrypts every stored secret value under `new_key` and makes it the active key.
+    /// Enabling encryption on a previously plaintext manager is the degenerate rotation from
+    /// no key at all.
+    ///
+    /// The rotation works on a scratch map and swaps it in only once every value has been
+    /// re-encrypted, so any failure leaves both the map and the active key untouched and the
+    /// old key keeps working. The write lock is held across the re-encryption — a half-rotated
+    /// map must never be observable — but the swap itself is just two pointer-sized writes.
+    ///
+    /// The materialized secret files are not rewritten: they hold the plaintext value handed
+    /// to libraries ([`RefAsType::File`]), which a key rotation does not change.
+    pub fn rotate_encryption_key(&self, new_key: &[u8]) -> SecretResult<()> {
+        let mut secret_guard = self.secrets.write();
+        let mut key_guard = self.encryption_key.write();
+        let mut rotated = HashMap::with_capacity(secret_guard.len());
+        for (secret_id, stored) in secret_guard.iter() {
+            let plaintext = Self::unseal(key_guard.as_deref(), stored)?;
+            rotated.insert(*secret_id, Self::seal(Some(new_key), plaintext));
+        }
+        *secret_guard = rotated;
+        *key_guard = Some(new_key.to_vec());
+        Ok(())
+    }
+
+    /// Encrypts `value` under `key` for storage in the in-memory map; an absent key stores
+    /// the plain bytes.
+    fn seal(key: Option<&[u8]>, value: Vec<u8>) -> Vec<u8> {
+        match key {
+            Some(key) => SecretEncryption::encrypt(key, &value)
+                .and_then(|sealed| sealed.serialize())
+                // AES-GCM encryption cannot fail for our key and nonce sizes, nor can the
+                // serialization of its two owned fields.
+                .expect("sealing a secret value cannot fail"),
+            None => value,
+        }
+    }
+
+    /// Inverse of [`Self::seal`]: decrypts a stored value with the active key, or copies it
+    /// out verbatim when no key is active. Fails on a corrupted value or a wrong key.
+    fn unseal(key: Option<&[u8]>, stored: &[u8]) -> SecretResult<Vec<u8>> {
+        match key {
+            Some(key) => SecretEncryption::deserialize(stored)?.decrypt(key),
+            None => Ok(stored.to_vec()),
+        }
+    }
+
+    /// Install a callback recording every secret resolution, replacing any previous one.
+    pub fn set_audit_callback(
+        &self,
+        callback: impl Fn(SecretId, RefAsType) + Send + Sync + 'static,
+    ) {
+        *self.audit_callback.write() = Some(Box::new(callback));
+    }
+
     pub fn fill_secrets(
         &self,
         mut options: BTreeMap<String, String>,
         secret_refs: BTreeMap<String, PbSecretRef>,
     ) -> SecretResult<BTreeMap<String, String>> {
-        let secret_guard = self.secrets.read();
-        for (option_key, secret_ref) in secret_refs {
-            let secret_id = secret_ref.secret_id;
-            let pb_secret_bytes = secret_guard
-                .get(&secret_id)
-                .ok_or(SecretError::ItemNotFound(secret_id))?;
-            let secret_value_bytes = Self::get_secret_value(pb_secret_bytes)?;
-            match secret_ref.ref_as() {
-                RefAsType::Text => {
-                    // We converted the secret string from sql to bytes using `as_bytes` in frontend.
-                    // So use `from_utf8` here to convert it back to string.
-                    options.insert(option_key, String::from_utf8(secret_value_bytes.clone())?);
-                }
-                RefAsType::File => {
-                    let path_str =
-                        self.get_or_init_secret_file(secret_id, secret_value_bytes.clone())?;
-                    options.insert(option_key, path_str);
-                }
-                RefAsType::Unspecified => {
-                    return Err(SecretError::UnspecifiedRefType(secret_id));
+        let mut resolved = Vec::with_capacity(secret_refs.len());
+        {
+            let secret_guard = self.secrets.read();
+            let key_guard = self.encryption_key.read();
+            for (option_key, secret_ref) in secret_refs {
+                let secret_id = secret_ref.secret_id;
+                let pb_secret_bytes = secret_guard
+                    .get(&secret_id)
+                    .ok_or(SecretError::ItemNotFound(secret_id))?;
+                let pb_secret_bytes = Self::unseal(key_guard.as_deref(), pb_secret_bytes)?;
+                let secret_value_bytes = Self::get_secret_value(&pb_secret_bytes)?;
+                match secret_ref.ref_as() {
+                    RefAsType::Text => {
+                        // We converted the secret string from sql to bytes using `as_bytes` in frontend.
+                        // So use `from_utf8` here to convert it back to string.
+                        options.insert(option_key, String::from_utf8(secret_value_bytes.clone())?);
+                    }
+                    RefAsType::File => {
+                        let path_str =
+                            self.get_or_init_secret_file(secret_id, secret_value_bytes.clone())?;
+                        options.insert(option_key, path_str);
+                    }
+                    RefAsType::Unspecified => {
+                        return Err(SecretError::UnspecifiedRefType(secret_id));
+                    }
                 }
+                resolved.push((secret_id, secret_ref.ref_as()));
+            }
+        }
+        // Invoke the audit callback after the secrets lock is released to avoid holding it
+        // across arbitrary user code.
+        if let Some(callback) = self.audit_callback.read().as_ref() {
+            for (secret_id, ref_as) in resolved {
+                callback(secret_id, ref_as);
             }
         }
         Ok(options)
@@ -149,6 +275,15 @@ impl LocalSecretManager {
         secret_id: SecretId,
         secret_bytes: Vec<u8>,
     ) -> SecretResult<String> {
+        // The directory lives under a temp dir and may be deleted out from under us (e.g. by a
+        // `/tmp` cleaner); recreate it instead of failing every file-ref secret from then on.
+        if !self.secret_file_dir.exists() {
+            tracing::warn!(
+                path = %self.secret_file_dir.to_string_lossy(),
+                "secret file directory is missing, recreating it"
+            );
+            std::fs::create_dir_all(&self.secret_file_dir)?;
+        }
         let path = self.secret_file_dir.join(secret_id.to_string());
         if !path.exists() {
             let mut file = File::create(&path)?;
@@ -185,3 +320,251 @@ impl LocalSecretManager {
         Ok(secret_value)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn manager_for_test() -> LocalSecretManager {
+        LocalSecretManager {
+            secrets: RwLock::new(HashMap::new()),
+            encryption_key: RwLock::new(None),
+            secret_file_dir: PathBuf::from("./tmp"),
+            audit_callback: RwLock::new(None),
+        }
+    }
+
+    fn meta_secret(value: &[u8]) -> Vec<u8> {
+        risingwave_pb::secret::Secret {
+            secret_backend: Some(risingwave_pb::secret::secret::SecretBackend::Meta(
+                risingwave_pb::secret::SecretMetaBackend {
+                    value: value.to_vec(),
+                },
+            )),
+        }
+        .encode_to_vec()
+    }
+
+    #[test]
+    fn test_get_secret_zeroizing() {
+        let manager = manager_for_test();
+        let secret = b"super-secret".to_vec();
+        manager.add_secret(1, secret.clone());
+
+        let zeroizing = manager.get_secret_zeroizing(1).unwrap();
+        // The wrapper derefs to the same bytes and wipes them on drop.
+        assert_eq!(zeroizing.as_slice(), secret.as_slice());
+        drop(zeroizing);
+
+        assert!(manager.get_secret_zeroizing(2).is_none());
+    }
+
+    #[test]
+    fn test_add_secrets_batch() {
+        let manager = manager_for_test();
+        manager.add_secret(1, b"existing".to_vec());
+
+        manager.add_secrets((2..=4).map(|id| (id, format!("secret-{id}").into_bytes())));
+
+        // The prior entry remains and the whole batch is retrievable.
+        assert_eq!(manager.get_secret(1).unwrap(), b"existing");
+        for id in 2..=4 {
+            assert_eq!(
+                manager.get_secret(id).unwrap(),
+                format!("secret-{id}").into_bytes()
+            );
+        }
+    }
+
+    #[test]
+    fn test_rotate_encryption_key() {
+        let manager = manager_for_test();
+        manager.add_secret(1, meta_secret(b"original"));
+
+        let secret_refs = BTreeMap::from([(
+            "key".to_string(),
+            PbSecretRef {
+                secret_id: 1,
+                ref_as: RefAsType::Text as i32,
+            },
+        )]);
+
+        let options = manager
+            .fill_secrets(BTreeMap::new(), secret_refs.clone())
+            .unwrap();
+        assert_eq!(options["key"], "original");
+
+        // Enabling encryption is the degenerate rotation from no key at all: resolution
+        // still yields the original plaintext, but the stored bytes are no longer the plain
+        // encoding.
+        manager.rotate_encryption_key(b"first-key").unwrap();
+        assert_ne!(
+            manager.secrets.read().get(&1).unwrap(),
+            &meta_secret(b"original")
+        );
+        assert_eq!(manager.get_secret(1).unwrap(), meta_secret(b"original"));
+        let options = manager
+            .fill_secrets(BTreeMap::new(), secret_refs.clone())
+            .unwrap();
+        assert_eq!(options["key"], "original");
+
+        // Rolling over to a second key keeps resolving, and a secret added after the
+        // rotation is sealed under the new key as well.
+        manager.rotate_encryption_key(b"second-key").unwrap();
+        manager.add_secret(2, meta_secret(b"added-later"));
+        assert_ne!(
+            manager.secrets.read().get(&2).unwrap(),
+            &meta_secret(b"added-later")
+        );
+        assert_eq!(manager.get_secret(2).unwrap(), meta_secret(b"added-later"));
+        let options = manager.fill_secrets(BTreeMap::new(), secret_refs).unwrap();
+        assert_eq!(options["key"], "original");
+    }
+
+    #[test]
+    fn test_fill_secrets_file_binary_round_trip() {
+        let secret_file_dir =
+            std::env::temp_dir().join(format!("secret_test_{}", std::process::id()));
+        std::fs::create_dir_all(&secret_file_dir).unwrap();
+        let manager = LocalSecretManager {
+            secrets: RwLock::new(HashMap::new()),
+            encryption_key: RwLock::new(None),
+            secret_file_dir: secret_file_dir.clone(),
+            audit_callback: RwLock::new(None),
+        };
+
+        // Binary payload that is not valid UTF-8, e.g. decoded from a base64 `CREATE SECRET`.
+        let payload = vec![0x00, 0xff, 0x10, 0x80];
+        manager.add_secret(1, meta_secret(&payload));
+
+        let secret_refs = BTreeMap::from([(
+            "key".to_string(),
+            PbSecretRef {
+                secret_id: 1,
+                ref_as: RefAsType::File as i32,
+            },
+        )]);
+        let options = manager.fill_secrets(BTreeMap::new(), secret_refs).unwrap();
+        // The secret file holds the exact stored bytes.
+        assert_eq!(std::fs::read(&options["key"]).unwrap(), payload);
+
+        std::fs::remove_dir_all(&secret_file_dir).unwrap();
+    }
+
+    #[test]
+    fn test_remove_secret_cleans_orphaned_file() {
+        let secret_file_dir =
+            std::env::temp_dir().join(format!("secret_orphan_test_{}", std::process::id()));
+        std::fs::create_dir_all(&secret_file_dir).unwrap();
+        let manager = LocalSecretManager {
+            secrets: RwLock::new(HashMap::new()),
+            encryption_key: RwLock::new(None),
+            secret_file_dir: secret_file_dir.clone(),
+            audit_callback: RwLock::new(None),
+        };
+
+        // A secret file orphaned by an interrupted prior run: on disk, but absent from the
+        // in-memory map.
+        let orphan_path = secret_file_dir.join("7");
+        std::fs::write(&orphan_path, b"stale").unwrap();
+        assert!(manager.get_secret(7).is_none());
+
+        // Dropping the secret still removes the file, independent of map presence.
+        manager.remove_secret(7);
+        assert!(!orphan_path.exists());
+
+        // The regular path removes both the entry and its file.
+        manager.add_secret(8, meta_secret(b"live"));
+        let secret_refs = BTreeMap::from([(
+            "key".to_string(),
+            PbSecretRef {
+                secret_id: 8,
+                ref_as: RefAsType::File as i32,
+            },
+        )]);
+        let options = manager.fill_secrets(BTreeMap::new(), secret_refs).unwrap();
+        let path = PathBuf::from(&options["key"]);
+        assert!(path.exists());
+        manager.remove_secret(8);
+        assert!(manager.get_secret(8).is_none());
+        assert!(!path.exists());
+
+        std::fs::remove_dir_all(&secret_file_dir).unwrap();
+    }
+
+    #[test]
+    fn test_fill_secrets_recreates_missing_dir() {
+        let secret_file_dir =
+            std::env::temp_dir().join(format!("secret_selfheal_test_{}", std::process::id()));
+        std::fs::create_dir_all(&secret_file_dir).unwrap();
+        let manager = LocalSecretManager {
+            secrets: RwLock::new(HashMap::new()),
+            encryption_key: RwLock::new(None),
+            secret_file_dir: secret_file_dir.clone(),
+            audit_callback: RwLock::new(None),
+        };
+
+        manager.add_secret(1, meta_secret(b"payload"));
+        let secret_refs = BTreeMap::from([(
+            "key".to_string(),
+            PbSecretRef {
+                secret_id: 1,
+                ref_as: RefAsType::File as i32,
+            },
+        )]);
+
+        // The directory is deleted out from under the manager, e.g. by a `/tmp` cleaner.
+        let options = manager
+            .fill_secrets(BTreeMap::new(), secret_refs.clone())
+            .unwrap();
+        std::fs::remove_dir_all(&secret_file_dir).unwrap();
+        assert!(!secret_file_dir.exists());
+
+        // The next resolution recreates the directory and rematerializes the file.
+        let options2 = manager.fill_secrets(BTreeMap::new(), secret_refs).unwrap();
+        assert_eq!(options2["key"], options["key"]);
+        assert_eq!(std::fs::read(&options2["key"]).unwrap(), b"payload");
+
+        std::fs::remove_dir_all(&secret_file_dir).unwrap();
+    }
+
+    #[test]
+    fn test_audit_callback() {
+        let manager = manager_for_test();
+        manager.add_secret(1, meta_secret(b"foo"));
+        manager.add_secret(2, meta_secret(b"bar"));
+
+        let audited = std::sync::Arc::new(parking_lot::Mutex::new(Vec::new()));
+        let audited_clone = audited.clone();
+        manager.set_audit_callback(move |secret_id, ref_as| {
+            audited_clone.lock().push((secret_id, ref_as));
+        });
+
+        let secret_refs = BTreeMap::from([
+            (
+                "key1".to_string(),
+                PbSecretRef {
+                    secret_id: 1,
+                    ref_as: RefAsType::Text as i32,
+                },
+            ),
+            (
+                "key2".to_string(),
+                PbSecretRef {
+                    secret_id: 2,
+                    ref_as: RefAsType::Text as i32,
+                },
+            ),
+        ]);
+        let options = manager.fill_secrets(BTreeMap::new(), secret_refs).unwrap();
+        assert_eq!(options["key1"], "foo");
+        assert_eq!(options["key2"], "bar");
+
+        // The callback fires exactly once per resolved ref, with its ref-as type.
+        let audited = audited.lock();
+        assert_eq!(
+            *audited,
+            vec![(1, RefAsType::Text), (2, RefAsType::Text)]
+        );
+    }
+}
@@ -176,6 +176,48 @@ where
         }
     }
 
+    /// Like [`Self::put`], but records the entry under the caller-provided `sequence` instead
+    /// of allocating a fresh one, inserting it at the matching recency position. This lets an
+    /// entry rehydrated from older state keep its original age, so sequence-based eviction
+    /// treats it as if it had never left the cache.
+    pub fn put_with_sequence(&mut self, key: K, mut value: V, sequence: Sequence) -> Option<V> {
+        unsafe {
+            let hash = self.hash_builder.hash_one(&key);
+
+            match self
+                .map
+                .entry(hash, |p| p.as_ref().key() == &key, |p| p.as_ref().hash)
+            {
+                Entry::Occupied(o) => {
+                    let mut ptr = *o.get();
+                    let entry = ptr.as_mut();
+                    std::mem::swap(&mut value, entry.value_mut());
+                    self.detach(ptr);
+                    self.attach_with_sequence(ptr, sequence);
+                    Some(value)
+                }
+                Entry::Vacant(v) => {
+                    let entry = Box::new_in(
+                        LruEntry {
+                            prev: None,
+                            next: None,
+                            key: MaybeUninit::new(key),
+                            value: MaybeUninit::new(value),
+                            hash,
+                            // sequence will be updated by `attach_with_sequence`
+                            sequence: 0,
+                        },
+                        self.alloc.clone(),
+                    );
+                    let ptr = NonNull::new_unchecked(Box::into_raw(entry));
+                    v.insert(ptr);
+                    self.attach_with_sequence(ptr, sequence);
+                    None
+                }
+            }
+        }
+    }
+
     pub fn get<'a, Q>(&'a mut self, key: &Q) -> Option<&'a V>
     where
         K: Borrow<Q>,
@@ -259,6 +301,32 @@ where
         }
     }
 
+    /// Peek the entry at the LRU end (the next candidate for eviction) without
+    /// updating its recency.
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        unsafe {
+            if self.is_empty() {
+                return None;
+            }
+            let ptr = self.dummy.next.unwrap_unchecked();
+            let entry = ptr.as_ref();
+            Some((entry.key(), entry.value()))
+        }
+    }
+
+    /// Peek the entry at the MRU end (the most recently accessed entry) without
+    /// updating its recency.
+    pub fn peek_mru(&self) -> Option<(&K, &V)> {
+        unsafe {
+            if self.is_empty() {
+                return None;
+            }
+            let ptr = self.dummy.prev.unwrap_unchecked();
+            let entry = ptr.as_ref();
+            Some((entry.key(), entry.value()))
+        }
+    }
+
     pub fn len(&self) -> usize {
         self.map.len()
     }
@@ -267,6 +335,58 @@ where
         self.len() == 0
     }
 
+    /// Number of entries the underlying table can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Shrinks the underlying table's capacity to fit the current number of entries, releasing
+    /// spare capacity retained from an earlier growth spike back to the allocator. Entries,
+    /// recency and sequences are untouched.
+    pub fn shrink_to_fit(&mut self) {
+        self.map.shrink_to_fit(|p| unsafe { p.as_ref().hash });
+    }
+
+    /// Returns the sequence of the first (least recently used) entry, if any.
+    pub fn peek_lru_sequence(&self) -> Option<Sequence> {
+        unsafe {
+            if self.is_empty() {
+                return None;
+            }
+            let ptr = self.dummy.next.unwrap_unchecked();
+            Some(ptr.as_ref().sequence)
+        }
+    }
+
+    /// Iterates over all entries from LRU to MRU, without updating recency or sequences.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> + '_ {
+        let dummy = self.dummy.as_ref() as *const LruEntry<K, V>;
+        let mut ptr = unsafe { self.dummy.next.unwrap_unchecked() };
+        std::iter::from_fn(move || unsafe {
+            if std::ptr::eq(ptr.as_ptr(), dummy) {
+                return None;
+            }
+            let entry = ptr.as_ptr();
+            ptr = (*entry).next.unwrap_unchecked();
+            Some(((*entry).key.assume_init_ref(), (*entry).value.assume_init_ref()))
+        })
+    }
+
+    /// Iterates over all entries from LRU to MRU, yielding mutable references to the values.
+    /// Like [`Self::iter`], recency and sequences are not updated.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> + '_ {
+        let dummy = self.dummy.as_mut() as *mut LruEntry<K, V>;
+        let mut ptr = unsafe { self.dummy.next.unwrap_unchecked() };
+        std::iter::from_fn(move || unsafe {
+            if std::ptr::eq(ptr.as_ptr(), dummy) {
+                return None;
+            }
+            let entry = ptr.as_ptr();
+            ptr = (*entry).next.unwrap_unchecked();
+            Some(((*entry).key.assume_init_ref(), (*entry).value.assume_init_mut()))
+        })
+    }
+
     /// Pop first entry if its sequence is less than the given sequence.
     pub fn pop_with_sequence(&mut self, sequence: Sequence) -> Option<(K, V, Sequence)> {
         unsafe {
@@ -352,6 +472,37 @@ where
             entry.sequence = SEQUENCER.with(|s| s.borrow_mut().alloc());
         }
     }
+
+    /// Attaches `ptr` at the list position matching `sequence`, keeping the list sorted by
+    /// sequence so that [`Self::pop_with_sequence`], which only ever inspects the head, still
+    /// pops strictly oldest-first.
+    fn attach_with_sequence(&mut self, mut ptr: NonNull<LruEntry<K, V>>, sequence: Sequence) {
+        unsafe {
+            let entry = ptr.as_mut();
+
+            debug_assert!(entry.prev.is_none() && entry.next.is_none());
+
+            // Walk from the MRU end to the first entry whose sequence is not greater than the
+            // new one and insert right after it. Rehydrated entries are older than everything
+            // already cached in the common case, so the walk usually crosses the whole list
+            // only when the cache is still small.
+            let dummy = NonNull::new_unchecked(self.dummy.as_mut() as *mut _);
+            let mut next = dummy;
+            let mut prev = self.dummy.prev.unwrap_unchecked();
+            while !std::ptr::eq(prev.as_ptr(), dummy.as_ptr()) && prev.as_ref().sequence > sequence
+            {
+                next = prev;
+                prev = prev.as_ref().prev.unwrap_unchecked();
+            }
+
+            entry.next = Some(next);
+            entry.prev = Some(prev);
+            prev.as_mut().next = Some(ptr);
+            next.as_mut().prev = Some(ptr);
+
+            entry.sequence = sequence;
+        }
+    }
 }
 
 impl<K, V, S, A> Drop for LruCache<K, V, S, A>
@@ -366,4 +517,101 @@ where
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peek_lru_mru() {
+        let mut cache = LruCache::unbounded();
+        assert_eq!(cache.peek_lru(), None);
+        assert_eq!(cache.peek_mru(), None);
+
+        cache.put(1, "one");
+        cache.put(2, "two");
+        cache.put(3, "three");
+
+        assert_eq!(cache.peek_lru(), Some((&1, &"one")));
+        assert_eq!(cache.peek_mru(), Some((&3, &"three")));
+
+        // `get` updates recency, `peek_lru`/`peek_mru` don't.
+        cache.get(&1);
+        assert_eq!(cache.peek_lru(), Some((&2, &"two")));
+        assert_eq!(cache.peek_mru(), Some((&1, &"one")));
+        assert_eq!(cache.peek_lru(), Some((&2, &"two")));
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut cache = LruCache::unbounded();
+        assert!(cache.iter().next().is_none());
+
+        cache.put(1, 10);
+        cache.put(2, 20);
+        cache.put(3, 30);
+        cache.get(&1);
+
+        // LRU to MRU order, reflecting the `get` above.
+        assert_eq!(
+            cache.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+            vec![(2, 20), (3, 30), (1, 10)]
+        );
+
+        for (k, v) in cache.iter_mut() {
+            *v += k;
+        }
+        assert_eq!(cache.peek(&2), Some(&22));
+
+        // Iteration does not update recency: 2 is still the eviction candidate.
+        assert_eq!(cache.peek_lru(), Some((&2, &22)));
+    }
+
+    #[test]
+    fn test_put_with_sequence() {
+        let mut cache = LruCache::unbounded();
+
+        // Insert out of sequence order: the list stays sorted by sequence regardless.
+        cache.put_with_sequence(3, 30, 300);
+        cache.put_with_sequence(1, 10, 100);
+        cache.put_with_sequence(2, 20, 200);
+        assert_eq!(cache.peek_lru_sequence(), Some(100));
+        assert_eq!(
+            cache.iter().map(|(k, _)| *k).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+
+        // Popping below a boundary yields exactly the older entries, oldest first.
+        assert_eq!(cache.pop_with_sequence(250), Some((1, 10, 100)));
+        assert_eq!(cache.pop_with_sequence(250), Some((2, 20, 200)));
+        assert_eq!(cache.pop_with_sequence(250), None);
+
+        // Overwriting an existing key re-ages it under the new sequence.
+        cache.put_with_sequence(4, 40, 500);
+        assert_eq!(cache.put_with_sequence(3, 31, 400), Some(30));
+        assert_eq!(cache.pop_with_sequence(Sequence::MAX), Some((3, 31, 400)));
+        assert_eq!(cache.pop_with_sequence(Sequence::MAX), Some((4, 40, 500)));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut cache = LruCache::unbounded();
+        for i in 0..1024 {
+            cache.put(i, i);
+        }
+        let capacity_grown = cache.capacity();
+
+        while cache.len() > 4 {
+            cache.pop_with_sequence(Sequence::MAX).unwrap();
+        }
+        // Popping entries does not release the table's capacity by itself...
+        assert_eq!(cache.capacity(), capacity_grown);
+
+        // ... shrinking does, and the surviving entries remain intact in LRU order.
+        cache.shrink_to_fit();
+        assert!(cache.capacity() < capacity_grown);
+        assert_eq!(
+            cache.iter().map(|(k, _)| *k).collect::<Vec<_>>(),
+            vec![1020, 1021, 1022, 1023]
+        );
+    }
+}
@@ -109,10 +109,52 @@ pub struct SessionConfig {
     #[parameter(default = false, rename = "batch_enable_distributed_dml")]
     batch_enable_distributed_dml: bool,
 
+    /// Enable pushing the residual (non-key) predicate of a batch scan down into the storage
+    /// scan, so that non-matching rows are filtered out before being shipped.
+    #[parameter(default = false)]
+    batch_enable_residual_filter_pushdown: bool,
+
+    /// Target in-memory size in bytes of one chunk produced by a batch table scan. When
+    /// positive, the planner derives a per-scan chunk-size hint from the estimated row width,
+    /// so scans of wide rows produce smaller chunks and scans of narrow rows larger ones.
+    /// `0` disables the hint and the executor's configured chunk size applies.
+    #[parameter(default = 0_u64)]
+    batch_scan_chunk_size_target_bytes: u64,
+
     /// The max gap allowed to transform small range scan into multi point lookup.
     #[parameter(default = 8)]
     max_split_range_gap: i32,
 
+    /// If `STRICT_STRUCT_CAST` is on, a struct type originating from a named (user-defined) type
+    /// can only be implicitly or assign-cast to the very same named type, even if another struct
+    /// type happens to be structurally identical. Explicit casts are still allowed.
+    #[parameter(default = false)]
+    strict_struct_cast: bool,
+
+    /// If `FILE_SCAN_DRY_RUN` is on, binding a `file_scan(...)` call skips all object-store
+    /// access (listing, schema inference) and instead requires a trailing schema argument like
+    /// `'a INT, b VARCHAR'`. Useful for validating queries offline, e.g. in CI.
+    #[parameter(default = false)]
+    file_scan_dry_run: bool,
+
+    /// If `LENIENT_NUMERIC_CAST` is on, explicit casts from `varchar` to numeric types accept
+    /// locale-style grouping separators, e.g. `'1,234.56'::decimal`. The separators are
+    /// configured by `NUMERIC_SEPARATORS`; strict parsing stays the default.
+    #[parameter(default = false)]
+    lenient_numeric_cast: bool,
+
+    /// The grouping and decimal separator characters used by `LENIENT_NUMERIC_CAST`, as a
+    /// two-character string. Defaults to `,.` (comma groups, dot decimal point).
+    #[parameter(default = ",.", check_hook = check_numeric_separators)]
+    numeric_separators: String,
+
+    /// The rounding mode applied when casting `float4`/`float8` to an integer type. The
+    /// default `half_to_even` matches PG, which rounds float-to-int casts to the nearest even
+    /// integer on ties. `half_away_from_zero` rounds `.5` values away from zero instead, for
+    /// users migrating from engines with that behavior.
+    #[parameter(default = "half_to_even", check_hook = check_float_to_int_rounding)]
+    float_to_int_rounding: String,
+
     /// Sets the order in which schemas are searched when an object (table, data type, function, etc.)
     /// is referenced by a simple name with no schema specified.
     /// See <https://www.postgresql.org/docs/14/runtime-config-client.html#GUC-SEARCH-PATH>
@@ -332,6 +374,26 @@ fn check_client_encoding(val: &str) -> Result<(), String> {
     }
 }
 
+fn check_numeric_separators(val: &str) -> Result<(), String> {
+    let mut chars = val.chars();
+    match (chars.next(), chars.next(), chars.next()) {
+        (Some(grouping), Some(decimal), None) if grouping != decimal => Ok(()),
+        _ => Err(
+            "NUMERIC_SEPARATORS must be two distinct characters: the grouping separator \
+             followed by the decimal separator, e.g. ',.'"
+                .to_string(),
+        ),
+    }
+}
+
+fn check_float_to_int_rounding(val: &str) -> Result<(), String> {
+    if val == "half_to_even" || val == "half_away_from_zero" {
+        Ok(())
+    } else {
+        Err("FLOAT_TO_INT_ROUNDING must be one of: half_to_even, half_away_from_zero".to_string())
+    }
+}
+
 fn check_bytea_output(val: &str) -> Result<(), String> {
     if val == "hex" {
         Ok(())
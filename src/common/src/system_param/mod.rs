@@ -92,6 +92,7 @@ macro_rules! for_all_params {
             { use_new_object_prefix_strategy,           bool,                           None,                           false,  "Whether to split object prefix.", },
             { license_key,                              risingwave_license::LicenseKey, Some(Default::default()),       true,   "The license key to activate enterprise features.", },
             { time_travel_retention_ms,                 u64,                            Some(0_u64),                    true,   "The data retention period for time travel, where 0 indicates that it's disabled.", },
+            { max_secret_size_bytes,                    u64,                            Some(1048576_u64),              true,   "Max size of a single secret in bytes, 1 MiB by default.", },
         }
     };
 }
@@ -450,6 +451,7 @@ mod tests {
             (USE_NEW_OBJECT_PREFIX_STRATEGY_KEY, "false"),
             (LICENSE_KEY_KEY, "foo"),
             (TIME_TRAVEL_RETENTION_MS_KEY, "0"),
+            (MAX_SECRET_SIZE_BYTES_KEY, "1048576"),
             ("a_deprecated_param", "foo"),
         ];
 
@@ -212,4 +212,10 @@ where
             .time_travel_retention_ms
             .unwrap_or_else(default::time_travel_retention_ms)
     }
+
+    fn max_secret_size_bytes(&self) -> u64 {
+        self.inner()
+            .max_secret_size_bytes
+            .unwrap_or_else(default::max_secret_size_bytes)
+    }
 }
@@ -250,6 +250,51 @@ impl_convert_int!(u16);
 impl_convert_int!(u32);
 impl_convert_int!(u64);
 
+/// Conversion to int256 truncates the fractional part toward zero, unlike the
+/// round-half-away-from-zero conversions to the narrower integer types above.
+impl core::convert::TryFrom<Decimal> for crate::types::Int256 {
+    type Error = Error;
+
+    fn try_from(d: Decimal) -> Result<Self, Self::Error> {
+        use rust_decimal::prelude::ToPrimitive;
+
+        match d.trunc() {
+            Decimal::Normalized(d) => {
+                // A truncated `rust_decimal` always fits in an i128 (its mantissa is 96 bits).
+                let i = d
+                    .to_i128()
+                    .ok_or_else(|| Error::ConversionTo("Int256".into()))?;
+                Ok(Self::from(i))
+            }
+            _ => Err(Error::ConversionTo("Int256".into())),
+        }
+    }
+}
+
+/// Conversion from int256 is exact where representable: a value whose magnitude exceeds the
+/// 96-bit decimal mantissa errors out instead of being rounded or saturated.
+impl core::convert::TryFrom<crate::types::Int256> for Decimal {
+    type Error = Error;
+
+    fn try_from(v: crate::types::Int256) -> Result<Self, Self::Error> {
+        use rust_decimal::prelude::FromPrimitive;
+
+        let i =
+            i128::try_from(v.into_inner()).map_err(|_| Error::ConversionTo("Decimal".into()))?;
+        rust_decimal::Decimal::from_i128(i)
+            .map(Decimal::Normalized)
+            .ok_or_else(|| Error::ConversionTo("Decimal".into()))
+    }
+}
+
+impl core::convert::TryFrom<crate::types::Int256Ref<'_>> for Decimal {
+    type Error = Error;
+
+    fn try_from(v: crate::types::Int256Ref<'_>) -> Result<Self, Self::Error> {
+        Self::try_from(crate::types::Int256::from(v))
+    }
+}
+
 checked_proxy!(CheckedRem, checked_rem, %);
 checked_proxy!(CheckedSub, checked_sub, -);
 checked_proxy!(CheckedAdd, checked_add, +);
@@ -983,4 +1028,56 @@ mod tests {
         let decimal = Decimal::Normalized(RustDecimal::try_from(1.0).unwrap());
         assert_eq!(decimal.estimated_size(), 20);
     }
+
+    #[test]
+    fn test_decimal_to_int256() {
+        use crate::types::Int256;
+
+        let to_int256 = |s: &str| Int256::try_from(Decimal::from_str(s).unwrap());
+
+        // The fractional part is truncated toward zero, not rounded.
+        assert_eq!(to_int256("42").unwrap(), Int256::from(42));
+        assert_eq!(to_int256("5.9").unwrap(), Int256::from(5));
+        assert_eq!(to_int256("-5.9").unwrap(), Int256::from(-5));
+        assert_eq!(to_int256("0.4").unwrap(), Int256::from(0));
+
+        // The largest representable decimals fit without overflow.
+        assert_eq!(
+            to_int256("79228162514264337593543950335").unwrap(),
+            Int256::from_str_prefixed("79228162514264337593543950335").unwrap()
+        );
+
+        // Non-finite decimals have no int256 representation.
+        assert!(Int256::try_from(Decimal::NaN).is_err());
+        assert!(Int256::try_from(Decimal::PositiveInf).is_err());
+        assert!(Int256::try_from(Decimal::NegativeInf).is_err());
+    }
+
+    #[test]
+    fn test_int256_to_decimal() {
+        use crate::types::Int256;
+
+        // In-range values convert exactly, including the sign and the mantissa boundary.
+        assert_eq!(
+            Decimal::try_from(Int256::from(42)).unwrap(),
+            Decimal::from(42)
+        );
+        assert_eq!(
+            Decimal::try_from(Int256::from(-42)).unwrap(),
+            Decimal::from(-42)
+        );
+        assert_eq!(
+            Decimal::try_from(
+                Int256::from_str_prefixed("79228162514264337593543950335").unwrap()
+            )
+            .unwrap(),
+            Decimal::from_str("79228162514264337593543950335").unwrap()
+        );
+
+        // Beyond the 96-bit mantissa the conversion errors instead of rounding or saturating.
+        Decimal::try_from(Int256::from_str_prefixed("79228162514264337593543950336").unwrap())
+            .unwrap_err();
+        Decimal::try_from(Int256::max_value()).unwrap_err();
+        Decimal::try_from(Int256::min_value()).unwrap_err();
+    }
 }
@@ -241,7 +241,7 @@ macro_rules! impl_convert_from {
     )*};
 }
 
-impl_convert_from!(i16, i32, i64);
+impl_convert_from!(i16, i32, i64, i128);
 
 impl<'a> From<Int256Ref<'a>> for F64 {
     fn from(value: Int256Ref<'a>) -> Self {
@@ -1034,6 +1034,20 @@ mod tests {
         assert_eq!(ret, OrderedFloat::<f64>::from(5_f64));
     }
 
+    #[test]
+    fn test_float_to_int_rounds_ties_to_even() {
+        // The default float-to-int cast matches PG, which rounds with `rint()`: `.5` values go
+        // to the nearest even integer. Half-away-from-zero rounding is opt-in via the
+        // `FLOAT_TO_INT_ROUNDING` session parameter, rewritten in the frontend.
+        assert_eq!(i32::try_from(OrderedFloat(2.5_f64)), Ok(2));
+        assert_eq!(i32::try_from(OrderedFloat(-2.5_f64)), Ok(-2));
+        assert_eq!(i32::try_from(OrderedFloat(3.5_f64)), Ok(4));
+        assert_eq!(i32::try_from(OrderedFloat(-3.5_f64)), Ok(-4));
+        assert_eq!(i32::try_from(OrderedFloat(2.5_f32)), Ok(2));
+        assert_eq!(i16::try_from(OrderedFloat(2.5_f64)), Ok(2));
+        assert_eq!(i64::try_from(OrderedFloat(3.5_f64)), Ok(4));
+    }
+
     #[test]
     fn test_nan_eq() {
         let nan_prim = f64::NAN;
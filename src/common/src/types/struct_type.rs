@@ -44,6 +44,10 @@ struct StructTypeInner {
     ///     e.g. `ROW(1, 2)`.
     field_names: Box<[String]>,
     field_types: Box<[DataType]>,
+    /// The identity of the named (user-defined) type this struct originates from, if any.
+    /// Purely structural structs have no identity. Note that the identity is not persisted
+    /// through protobuf, so it is only meaningful within the frontend.
+    identity: Option<Box<str>>,
 }
 
 impl StructType {
@@ -58,6 +62,26 @@ impl StructType {
         Self(Arc::new(StructTypeInner {
             field_types: field_types.into(),
             field_names: field_names.into(),
+            identity: None,
+        }))
+    }
+
+    /// Creates a struct type with named fields, carrying the identity of the named
+    /// (user-defined) type it originates from.
+    pub fn new_with_identity(
+        identity: impl Into<Box<str>>,
+        named_fields: Vec<(impl Into<String>, DataType)>,
+    ) -> Self {
+        let mut field_types = Vec::with_capacity(named_fields.len());
+        let mut field_names = Vec::with_capacity(named_fields.len());
+        for (name, ty) in named_fields {
+            field_names.push(name.into());
+            field_types.push(ty);
+        }
+        Self(Arc::new(StructTypeInner {
+            field_types: field_types.into(),
+            field_names: field_names.into(),
+            identity: Some(identity.into()),
         }))
     }
 
@@ -67,6 +91,7 @@ impl StructType {
         Self(Arc::new(StructTypeInner {
             field_types: Box::new([]),
             field_names: Box::new([]),
+            identity: None,
         }))
     }
 
@@ -76,6 +101,7 @@ impl StructType {
         Self(Arc::new(StructTypeInner {
             field_types: field_types.into(),
             field_names: field_names.into(),
+            identity: None,
         }))
     }
 
@@ -84,9 +110,16 @@ impl StructType {
         Self(Arc::new(StructTypeInner {
             field_types: fields.into(),
             field_names: Box::new([]),
+            identity: None,
         }))
     }
 
+    /// Gets the identity of the named (user-defined) type this struct originates from, or
+    /// `None` if it is purely structural.
+    pub fn identity(&self) -> Option<&str> {
+        self.0.identity.as_deref()
+    }
+
     /// Returns the number of fields.
     pub fn len(&self) -> usize {
         self.0.field_types.len()
@@ -172,6 +205,7 @@ impl FromStr for StructType {
         Ok(Self(Arc::new(StructTypeInner {
             field_types: field_types.into(),
             field_names: field_names.into(),
+            identity: None,
         })))
     }
 }
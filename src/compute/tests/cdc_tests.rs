@@ -384,10 +384,13 @@ async fn test_cdc_backfill() -> StreamResult<()> {
         table.clone(),
         vec![ScanRange::full()],
         true,
+        false,
         test_batch_query_epoch(),
         1024,
         "RowSeqExecutor2".to_string(),
         None,
+        false,
+        None,
         None,
         None,
     ));
@@ -15,6 +15,7 @@
 #![feature(coroutines)]
 #![feature(proc_macro_hygiene, stmt_expr_attributes)]
 
+use std::ops::Bound;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 
@@ -263,10 +264,13 @@ async fn test_table_materialize() -> StreamResult<()> {
         table.clone(),
         vec![ScanRange::full()],
         true,
+        false,
         test_batch_query_epoch(),
         1024,
         "RowSeqExecutor2".to_string(),
         None,
+        false,
+        None,
         None,
         None,
     ));
@@ -334,10 +338,13 @@ async fn test_table_materialize() -> StreamResult<()> {
         table.clone(),
         vec![ScanRange::full()],
         true,
+        false,
         test_batch_query_epoch(),
         1024,
         "RowSeqScanExecutor2".to_string(),
         None,
+        false,
+        None,
         None,
         None,
     ));
@@ -414,10 +421,13 @@ async fn test_table_materialize() -> StreamResult<()> {
         table,
         vec![ScanRange::full()],
         true,
+        false,
         test_batch_query_epoch(),
         1024,
         "RowSeqScanExecutor2".to_string(),
         None,
+        false,
+        None,
         None,
         None,
     ));
@@ -490,10 +500,13 @@ async fn test_row_seq_scan() -> StreamResult<()> {
         table,
         vec![ScanRange::full()],
         true,
+        false,
         test_batch_query_epoch(),
         1,
         "RowSeqScanExecutor2".to_string(),
         None,
+        false,
+        None,
         None,
         None,
     ));
@@ -533,3 +546,81 @@ async fn test_row_seq_scan() -> StreamResult<()> {
     );
     Ok(())
 }
+
+#[tokio::test]
+async fn test_row_seq_scan_reversed() -> StreamResult<()> {
+    // A reverse scan over a lower-bounded range returns the rows with `id > 100` in descending
+    // pk order and stops after `limit` rows: the "latest N rows above X" query shape.
+    let memory_state_store = MemoryStateStore::new();
+
+    let column_descs = vec![
+        ColumnDesc::unnamed(ColumnId::from(0), DataType::Int32), // pk
+        ColumnDesc::unnamed(ColumnId::from(1), DataType::Int64),
+    ];
+
+    let mut state = StateTable::from_table_catalog(
+        &gen_pbtable(
+            TableId::from(0x42),
+            column_descs.clone(),
+            vec![OrderType::ascending()],
+            vec![0],
+            0,
+        ),
+        memory_state_store.clone(),
+        None,
+    )
+    .await;
+    let table = StorageTable::for_test(
+        memory_state_store.clone(),
+        TableId::from(0x42),
+        column_descs.clone(),
+        vec![OrderType::ascending()],
+        vec![0],
+        vec![0, 1],
+    );
+
+    let mut epoch = EpochPair::new_test_epoch(test_epoch(1));
+    state.init_epoch(epoch);
+    for id in 98..=103_i32 {
+        state.insert(OwnedRow::new(vec![
+            Some(id.into()),
+            Some((id as i64 * 10).into()),
+        ]));
+    }
+    epoch.inc_for_test();
+    state.commit(epoch).await.unwrap();
+
+    // `WHERE id > 100 ORDER BY id DESC LIMIT 2`: a lower-bounded range scanned backward. The
+    // bound keeps its forward meaning; only the iteration direction flips.
+    let scan_range = ScanRange {
+        pk_prefix: OwnedRow::new(vec![]),
+        next_col_bounds: (Bound::Excluded(Some(100_i32.into())), Bound::Unbounded),
+    };
+    let executor = Box::new(RowSeqScanExecutor::new(
+        table,
+        vec![scan_range],
+        true,
+        true,
+        test_batch_query_epoch(),
+        1024,
+        "RowSeqScanExecutorReversed".to_string(),
+        Some(2),
+        true,
+        None,
+        None,
+        None,
+    ));
+
+    let mut stream = executor.execute();
+    let chunk = stream.next().await.unwrap().unwrap();
+    assert_eq!(
+        chunk.column_at(0).as_int32().iter().collect::<Vec<_>>(),
+        vec![Some(103), Some(102)]
+    );
+    assert_eq!(
+        chunk.column_at(1).as_int64().iter().collect::<Vec<_>>(),
+        vec![Some(1030), Some(1020)]
+    );
+    assert!(stream.next().await.is_none());
+    Ok(())
+}
@@ -151,4 +151,7 @@ pub enum CompressionFormat {
 
     #[serde(rename = "gzip", alias = "gz")]
     Gzip,
+
+    #[serde(rename = "zstd", alias = "zst")]
+    Zstd,
 }
@@ -15,7 +15,7 @@
 use std::future::IntoFuture;
 use std::pin::Pin;
 
-use async_compression::tokio::bufread::GzipDecoder;
+use async_compression::tokio::bufread::{GzipDecoder, ZstdDecoder};
 use async_trait::async_trait;
 use futures::TryStreamExt;
 use futures_async_stream::try_stream;
@@ -162,11 +162,18 @@ impl<Src: OpendalSource> OpendalReader<Src> {
                 let gzip_decoder = GzipDecoder::new(stream_reader);
                 Box::pin(BufReader::new(gzip_decoder)) as Pin<Box<dyn AsyncRead + Send>>
             }
+            CompressionFormat::Zstd => {
+                let zstd_decoder = ZstdDecoder::new(stream_reader);
+                Box::pin(BufReader::new(zstd_decoder)) as Pin<Box<dyn AsyncRead + Send>>
+            }
             CompressionFormat::None => {
                 // todo: support automatic decompression of more compression types.
                 if object_name.ends_with(".gz") || object_name.ends_with(".gzip") {
                     let gzip_decoder = GzipDecoder::new(stream_reader);
                     Box::pin(BufReader::new(gzip_decoder)) as Pin<Box<dyn AsyncRead + Send>>
+                } else if object_name.ends_with(".zst") || object_name.ends_with(".zstd") {
+                    let zstd_decoder = ZstdDecoder::new(stream_reader);
+                    Box::pin(BufReader::new(zstd_decoder)) as Pin<Box<dyn AsyncRead + Send>>
                 } else {
                     Box::pin(BufReader::new(stream_reader)) as Pin<Box<dyn AsyncRead + Send>>
                 }
@@ -12,7 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+use std::future::Future;
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -21,7 +22,8 @@ use bytes::Bytes;
 use futures::future::BoxFuture;
 use futures::TryFutureExt;
 use iceberg::io::{
-    FileIOBuilder, FileMetadata, FileRead, S3_ACCESS_KEY_ID, S3_REGION, S3_SECRET_ACCESS_KEY,
+    FileIOBuilder, FileMetadata, FileRead, S3_ACCESS_KEY_ID, S3_ENDPOINT, S3_REGION,
+    S3_SECRET_ACCESS_KEY,
 };
 use iceberg::{Error, ErrorKind};
 use opendal::layers::RetryLayer;
@@ -30,6 +32,8 @@ use opendal::Operator;
 use parquet::arrow::async_reader::{AsyncFileReader, MetadataLoader};
 use parquet::arrow::ParquetRecordBatchStreamBuilder;
 use parquet::file::metadata::ParquetMetaData;
+use tokio_retry::strategy::{jitter, ExponentialBackoff};
+use tokio_retry::RetryIf;
 use url::Url;
 
 pub struct ParquetFileReader<R: FileRead> {
@@ -62,16 +66,27 @@ impl<R: FileRead> AsyncFileReader for ParquetFileReader<R> {
     }
 }
 
+/// `s3_endpoint` overrides the S3 endpoint for S3-compatible stores (MinIO, Cloudflare R2,
+/// ...); `None` targets AWS S3. With an endpoint set, `path_style_access` selects between
+/// path-style (`endpoint/bucket/key`) and virtual-hosted-style (`bucket.endpoint/key`)
+/// addressing.
 pub async fn create_parquet_stream_builder(
     s3_region: String,
     s3_access_key: String,
     s3_secret_key: String,
+    s3_endpoint: Option<String>,
+    path_style_access: bool,
     location: String,
 ) -> Result<ParquetRecordBatchStreamBuilder<ParquetFileReader<impl FileRead>>, anyhow::Error> {
     let mut props = HashMap::new();
     props.insert(S3_REGION, s3_region.clone());
     props.insert(S3_ACCESS_KEY_ID, s3_access_key.clone());
     props.insert(S3_SECRET_ACCESS_KEY, s3_secret_key.clone());
+    if let Some(endpoint) = s3_endpoint {
+        props.insert(S3_ENDPOINT, endpoint);
+    }
+    // Same property key as `build_iceberg_configs` in `connector_common`.
+    props.insert("s3.path-style-access", path_style_access.to_string());
 
     let file_io_builder = FileIOBuilder::new("s3");
     let file_io = file_io_builder
@@ -89,10 +104,517 @@ pub async fn create_parquet_stream_builder(
         .map_err(|e| anyhow!(e))
 }
 
+/// Statistics of a parquet file, collected from the object metadata and the parquet footer
+/// without reading any data pages.
+pub struct ParquetFileStats {
+    pub num_rows: i64,
+    pub file_size: i64,
+    pub num_row_groups: i32,
+}
+
+impl ParquetFileStats {
+    /// Collects the stats of one file from its object size and parquet footer.
+    fn from_footer(file_size: i64, metadata: &ParquetMetaData) -> Self {
+        Self {
+            num_rows: metadata.file_metadata().num_rows(),
+            file_size,
+            num_row_groups: metadata.num_row_groups() as i32,
+        }
+    }
+}
+
+/// Reads the per-file statistics of a parquet file from its footer. Endpoint and addressing
+/// style behave as in [`create_parquet_stream_builder`].
+pub async fn read_parquet_file_stats(
+    s3_region: String,
+    s3_access_key: String,
+    s3_secret_key: String,
+    s3_endpoint: Option<String>,
+    path_style_access: bool,
+    location: String,
+) -> Result<ParquetFileStats, anyhow::Error> {
+    let mut props = HashMap::new();
+    props.insert(S3_REGION, s3_region.clone());
+    props.insert(S3_ACCESS_KEY_ID, s3_access_key.clone());
+    props.insert(S3_SECRET_ACCESS_KEY, s3_secret_key.clone());
+    if let Some(endpoint) = s3_endpoint {
+        props.insert(S3_ENDPOINT, endpoint);
+    }
+    props.insert("s3.path-style-access", path_style_access.to_string());
+
+    let file_io_builder = FileIOBuilder::new("s3");
+    let file_io = file_io_builder
+        .with_props(props.into_iter())
+        .build()
+        .map_err(|e| anyhow!(e))?;
+    let parquet_file = file_io.new_input(&location).map_err(|e| anyhow!(e))?;
+
+    let parquet_metadata = parquet_file.metadata().await.map_err(|e| anyhow!(e))?;
+    let file_size = parquet_metadata.size as i64;
+    let parquet_reader = parquet_file.reader().await.map_err(|e| anyhow!(e))?;
+    let mut parquet_file_reader = ParquetFileReader::new(parquet_metadata, parquet_reader);
+
+    let metadata = parquet_file_reader.get_metadata().await?;
+    Ok(ParquetFileStats::from_footer(file_size, &metadata))
+}
+
+/// Retries `action` with bounded exponential backoff (100ms base, jittered) while it fails
+/// with a transient object-store error, for at most `attempts` total attempts. Non-transient
+/// errors (authentication, not found, malformed data) fail fast.
+pub async fn retry_on_transient<T, Fut>(
+    attempts: usize,
+    action: impl FnMut() -> Fut,
+) -> Result<T, anyhow::Error>
+where
+    Fut: Future<Output = Result<T, anyhow::Error>>,
+{
+    assert!(attempts >= 1);
+    RetryIf::spawn(
+        ExponentialBackoff::from_millis(100)
+            .map(jitter)
+            .take(attempts - 1),
+        action,
+        is_transient_error,
+    )
+    .await
+}
+
+/// Whether an object-store error is worth retrying. Throttling and other failures the store
+/// marks as temporary are; anything unrecognized fails fast, since retrying an auth or
+/// not-found error only delays the inevitable.
+fn is_transient_error(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        cause
+            .downcast_ref::<opendal::Error>()
+            .is_some_and(|e| e.is_temporary())
+    })
+}
+
+/// Builds an S3 [`Operator`] on `bucket` with the standard retry layer.
+///
+/// `s3_endpoint` overrides the endpoint for S3-compatible stores (MinIO, Cloudflare R2, ...);
+/// `None` targets AWS S3. With an endpoint set, `path_style_access` selects between
+/// path-style (`endpoint/bucket/key`) and virtual-hosted-style (`bucket.endpoint/key`)
+/// addressing; opendal defaults to path-style, which most self-hosted stores expect.
+fn build_s3_operator(
+    s3_region: &str,
+    s3_access_key: &str,
+    s3_secret_key: &str,
+    s3_endpoint: Option<&str>,
+    path_style_access: bool,
+    bucket: &str,
+) -> Result<Operator, anyhow::Error> {
+    let mut builder = S3::default();
+    builder = builder
+        .region(s3_region)
+        .access_key_id(s3_access_key)
+        .secret_access_key(s3_secret_key)
+        .bucket(bucket);
+    if let Some(endpoint) = s3_endpoint {
+        builder = builder.endpoint(endpoint);
+    }
+    if !path_style_access {
+        builder = builder.enable_virtual_host_style();
+    }
+    Ok(Operator::new(builder)?
+        .layer(RetryLayer::default())
+        .finish())
+}
+
+/// Reads a manifest file containing newline-separated object keys and resolves them into
+/// absolute `s3://` locations to scan.
+///
+/// Keys in the manifest may be absolute (`s3://bucket/path/to/file`) or relative, in which case
+/// they are resolved against the directory containing the manifest. Empty lines are skipped.
+/// Each entry within the manifest's bucket is checked for existence, so that a manifest
+/// referencing a missing file fails with a clear error instead of a confusing one at read time.
+pub async fn read_manifest_list(
+    s3_region: String,
+    s3_access_key: String,
+    s3_secret_key: String,
+    s3_endpoint: Option<String>,
+    path_style_access: bool,
+    manifest_location: String,
+) -> Result<Vec<String>, anyhow::Error> {
+    let url = Url::parse(&manifest_location)?;
+    let bucket = url.host_str().ok_or_else(|| {
+        Error::new(
+            ErrorKind::DataInvalid,
+            format!("Invalid s3 url: {}, missing bucket", manifest_location),
+        )
+    })?;
+
+    let prefix = format!("s3://{}/", bucket);
+    if !manifest_location.starts_with(&prefix) {
+        return Err(Error::new(
+            ErrorKind::DataInvalid,
+            format!(
+                "Invalid s3 url: {}, should start with {}",
+                manifest_location, prefix
+            ),
+        ))?;
+    }
+
+    let op = build_s3_operator(
+        &s3_region,
+        &s3_access_key,
+        &s3_secret_key,
+        s3_endpoint.as_deref(),
+        path_style_access,
+        bucket,
+    )?;
+
+    let content = op.read(&manifest_location[prefix.len()..]).await?;
+    let content = String::from_utf8(content.to_vec())?;
+
+    let manifest_dir = match manifest_location.rfind('/') {
+        Some(idx) => &manifest_location[..=idx],
+        None => unreachable!("manifest location always contains the bucket prefix"),
+    };
+    let files = resolve_manifest_entries(manifest_dir, &content);
+
+    for file in &files {
+        if let Some(key) = file.strip_prefix(&prefix) {
+            op.stat(key).await.map_err(|e| {
+                anyhow!(e).context(format!("file {} listed in manifest does not exist", file))
+            })?;
+        }
+    }
+
+    Ok(files)
+}
+
+/// Resolves the newline-separated entries of a manifest against the directory containing the
+/// manifest. Absolute `s3://` entries are kept as-is.
+fn resolve_manifest_entries(manifest_dir: &str, content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            if line.starts_with("s3://") {
+                line.to_string()
+            } else {
+                format!("{}{}", manifest_dir, line.trim_start_matches('/'))
+            }
+        })
+        .collect()
+}
+
+/// Splits an `s3://bucket/key` location into an [`Operator`] on the bucket and the key within
+/// it.
+fn operator_and_key(
+    s3_region: &str,
+    s3_access_key: &str,
+    s3_secret_key: &str,
+    s3_endpoint: Option<&str>,
+    path_style_access: bool,
+    location: &str,
+) -> Result<(Operator, String), anyhow::Error> {
+    let url = Url::parse(location)?;
+    let bucket = url.host_str().ok_or_else(|| {
+        Error::new(
+            ErrorKind::DataInvalid,
+            format!("Invalid s3 url: {}, missing bucket", location),
+        )
+    })?;
+
+    let prefix = format!("s3://{}/", bucket);
+    if !location.starts_with(&prefix) {
+        return Err(Error::new(
+            ErrorKind::DataInvalid,
+            format!(
+                "Invalid s3 url: {}, should start with {}",
+                location, prefix
+            ),
+        ))?;
+    }
+
+    let op = build_s3_operator(
+        s3_region,
+        s3_access_key,
+        s3_secret_key,
+        s3_endpoint,
+        path_style_access,
+        bucket,
+    )?;
+    Ok((op, location[prefix.len()..].to_string()))
+}
+
+/// Compression codecs the text readers decompress transparently, detected from the object's
+/// file extension like the filesystem source does for its objects.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TextCompression {
+    None,
+    Gzip,
+    Zstd,
+}
+
+/// Detects the compression codec of a text object from its file extension, e.g.
+/// `events.jsonl.gz`. Unrecognized extensions read as plain text.
+pub fn detect_text_compression(location: &str) -> TextCompression {
+    if location.ends_with(".gz") || location.ends_with(".gzip") {
+        TextCompression::Gzip
+    } else if location.ends_with(".zst") || location.ends_with(".zstd") {
+        TextCompression::Zstd
+    } else {
+        TextCompression::None
+    }
+}
+
+/// Decompresses a whole text object per its detected codec; `None` passes the bytes through.
+async fn decompress_text(
+    buf: Vec<u8>,
+    compression: TextCompression,
+) -> Result<Vec<u8>, anyhow::Error> {
+    use async_compression::tokio::bufread::{GzipDecoder, ZstdDecoder};
+    use tokio::io::AsyncReadExt;
+
+    let mut decompressed = Vec::new();
+    match compression {
+        TextCompression::None => return Ok(buf),
+        TextCompression::Gzip => {
+            GzipDecoder::new(buf.as_slice())
+                .read_to_end(&mut decompressed)
+                .await?
+        }
+        TextCompression::Zstd => {
+            ZstdDecoder::new(buf.as_slice())
+                .read_to_end(&mut decompressed)
+                .await?
+        }
+    };
+    Ok(decompressed)
+}
+
+/// Byte chunk size for the ranged reads in [`read_text_head`].
+const TEXT_HEAD_CHUNK_SIZE: u64 = 256 * 1024;
+
+/// Reads the first `max_lines` non-empty lines of a newline-delimited text object, e.g. for
+/// NDJSON schema inference at binding time. A plain object is fetched in fixed-size ranges
+/// until enough complete lines are buffered, so sampling a multi-gigabyte export stays cheap;
+/// a gzip/zstd object (by extension) must be fetched and decompressed whole first, since its
+/// byte ranges do not map to line ranges.
+pub async fn read_text_head(
+    s3_region: String,
+    s3_access_key: String,
+    s3_secret_key: String,
+    s3_endpoint: Option<String>,
+    path_style_access: bool,
+    location: String,
+    max_lines: usize,
+) -> Result<Vec<String>, anyhow::Error> {
+    let (op, key) = operator_and_key(
+        &s3_region,
+        &s3_access_key,
+        &s3_secret_key,
+        s3_endpoint.as_deref(),
+        path_style_access,
+        &location,
+    )?;
+
+    let compression = detect_text_compression(&location);
+    let content = if compression != TextCompression::None {
+        let buf = op.read(&key).await?;
+        String::from_utf8(decompress_text(buf.to_vec(), compression).await?)?
+    } else {
+        let len = op.stat(&key).await?.content_length();
+        let mut buf: Vec<u8> = vec![];
+        let mut offset = 0u64;
+        while offset < len {
+            let end = (offset + TEXT_HEAD_CHUNK_SIZE).min(len);
+            let chunk = op.read_with(&key).range(offset..end).await?;
+            buf.extend_from_slice(&chunk.to_vec());
+            offset = end;
+            if buf.iter().filter(|b| **b == b'\n').count() >= max_lines {
+                break;
+            }
+        }
+        if offset < len {
+            // The read stopped mid-object, so the buffer may end in a partial line (and even a
+            // partial UTF-8 character); drop everything after the last newline. One exists,
+            // since stopping early requires `max_lines >= 1` newlines in the buffer.
+            let last_newline = buf
+                .iter()
+                .rposition(|b| *b == b'\n')
+                .expect("stopped early only after seeing a newline");
+            buf.truncate(last_newline + 1);
+        }
+        String::from_utf8(buf)?
+    };
+
+    Ok(content
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .take(max_lines)
+        .map(|line| line.to_string())
+        .collect())
+}
+
+/// Reads an entire newline-delimited text object into a string, for the NDJSON scan executor.
+/// Objects with a gzip/zstd file extension are decompressed transparently.
+pub async fn read_text_file(
+    s3_region: String,
+    s3_access_key: String,
+    s3_secret_key: String,
+    s3_endpoint: Option<String>,
+    path_style_access: bool,
+    location: String,
+) -> Result<String, anyhow::Error> {
+    let (op, key) = operator_and_key(
+        &s3_region,
+        &s3_access_key,
+        &s3_secret_key,
+        s3_endpoint.as_deref(),
+        path_style_access,
+        &location,
+    )?;
+    let content = op.read(&key).await?;
+    let content = decompress_text(content.to_vec(), detect_text_compression(&location)).await?;
+    Ok(String::from_utf8(content)?)
+}
+
+/// Reads the Delta transaction log under `<table_location>/_delta_log/` and returns the
+/// absolute locations of the table's active parquet files, honoring `remove` tombstones.
+///
+/// The JSON commits are replayed from version 0. Checkpoint parquet files are not parsed:
+/// replaying the JSON commits alone yields the same state as long as none of them have been
+/// cleaned up, and a `_last_checkpoint` pointing at a checkpoint that is still being written
+/// (or was never completed) is thereby tolerated. If commits before a checkpoint have already
+/// been vacuumed, we fail instead of silently returning a partial file set.
+pub async fn read_delta_table_files(
+    s3_region: String,
+    s3_access_key: String,
+    s3_secret_key: String,
+    s3_endpoint: Option<String>,
+    path_style_access: bool,
+    table_location: String,
+) -> Result<Vec<String>, anyhow::Error> {
+    let table_location = table_location.trim_end_matches('/').to_string();
+    let url = Url::parse(&table_location)?;
+    let bucket = url.host_str().ok_or_else(|| {
+        Error::new(
+            ErrorKind::DataInvalid,
+            format!("Invalid s3 url: {}, missing bucket", table_location),
+        )
+    })?;
+
+    let prefix = format!("s3://{}/", bucket);
+    if !table_location.starts_with(&prefix) {
+        return Err(Error::new(
+            ErrorKind::DataInvalid,
+            format!(
+                "Invalid s3 url: {}, should start with {}",
+                table_location, prefix
+            ),
+        ))?;
+    }
+
+    let op = build_s3_operator(
+        &s3_region,
+        &s3_access_key,
+        &s3_secret_key,
+        s3_endpoint.as_deref(),
+        path_style_access,
+        bucket,
+    )?;
+
+    let log_dir = format!("{}/_delta_log/", &table_location[prefix.len()..]);
+    let mut commits: Vec<(u64, String)> = op
+        .list(&log_dir)
+        .await
+        .map_err(|e| anyhow!(e))?
+        .into_iter()
+        .filter_map(|entry| {
+            delta_commit_version(entry.name()).map(|version| (version, entry.path().to_string()))
+        })
+        .collect();
+    commits.sort();
+
+    if commits.is_empty() {
+        return Err(anyhow!(
+            "{} is not a Delta table: no JSON commits found under _delta_log",
+            table_location
+        ));
+    }
+    for (i, (version, _)) in commits.iter().enumerate() {
+        if *version != i as u64 {
+            return Err(anyhow!(
+                "Delta log of {} is missing commit version {}, likely cleaned up after a \
+                 checkpoint; reading checkpointed Delta logs is not supported yet",
+                table_location,
+                i
+            ));
+        }
+    }
+
+    let mut contents = Vec::with_capacity(commits.len());
+    for (_, key) in &commits {
+        let content = op.read(key).await.map_err(|e| anyhow!(e))?;
+        contents.push(String::from_utf8(content.to_vec())?);
+    }
+    replay_delta_commits(&format!("{}/", table_location), &contents)
+}
+
+/// Applies the `add` and `remove` actions of the given commit contents (oldest first) and
+/// resolves the surviving file paths against the table directory. Other actions (`metaData`,
+/// `protocol`, `commitInfo`, `txn`) do not affect the file set and are skipped.
+fn replay_delta_commits(
+    table_dir: &str,
+    commits: &[String],
+) -> Result<Vec<String>, anyhow::Error> {
+    let mut active = BTreeSet::new();
+    for commit in commits {
+        for line in commit.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let action: serde_json::Value = serde_json::from_str(line)?;
+            if let Some(add) = action.get("add") {
+                let path = add
+                    .get("path")
+                    .and_then(|p| p.as_str())
+                    .ok_or_else(|| anyhow!("`add` action without a path"))?;
+                active.insert(path.to_string());
+            } else if let Some(remove) = action.get("remove") {
+                let path = remove
+                    .get("path")
+                    .and_then(|p| p.as_str())
+                    .ok_or_else(|| anyhow!("`remove` action without a path"))?;
+                active.remove(path);
+            }
+        }
+    }
+    Ok(active
+        .into_iter()
+        .map(|path| {
+            if path.starts_with("s3://") {
+                path
+            } else {
+                format!("{}{}", table_dir, path)
+            }
+        })
+        .collect())
+}
+
+/// Parses the version of a Delta JSON commit file name, e.g. `00000000000000000003.json`.
+/// Checkpoint parquet files and `_last_checkpoint` do not match.
+fn delta_commit_version(name: &str) -> Option<u64> {
+    let stem = name.strip_suffix(".json")?;
+    if stem.len() != 20 {
+        return None;
+    }
+    stem.parse().ok()
+}
+
 pub async fn list_s3_directory(
     s3_region: String,
     s3_access_key: String,
     s3_secret_key: String,
+    s3_endpoint: Option<String>,
+    path_style_access: bool,
     dir: String,
 ) -> Result<Vec<String>, anyhow::Error> {
     let url = Url::parse(&dir)?;
@@ -105,15 +627,14 @@ pub async fn list_s3_directory(
 
     let prefix = format!("s3://{}/", bucket);
     if dir.starts_with(&prefix) {
-        let mut builder = S3::default();
-        builder = builder
-            .region(&s3_region)
-            .access_key_id(&s3_access_key)
-            .secret_access_key(&s3_secret_key)
-            .bucket(bucket);
-        let op = Operator::new(builder)?
-            .layer(RetryLayer::default())
-            .finish();
+        let op = build_s3_operator(
+            &s3_region,
+            &s3_access_key,
+            &s3_secret_key,
+            s3_endpoint.as_deref(),
+            path_style_access,
+            bucket,
+        )?;
 
         op.list(&dir[prefix.len()..])
             .await
@@ -130,3 +651,294 @@ pub async fn list_s3_directory(
         ))?
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay_delta_log_fixture() {
+        // A small on-disk Delta log: commit 0 creates the table with two files, commit 1
+        // removes one of them and adds another.
+        let fixture_dir =
+            std::env::temp_dir().join(format!("delta_log_test_{}", std::process::id()));
+        let log_dir = fixture_dir.join("_delta_log");
+        std::fs::create_dir_all(&log_dir).unwrap();
+        std::fs::write(
+            log_dir.join("00000000000000000000.json"),
+            concat!(
+                r#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}"#,
+                "\n",
+                r#"{"metaData":{"id":"00000000-0000-0000-0000-000000000000","format":{"provider":"parquet"}}}"#,
+                "\n",
+                r#"{"add":{"path":"part-00000.parquet","size":100,"dataChange":true}}"#,
+                "\n",
+                r#"{"add":{"path":"part-00001.parquet","size":100,"dataChange":true}}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+        std::fs::write(
+            log_dir.join("00000000000000000001.json"),
+            concat!(
+                r#"{"remove":{"path":"part-00000.parquet","dataChange":true}}"#,
+                "\n",
+                r#"{"add":{"path":"part-00002.parquet","size":100,"dataChange":true}}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+        // A checkpoint hint must not be picked up as a commit.
+        std::fs::write(log_dir.join("_last_checkpoint"), r#"{"version":1,"size":3}"#).unwrap();
+
+        let mut commits: Vec<(u64, std::path::PathBuf)> = std::fs::read_dir(&log_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap())
+            .filter_map(|entry| {
+                delta_commit_version(entry.file_name().to_str().unwrap())
+                    .map(|version| (version, entry.path()))
+            })
+            .collect();
+        commits.sort();
+        assert_eq!(commits.iter().map(|(v, _)| *v).collect::<Vec<_>>(), [0, 1]);
+
+        let contents: Vec<String> = commits
+            .iter()
+            .map(|(_, path)| std::fs::read_to_string(path).unwrap())
+            .collect();
+        let files = replay_delta_commits("s3://bucket/table/", &contents).unwrap();
+        assert_eq!(
+            files,
+            vec![
+                "s3://bucket/table/part-00001.parquet",
+                "s3://bucket/table/part-00002.parquet",
+            ]
+        );
+
+        std::fs::remove_dir_all(&fixture_dir).unwrap();
+    }
+
+    #[test]
+    fn test_delta_commit_version() {
+        assert_eq!(delta_commit_version("00000000000000000003.json"), Some(3));
+        assert_eq!(delta_commit_version("3.json"), None);
+        assert_eq!(
+            delta_commit_version("00000000000000000001.checkpoint.parquet"),
+            None
+        );
+        assert_eq!(delta_commit_version("_last_checkpoint"), None);
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_transient() {
+        // Throttled twice, then the schema is returned on the third attempt.
+        let attempts = std::sync::atomic::AtomicUsize::new(0);
+        let schema = retry_on_transient(3, || {
+            let attempt = attempts.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            async move {
+                if attempt < 2 {
+                    Err(anyhow!(opendal::Error::new(
+                        opendal::ErrorKind::RateLimited,
+                        "slow down"
+                    )
+                    .set_temporary()))
+                } else {
+                    Ok("a INT, b VARCHAR")
+                }
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(schema, "a INT, b VARCHAR");
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::Relaxed), 3);
+
+        // The attempt bound is honored.
+        let attempts = std::sync::atomic::AtomicUsize::new(0);
+        retry_on_transient(3, || {
+            attempts.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            async {
+                Err::<(), _>(anyhow!(opendal::Error::new(
+                    opendal::ErrorKind::RateLimited,
+                    "slow down"
+                )
+                .set_temporary()))
+            }
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::Relaxed), 3);
+
+        // Non-transient errors (auth, not found) fail fast without a second attempt.
+        let attempts = std::sync::atomic::AtomicUsize::new(0);
+        retry_on_transient(3, || {
+            attempts.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            async {
+                Err::<(), _>(anyhow!(opendal::Error::new(
+                    opendal::ErrorKind::NotFound,
+                    "no such key"
+                )))
+            }
+        })
+        .await
+        .unwrap_err();
+        assert_eq!(attempts.load(std::sync::atomic::Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_parquet_file_stats_from_footer() {
+        use std::fs::File;
+
+        use parquet::data_type::Int64Type;
+        use parquet::file::properties::WriterProperties;
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+        use parquet::file::writer::SerializedFileWriter;
+        use parquet::schema::parser::parse_message_type;
+
+        // A mock directory of two parquet files with different row counts, as
+        // `file_scan_metadata` would list and inspect them.
+        let dir =
+            std::env::temp_dir().join(format!("parquet_stats_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let schema = Arc::new(parse_message_type("message m { required int64 id; }").unwrap());
+        let files = [("a.parquet", 3i64), ("b.parquet", 5)];
+        for (name, rows) in files {
+            let file = File::create(dir.join(name)).unwrap();
+            let mut writer = SerializedFileWriter::new(
+                file,
+                schema.clone(),
+                Arc::new(WriterProperties::builder().build()),
+            )
+            .unwrap();
+            let mut row_group = writer.next_row_group().unwrap();
+            let mut column = row_group.next_column().unwrap().unwrap();
+            let values: Vec<i64> = (0..rows).collect();
+            column
+                .typed::<Int64Type>()
+                .write_batch(&values, None, None)
+                .unwrap();
+            column.close().unwrap();
+            row_group.close().unwrap();
+            writer.close().unwrap();
+        }
+
+        for (name, rows) in files {
+            let path = dir.join(name);
+            let file_size = std::fs::metadata(&path).unwrap().len() as i64;
+            let reader = SerializedFileReader::new(File::open(&path).unwrap()).unwrap();
+            let stats = ParquetFileStats::from_footer(file_size, reader.metadata());
+            assert_eq!(stats.num_rows, rows);
+            assert_eq!(stats.file_size, file_size);
+            assert_eq!(stats.num_row_groups, 1);
+        }
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_detect_text_compression() {
+        assert_eq!(
+            detect_text_compression("s3://bucket/dir/a.jsonl.gz"),
+            TextCompression::Gzip
+        );
+        assert_eq!(
+            detect_text_compression("s3://bucket/dir/a.json.gzip"),
+            TextCompression::Gzip
+        );
+        assert_eq!(
+            detect_text_compression("s3://bucket/dir/a.jsonl.zst"),
+            TextCompression::Zstd
+        );
+        assert_eq!(
+            detect_text_compression("s3://bucket/dir/a.jsonl.zstd"),
+            TextCompression::Zstd
+        );
+        assert_eq!(
+            detect_text_compression("s3://bucket/dir/a.jsonl"),
+            TextCompression::None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_decompress_text_round_trip() {
+        use async_compression::tokio::bufread::{GzipEncoder, ZstdEncoder};
+        use tokio::io::AsyncReadExt;
+
+        // A mock directory of two NDJSON files. Both the inference sampling and the scan
+        // executor read through `decompress_text`, so recovering the exact lines means a
+        // compressed directory infers the same schema as its uncompressed equivalent.
+        let files: [&[u8]; 2] = [
+            b"{\"a\": 1, \"b\": \"x\"}\n{\"a\": 2, \"b\": \"y\"}\n",
+            b"{\"a\": 3, \"c\": 1.5}\n",
+        ];
+        for content in files {
+            let mut gzipped = Vec::new();
+            GzipEncoder::new(content)
+                .read_to_end(&mut gzipped)
+                .await
+                .unwrap();
+            assert_ne!(gzipped, content);
+            assert_eq!(
+                decompress_text(gzipped, TextCompression::Gzip).await.unwrap(),
+                content
+            );
+
+            let mut zstded = Vec::new();
+            ZstdEncoder::new(content)
+                .read_to_end(&mut zstded)
+                .await
+                .unwrap();
+            assert_eq!(
+                decompress_text(zstded, TextCompression::Zstd).await.unwrap(),
+                content
+            );
+
+            // Plain text passes through untouched.
+            assert_eq!(
+                decompress_text(content.to_vec(), TextCompression::None)
+                    .await
+                    .unwrap(),
+                content
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_s3_operator_endpoint_override() {
+        // The operator must build against the default AWS endpoint as well as a custom
+        // endpoint with either addressing style; credential and endpoint validation only
+        // happens on the first request.
+        for (endpoint, path_style_access) in [
+            (None, true),
+            (Some("http://127.0.0.1:9000"), true),
+            (Some("http://127.0.0.1:9000"), false),
+        ] {
+            let op = build_s3_operator(
+                "us-east-1",
+                "minioadmin",
+                "minioadmin",
+                endpoint,
+                path_style_access,
+                "bucket",
+            )
+            .unwrap();
+            assert_eq!(op.info().name(), "bucket");
+        }
+    }
+
+    #[test]
+    fn test_resolve_manifest_entries() {
+        let files = resolve_manifest_entries(
+            "s3://bucket/dir/",
+            "a.parquet\n\nsub/b.parquet\ns3://other/c.parquet\n  \n/d.parquet\n",
+        );
+        assert_eq!(
+            files,
+            vec![
+                "s3://bucket/dir/a.parquet",
+                "s3://bucket/dir/sub/b.parquet",
+                "s3://other/c.parquet",
+                "s3://bucket/dir/d.parquet",
+            ]
+        );
+    }
+}
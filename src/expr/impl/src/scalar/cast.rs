@@ -21,7 +21,10 @@ use itertools::Itertools;
 use risingwave_common::array::{ArrayImpl, DataChunk, ListRef, ListValue, StructRef, StructValue};
 use risingwave_common::cast;
 use risingwave_common::row::OwnedRow;
-use risingwave_common::types::{Int256, JsonbRef, MapRef, MapValue, ToText, F64};
+use risingwave_common::types::{
+    DataType, Decimal, Int256, Int256Ref, Interval, JsonbRef, JsonbVal, MapRef, MapValue,
+    ScalarImpl, Time, Timestamp, Timestamptz, ToText, F64,
+};
 use risingwave_common::util::iter_util::ZipEqFast;
 use risingwave_expr::expr::{build_func, Context, ExpressionBoxExt, InputRefExpression};
 use risingwave_expr::{function, ExprError, Result};
@@ -47,6 +50,69 @@ where
     })
 }
 
+/// Parses a numeric literal with locale-style separators: `grouping` separators are allowed
+/// between digits of the integer part, and `decimal` marks the fraction. The frontend binds
+/// explicit `varchar` to numeric casts to this function when `LENIENT_NUMERIC_CAST` is on;
+/// the strict [`str_parse`] path stays the default.
+#[function("parse_numeric(varchar, varchar, varchar) -> decimal")]
+pub fn parse_numeric(elem: &str, grouping: &str, decimal: &str) -> Result<Decimal> {
+    let (Some(grouping), Some(decimal)) = (single_char(grouping), single_char(decimal)) else {
+        return Err(ExprError::InvalidParam {
+            name: "separators",
+            reason: "grouping and decimal separators must be single characters".into(),
+        });
+    };
+    if grouping == decimal {
+        return Err(ExprError::InvalidParam {
+            name: "separators",
+            reason: "grouping and decimal separators must differ".into(),
+        });
+    }
+
+    let parse_err = || ExprError::Parse(format!("numeric {:?}", elem).into());
+    let s = elem.trim();
+    if s.is_empty() {
+        return Err(parse_err());
+    }
+    let (int_part, frac_part) = match s.split_once(decimal) {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (s, None),
+    };
+
+    // Grouping separators must sit between digits; anything else (leading, doubled, or
+    // trailing separators) is ambiguous and rejected rather than silently dropped.
+    let chars = int_part.chars().collect::<Vec<_>>();
+    let mut normalized = String::with_capacity(s.len());
+    for (i, &c) in chars.iter().enumerate() {
+        if c == grouping {
+            let digit_before = i > 0 && chars[i - 1].is_ascii_digit();
+            let digit_after = chars.get(i + 1).is_some_and(|c| c.is_ascii_digit());
+            if !(digit_before && digit_after) {
+                return Err(parse_err());
+            }
+        } else {
+            normalized.push(c);
+        }
+    }
+    if let Some(frac_part) = frac_part {
+        // A second decimal separator or a grouping separator in the fraction is malformed.
+        if frac_part.contains(decimal) || frac_part.contains(grouping) {
+            return Err(parse_err());
+        }
+        normalized.push('.');
+        normalized.push_str(frac_part);
+    }
+    normalized.parse().map_err(|_| parse_err())
+}
+
+fn single_char(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
 // TODO: introduce `FromBinary` and support all types
 #[function("pgwire_recv(bytea) -> int8")]
 pub fn pgwire_recv(elem: &[u8]) -> Result<i64> {
@@ -58,11 +124,21 @@ pub fn pgwire_recv(elem: &[u8]) -> Result<i64> {
 #[function("cast(int2) -> int256")]
 #[function("cast(int4) -> int256")]
 #[function("cast(int8) -> int256")]
+#[function("cast(decimal) -> int256")]
 pub fn to_int256<T: TryInto<Int256>>(elem: T) -> Result<Int256> {
     elem.try_into()
         .map_err(|_| ExprError::CastOutOfRange("int256"))
 }
 
+/// Casts an int256 to a decimal. The conversion is exact where representable; an int256 whose
+/// magnitude exceeds the 96-bit decimal mantissa errors out instead of being rounded or
+/// saturated.
+#[function("cast(int256) -> decimal")]
+pub fn int256_to_decimal(elem: Int256Ref<'_>) -> Result<Decimal> {
+    elem.try_into()
+        .map_err(|_| ExprError::CastOutOfRange("decimal"))
+}
+
 #[function("cast(jsonb) -> boolean")]
 pub fn jsonb_to_bool(v: JsonbRef<'_>) -> Result<bool> {
     v.as_bool().map_err(|e| ExprError::Parse(e.into()))
@@ -83,10 +159,47 @@ pub fn jsonb_to_number<T: TryFrom<F64>>(v: JsonbRef<'_>) -> Result<T> {
         .map_err(|_| ExprError::NumericOutOfRange)
 }
 
+/// Converts a timestamp into a JSON string value in ISO 8601 format (`T` separator), matching
+/// the `to_jsonb` representation.
+#[function("cast(timestamp) -> jsonb")]
+pub fn timestamp_to_jsonb(v: Timestamp) -> JsonbVal {
+    jsonbb::Value::from(format!("{}T{}", v.0.date(), v.0.time()).as_str()).into()
+}
+
+/// Converts a timestamptz into a JSON string value in RFC 3339 format with an explicit UTC
+/// offset, matching the `to_jsonb` representation. The instant is rendered in UTC, so the
+/// result is independent of the session time zone.
+#[function("cast(timestamptz) -> jsonb")]
+pub fn timestamptz_to_jsonb(v: Timestamptz) -> JsonbVal {
+    jsonbb::Value::from(v.to_datetime_utc().to_rfc3339().as_str()).into()
+}
+
+/// Parses a JSON string value as a timestamp. Both the `T`-separated ISO 8601 form produced by
+/// [`timestamp_to_jsonb`] and the space-separated form are accepted.
+#[function("cast(jsonb) -> timestamp")]
+pub fn jsonb_to_timestamp(v: JsonbRef<'_>) -> Result<Timestamp> {
+    v.as_string()
+        .map_err(|e| ExprError::Parse(e.into()))?
+        .parse()
+        .map_err(|err: <Timestamp as FromStr>::Err| {
+            ExprError::Parse(err.to_report_string().into())
+        })
+}
+
+/// Parses a JSON string value as a timestamptz. The string must carry an explicit UTC offset
+/// (or `Z`), so the result is independent of the session time zone; for local-time strings,
+/// cast to `timestamp` and apply `AT TIME ZONE` instead.
+#[function("cast(jsonb) -> timestamptz")]
+pub fn jsonb_to_timestamptz(v: JsonbRef<'_>) -> Result<Timestamptz> {
+    v.as_string()
+        .map_err(|e| ExprError::Parse(e.into()))?
+        .parse()
+        .map_err(|err: <Timestamptz as FromStr>::Err| ExprError::Parse(err.into()))
+}
+
 #[function("cast(int4) -> int2")]
 #[function("cast(int8) -> int2")]
 #[function("cast(int8) -> int4")]
-#[function("cast(serial) -> int8")]
 #[function("cast(float4) -> int2")]
 #[function("cast(float8) -> int2")]
 #[function("cast(float4) -> int4")]
@@ -109,7 +222,9 @@ where
         .map_err(|_| ExprError::CastOutOfRange(std::any::type_name::<T2>()))
 }
 
+#[function("cast(boolean) -> int2")]
 #[function("cast(boolean) -> int4")]
+#[function("cast(boolean) -> int8")]
 #[function("cast(int2) -> int4")]
 #[function("cast(int2) -> int8")]
 #[function("cast(int2) -> float4")]
@@ -123,11 +238,12 @@ where
 #[function("cast(int8) -> float8")]
 #[function("cast(int8) -> decimal")]
 #[function("cast(float4) -> float8")]
+#[function("cast(serial) -> int8")]
+#[function("cast(int8) -> serial")]
 #[function("cast(date) -> timestamp")]
 #[function("cast(time) -> interval")]
 #[function("cast(timestamp) -> date")]
 #[function("cast(timestamp) -> time")]
-#[function("cast(interval) -> time")]
 #[function("cast(varchar) -> varchar")]
 #[function("cast(int256) -> float8")]
 pub fn cast<T1, T2>(elem: T1) -> T2
@@ -137,6 +253,25 @@ where
     elem.into()
 }
 
+/// Casts an interval to a time of day.
+///
+/// The interval must itself denote a valid time of day: no month or day component, and a
+/// microsecond component within `[00:00:00, 24:00:00)`. Anything else — `'25 hours'`, `'1 day'`,
+/// a negative interval — errors out instead of silently wrapping around midnight, so
+/// `time -> interval -> time` round-trips exactly and an out-of-range interval never produces a
+/// surprising time.
+#[function("cast(interval) -> time")]
+pub fn interval_to_time(elem: Interval) -> Result<Time> {
+    if elem.months() != 0
+        || elem.days() != 0
+        || elem.usecs() < 0
+        || elem.usecs() >= Interval::USECS_PER_DAY
+    {
+        return Err(ExprError::CastOutOfRange("time"));
+    }
+    Ok(elem.into())
+}
+
 #[function("cast(varchar) -> boolean")]
 pub fn str_to_bool(input: &str) -> Result<bool> {
     cast::str_to_bool(input).map_err(|err| ExprError::Parse(err.into()))
@@ -150,6 +285,7 @@ pub fn int_to_bool(input: i32) -> bool {
 /// For most of the types, cast them to varchar is the same as their pgwire "TEXT" format.
 /// So we use `ToText` to cast type to varchar.
 #[function("cast(*int) -> varchar")]
+#[function("cast(serial) -> varchar")]
 #[function("cast(decimal) -> varchar")]
 #[function("cast(*float) -> varchar")]
 #[function("cast(int256) -> varchar")]
@@ -195,8 +331,61 @@ fn str_to_list(input: &str, ctx: &Context) -> Result<ListValue> {
 }
 
 /// Cast array with `source_elem_type` into array with `target_elem_type` by casting each element.
+///
+/// Additionally supports explicit casts that change the dimensionality by one level:
+/// - Flatten, e.g. `int[][]::int[]`: concatenates the inner arrays in row-major order. `NULL`
+///   inner arrays contribute no elements.
+/// - Nest, e.g. `int[]::int[][]`: wraps each element (including `NULL`s) in a singleton inner
+///   array.
 #[function("cast(anyarray) -> anyarray", type_infer = "unreachable")]
 fn list_cast(input: ListRef<'_>, ctx: &Context) -> Result<ListValue> {
+    let source_elem = ctx.arg_types[0].as_list();
+    let target_elem = ctx.return_type.as_list();
+    if let DataType::List(source_inner) = source_elem
+        && !matches!(target_elem, DataType::List(_) | DataType::Varchar)
+    {
+        // Flatten one nesting level, then cast element-wise if needed.
+        let mut builder = source_inner.create_array_builder(input.len());
+        for datum in input.iter() {
+            if let Some(scalar) = datum {
+                for elem in scalar.into_list().iter() {
+                    builder.append(elem);
+                }
+            }
+        }
+        let flattened = ListValue::new(builder.finish());
+        if **source_inner == *target_elem {
+            return Ok(flattened);
+        }
+        let new_ctx = Context {
+            arg_types: vec![DataType::List(source_inner.clone())],
+            return_type: ctx.return_type.clone(),
+            variadic: ctx.variadic,
+        };
+        return list_cast(flattened.as_scalar_ref(), &new_ctx);
+    }
+    if let DataType::List(target_inner) = target_elem
+        && !matches!(source_elem, DataType::List(_) | DataType::Varchar)
+    {
+        // Cast element-wise if needed, then nest each element into a singleton array.
+        let input = if source_elem == &**target_inner {
+            input.to_owned()
+        } else {
+            let new_ctx = Context {
+                arg_types: ctx.arg_types.clone(),
+                return_type: DataType::List(target_inner.clone()),
+                variadic: ctx.variadic,
+            };
+            list_cast(input, &new_ctx)?
+        };
+        let mut builder = target_elem.create_array_builder(input.len());
+        for datum in input.iter() {
+            let mut inner_builder = target_inner.create_array_builder(1);
+            inner_builder.append(datum);
+            builder.append(Some(ScalarImpl::from(ListValue::new(inner_builder.finish()))));
+        }
+        return Ok(ListValue::new(builder.finish()));
+    }
     let cast = build_func(
         PbType::Cast,
         ctx.return_type.as_list().clone(),
@@ -212,13 +401,40 @@ fn list_cast(input: ListRef<'_>, ctx: &Context) -> Result<ListValue> {
     Ok(ListValue::new(Arc::try_unwrap(list).unwrap()))
 }
 
+/// How a struct cast fills target fields that have no source counterpart, when the target
+/// struct has more fields than the source.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StructPadding {
+    /// Pad missing trailing fields with `NULL`, matching PostgreSQL.
+    #[default]
+    Null,
+    /// Pad missing trailing fields with the type-appropriate default from [`type_default`].
+    TypeDefault,
+}
+
 /// Cast struct of `source_elem_type` to `target_elem_type` by casting each element.
 #[function("cast(struct) -> struct", type_infer = "unreachable")]
 fn struct_cast(input: StructRef<'_>, ctx: &Context) -> Result<StructValue> {
-    let fields = (input.iter_fields_ref())
-        .zip_eq_fast(ctx.arg_types[0].as_struct().types())
-        .zip_eq_fast(ctx.return_type.as_struct().types())
-        .map(|((datum_ref, source_field_type), target_field_type)| {
+    struct_cast_with_padding(input, ctx, StructPadding::default())
+}
+
+/// Like [`struct_cast`], but with an explicit choice of how to synthesize target fields beyond
+/// the source struct's length. Source fields beyond the target's length are discarded.
+pub fn struct_cast_with_padding(
+    input: StructRef<'_>,
+    ctx: &Context,
+    padding: StructPadding,
+) -> Result<StructValue> {
+    let mut source_fields =
+        (input.iter_fields_ref()).zip_eq_fast(ctx.arg_types[0].as_struct().types());
+    let fields = (ctx.return_type.as_struct().types())
+        .map(|target_field_type| {
+            let Some((datum_ref, source_field_type)) = source_fields.next() else {
+                return match padding {
+                    StructPadding::Null => Ok(None),
+                    StructPadding::TypeDefault => type_default(target_field_type).map(Some),
+                };
+            };
             if source_field_type == target_field_type {
                 return Ok(datum_ref.map(|scalar_ref| scalar_ref.into_scalar_impl()));
             }
@@ -241,6 +457,38 @@ fn struct_cast(input: StructRef<'_>, ctx: &Context) -> Result<StructValue> {
     Ok(StructValue::new(fields))
 }
 
+/// Synthesizes the type-appropriate default value used by [`StructPadding::TypeDefault`]: zero
+/// for numeric types, empty for strings, byteas and collections, `false` for booleans. Nested
+/// structs recurse field-wise, while arrays and maps default to empty rather than repeating an
+/// element default. Types with no natural default (temporal types, jsonb, ...) are refused.
+pub fn type_default(ty: &DataType) -> Result<ScalarImpl> {
+    Ok(match ty {
+        DataType::Boolean => ScalarImpl::Bool(false),
+        DataType::Int16 => ScalarImpl::Int16(0),
+        DataType::Int32 => ScalarImpl::Int32(0),
+        DataType::Int64 => ScalarImpl::Int64(0),
+        DataType::Int256 => ScalarImpl::Int256(Int256::from(0)),
+        DataType::Float32 => ScalarImpl::Float32(0.0.into()),
+        DataType::Float64 => ScalarImpl::Float64(0.0.into()),
+        DataType::Decimal => ScalarImpl::Decimal(Decimal::from(0)),
+        DataType::Varchar => ScalarImpl::Utf8("".into()),
+        DataType::Bytea => ScalarImpl::Bytea([].into()),
+        DataType::List(elem) => ScalarImpl::List(ListValue::empty(elem)),
+        DataType::Map(m) => {
+            ScalarImpl::Map(MapValue::from_entries(ListValue::empty(&m.clone().into_struct())))
+        }
+        DataType::Struct(ty) => ScalarImpl::Struct(StructValue::new(
+            ty.types().map(|f| type_default(f).map(Some)).try_collect()?,
+        )),
+        _ => {
+            return Err(ExprError::InvalidParam {
+                name: "padding",
+                reason: format!("no type default for {}", ty).into(),
+            })
+        }
+    })
+}
+
 /// Cast array with `source_elem_type` into array with `target_elem_type` by casting each element.
 #[function("cast(anymap) -> anymap", type_infer = "unreachable")]
 fn map_cast(map: MapRef<'_>, ctx: &Context) -> Result<MapValue> {
@@ -268,6 +516,44 @@ mod tests {
         assert!(!int_to_bool(0));
     }
 
+    #[test]
+    fn test_parse_numeric() {
+        let dec = |s: &str| s.parse::<Decimal>().unwrap();
+
+        assert_eq!(parse_numeric("1,234.56", ",", ".").unwrap(), dec("1234.56"));
+        assert_eq!(
+            parse_numeric("  -1,234,567  ", ",", ".").unwrap(),
+            dec("-1234567")
+        );
+        // European-style separators.
+        assert_eq!(parse_numeric("1.234,56", ".", ",").unwrap(), dec("1234.56"));
+        // Inputs without separators still parse, so the lenient mode is a superset of strict.
+        assert_eq!(parse_numeric("42", ",", ".").unwrap(), dec("42"));
+        assert_eq!(parse_numeric(".5", ",", ".").unwrap(), dec("0.5"));
+
+        // Empty or all-whitespace input.
+        assert!(parse_numeric("", ",", ".").is_err());
+        assert!(parse_numeric("   ", ",", ".").is_err());
+        // Grouping separators not between digits.
+        assert!(parse_numeric(",123", ",", ".").is_err());
+        assert!(parse_numeric("123,", ",", ".").is_err());
+        assert!(parse_numeric("1,,2", ",", ".").is_err());
+        // Separators after the decimal point.
+        assert!(parse_numeric("1.2,3", ",", ".").is_err());
+        assert!(parse_numeric("1.2.3", ",", ".").is_err());
+        // Ambiguous or malformed separator configuration.
+        assert!(parse_numeric("1", ",", ",").is_err());
+        assert!(parse_numeric("1", ",,", ".").is_err());
+
+        // The strict path is unchanged: grouping separators are still rejected.
+        let ctx = Context {
+            arg_types: vec![DataType::Varchar],
+            return_type: DataType::Decimal,
+            variadic: false,
+        };
+        assert!(str_parse::<Decimal>("1,234", &ctx).is_err());
+    }
+
     #[test]
     fn number_to_string() {
         macro_rules! test {
@@ -304,6 +590,130 @@ mod tests {
         test!(general_to_text(Decimal::NaN), "NaN");
     }
 
+    #[test]
+    fn test_str_to_jsonb_rejects_malformed() {
+        // `varchar -> jsonb` is an assign cast in the frontend so ingestion works without an
+        // explicit cast; malformed JSON must therefore fail loudly here at runtime.
+        let ctx = Context {
+            arg_types: vec![DataType::Varchar],
+            return_type: DataType::Jsonb,
+            variadic: false,
+        };
+        assert_eq!(
+            str_parse::<JsonbVal>(r#"{"k": [1, "v"]}"#, &ctx).unwrap(),
+            r#"{"k": [1, "v"]}"#.parse().unwrap()
+        );
+        for malformed in [r#"{"k": "#, "not json", "{'single': 'quotes'}", ""] {
+            assert!(str_parse::<JsonbVal>(malformed, &ctx).is_err(), "{malformed}");
+        }
+    }
+
+    #[test]
+    fn test_temporal_jsonb_round_trip() {
+        // Timestamps become ISO 8601 JSON strings (`T` separator) and round-trip exactly.
+        let ts: Timestamp = "2022-07-01 12:34:56.789".parse().unwrap();
+        let jsonb = timestamp_to_jsonb(ts);
+        assert_eq!(
+            jsonb,
+            JsonbVal::from(jsonbb::Value::from("2022-07-01T12:34:56.789"))
+        );
+        assert_eq!(jsonb_to_timestamp(jsonb.as_scalar_ref()).unwrap(), ts);
+
+        // Timestamptz is rendered in UTC with an explicit offset: the original offset is not
+        // preserved, but the instant round-trips exactly.
+        let tz: Timestamptz = "2022-07-01 12:34:56+02:00".parse().unwrap();
+        let jsonb = timestamptz_to_jsonb(tz);
+        assert_eq!(
+            jsonb,
+            JsonbVal::from(jsonbb::Value::from("2022-07-01T10:34:56+00:00"))
+        );
+        assert_eq!(jsonb_to_timestamptz(jsonb.as_scalar_ref()).unwrap(), tz);
+
+        // A JSON value that is not a string fails to parse.
+        let number: JsonbVal = "1".parse().unwrap();
+        assert!(jsonb_to_timestamp(number.as_scalar_ref()).is_err());
+        assert!(jsonb_to_timestamptz(number.as_scalar_ref()).is_err());
+
+        // A garbage string fails to parse.
+        let garbage: JsonbVal = "\"not a timestamp\"".parse().unwrap();
+        assert!(jsonb_to_timestamp(garbage.as_scalar_ref()).is_err());
+
+        // A local-time string without an offset is rejected as timestamptz: the cast must stay
+        // independent of the session time zone. Conversely, a string carrying an offset is not
+        // a plain timestamp.
+        let local: JsonbVal = "\"2022-07-01T12:34:56\"".parse().unwrap();
+        assert!(jsonb_to_timestamptz(local.as_scalar_ref()).is_err());
+        let offset: JsonbVal = "\"2022-07-01T12:34:56+02:00\"".parse().unwrap();
+        assert!(jsonb_to_timestamp(offset.as_scalar_ref()).is_err());
+    }
+
+    #[test]
+    fn test_interval_time_boundaries() {
+        // A time of day round-trips exactly through interval.
+        let time: Time = "23:00:00".parse().unwrap();
+        let interval: Interval = time.into();
+        assert_eq!(interval, Interval::from_month_day_usec(0, 0, 23 * 3600 * 1_000_000));
+        assert_eq!(interval_to_time(interval).unwrap(), time);
+
+        // The last representable microsecond of the day is still in range.
+        let last = Interval::from_month_day_usec(0, 0, Interval::USECS_PER_DAY - 1);
+        assert_eq!(
+            interval_to_time(last).unwrap(),
+            "23:59:59.999999".parse().unwrap()
+        );
+
+        // Intervals outside `[00:00:00, 24:00:00)`, or carrying a month/day component, error out
+        // instead of wrapping around midnight.
+        for out_of_range in [
+            Interval::from_month_day_usec(0, 0, Interval::USECS_PER_DAY), // '24 hours'
+            Interval::from_month_day_usec(0, 0, 25 * 3600 * 1_000_000),   // '25 hours'
+            Interval::from_month_day_usec(0, 0, -1),                      // negative
+            Interval::from_month_day_usec(0, 1, 0),                       // '1 day'
+            Interval::from_month_day_usec(1, 0, 0),                       // '1 month'
+        ] {
+            assert!(interval_to_time(out_of_range).is_err(), "{out_of_range}");
+        }
+    }
+
+    #[test]
+    fn test_decimal_float_special_values() {
+        // `numeric 'NaN'` / `'Infinity'` parse like their float8 counterparts...
+        assert_eq!("NaN".parse::<Decimal>().unwrap(), Decimal::NaN);
+        assert_eq!("Infinity".parse::<Decimal>().unwrap(), Decimal::PositiveInf);
+        assert_eq!("-Infinity".parse::<Decimal>().unwrap(), Decimal::NegativeInf);
+
+        // ... cast onto the corresponding float values...
+        assert!(try_cast::<_, F64>(Decimal::NaN).unwrap().0.is_nan());
+        assert_eq!(
+            try_cast::<_, F64>(Decimal::PositiveInf).unwrap(),
+            F64::from(f64::INFINITY)
+        );
+        assert_eq!(
+            try_cast::<_, F64>(Decimal::NegativeInf).unwrap(),
+            F64::from(f64::NEG_INFINITY)
+        );
+
+        // ... and round-trip back from float8 exactly.
+        assert_eq!(
+            try_cast::<F64, Decimal>(f64::NAN.into()).unwrap(),
+            Decimal::NaN
+        );
+        assert_eq!(
+            try_cast::<F64, Decimal>(f64::INFINITY.into()).unwrap(),
+            Decimal::PositiveInf
+        );
+        assert_eq!(
+            try_cast::<F64, Decimal>(f64::NEG_INFINITY.into()).unwrap(),
+            Decimal::NegativeInf
+        );
+
+        // Both types sort NaN greatest, above +Infinity, so ORDER BY / UNION dedup / CASE
+        // comparisons agree on the special values before and after the cast.
+        assert!(Decimal::NaN > Decimal::PositiveInf);
+        assert!(Decimal::NegativeInf < Decimal::from(0));
+        assert!(F64::from(f64::NAN) > F64::from(f64::INFINITY));
+    }
+
     #[test]
     fn test_str_to_list() {
         // Empty List
@@ -375,6 +785,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_list_cast_nesting() {
+        let str_ctx = |ty: &str| Context {
+            arg_types: vec![DataType::Varchar],
+            return_type: DataType::from_str(ty).unwrap(),
+            variadic: false,
+        };
+
+        // Flatten: inner arrays of mixed lengths are concatenated in row-major order, and `NULL`
+        // inner arrays contribute no elements.
+        let nested =
+            str_to_list("{{1, 2, 3}, NULL, {4}, {}, {5, 6}}", &str_ctx("int[][]")).unwrap();
+        let ctx = Context {
+            arg_types: vec![DataType::from_str("int[][]").unwrap()],
+            return_type: DataType::from_str("int[]").unwrap(),
+            variadic: false,
+        };
+        assert_eq!(
+            list_cast(nested.as_scalar_ref(), &ctx).unwrap(),
+            ListValue::from_iter([1, 2, 3, 4, 5, 6])
+        );
+
+        // Flatten combined with an element-wise cast.
+        let ctx = Context {
+            arg_types: vec![DataType::from_str("int[][]").unwrap()],
+            return_type: DataType::from_str("int8[]").unwrap(),
+            variadic: false,
+        };
+        assert_eq!(
+            list_cast(nested.as_scalar_ref(), &ctx).unwrap(),
+            ListValue::from_iter([1i64, 2, 3, 4, 5, 6])
+        );
+
+        // Nest: each element, including `NULL`s, is wrapped in a singleton inner array.
+        let flat = ListValue::from_iter([Some(1), None, Some(3)]);
+        let ctx = Context {
+            arg_types: vec![DataType::from_str("int[]").unwrap()],
+            return_type: DataType::from_str("int[][]").unwrap(),
+            variadic: false,
+        };
+        assert_eq!(
+            list_cast(flat.as_scalar_ref(), &ctx).unwrap(),
+            str_to_list("{{1}, {NULL}, {3}}", &str_ctx("int[][]")).unwrap()
+        );
+    }
+
     #[test]
     fn test_invalid_str_to_list() {
         // Unbalanced input
@@ -419,6 +875,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_struct_cast_padding() {
+        let ctx = Context {
+            arg_types: vec![DataType::Struct(StructType::new(vec![(
+                "a",
+                DataType::Int32,
+            )]))],
+            return_type: DataType::Struct(StructType::new(vec![
+                ("a", DataType::Int64),
+                ("b", DataType::Int32),
+                ("c", DataType::Varchar),
+            ])),
+            variadic: false,
+        };
+        let input = StructValue::new(vec![Some(1i32.to_scalar_value())]);
+
+        // NULL padding is the default, matching PG.
+        assert_eq!(
+            struct_cast(input.as_scalar_ref(), &ctx).unwrap(),
+            StructValue::new(vec![Some(1i64.to_scalar_value()), None, None])
+        );
+
+        // Type-default padding synthesizes zero / empty string instead.
+        assert_eq!(
+            struct_cast_with_padding(input.as_scalar_ref(), &ctx, StructPadding::TypeDefault)
+                .unwrap(),
+            StructValue::new(vec![
+                Some(1i64.to_scalar_value()),
+                Some(0i32.to_scalar_value()),
+                Some("".into()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_type_default_nested() {
+        // Nested structs recurse field-wise; arrays default to empty.
+        assert_eq!(
+            type_default(&DataType::Struct(StructType::new(vec![
+                ("n", DataType::Decimal),
+                ("s", DataType::Varchar),
+                ("l", DataType::List(Box::new(DataType::Int32))),
+            ])))
+            .unwrap(),
+            ScalarImpl::Struct(StructValue::new(vec![
+                Some(ScalarImpl::Decimal(Decimal::from(0))),
+                Some("".into()),
+                Some(ScalarImpl::List(ListValue::empty(&DataType::Int32))),
+            ]))
+        );
+        // No natural default for temporal types.
+        assert!(type_default(&DataType::Timestamp).is_err());
+    }
+
     #[test]
     fn test_timestamp() {
         assert_eq!(
@@ -237,6 +237,40 @@ mod tests {
         }
     }
 
+    /// `timestamptz::date` truncates in the session time zone (via `AT TIME ZONE`), so the same
+    /// instant near midnight yields different dates in different zones.
+    #[test]
+    fn test_timestamptz_to_date_straddles_midnight() {
+        let instant = str_to_timestamptz("2022-01-01 03:00:00", "UTC").unwrap();
+
+        let utc_local = timestamptz_at_time_zone(instant, "UTC").unwrap();
+        assert_eq!(utc_local.0.date().to_string(), "2022-01-01");
+
+        // 2022-01-01 03:00:00 UTC is still 2021-12-31 19:00:00 in US/Pacific.
+        let pacific_local = timestamptz_at_time_zone(instant, "US/Pacific").unwrap();
+        assert_eq!(pacific_local.0.date().to_string(), "2021-12-31");
+    }
+
+    /// `timestamptz::timestamp` converts the instant to the session time zone and drops the
+    /// offset (the frontend lowers the cast to `AT TIME ZONE`), so the resulting naive
+    /// timestamp depends on the session time zone.
+    #[test]
+    fn test_timestamptz_to_timestamp_strips_zone() {
+        let instant = str_to_timestamptz("2022-07-01 12:34:56", "UTC").unwrap();
+
+        let utc_local = timestamptz_at_time_zone(instant, "UTC").unwrap();
+        assert_eq!(utc_local.to_string(), "2022-07-01 12:34:56");
+
+        let singapore_local = timestamptz_at_time_zone(instant, "Asia/Singapore").unwrap();
+        assert_eq!(singapore_local.to_string(), "2022-07-01 20:34:56");
+
+        // Round-tripping through the same zone recovers the instant.
+        assert_eq!(
+            timestamp_at_time_zone(singapore_local, "Asia/Singapore").unwrap(),
+            instant
+        );
+    }
+
     #[test]
     #[rustfmt::skip]
     fn test_time_zone_conversion_daylight_forward() {
@@ -13,9 +13,11 @@
 // limitations under the License.
 
 use num_traits::One;
-use risingwave_common::types::{CheckedAdd, Decimal, IsNegative};
+use risingwave_common::types::{CheckedAdd, Decimal, Interval, IsNegative, Timestamptz};
 use risingwave_expr::{function, ExprError, Result};
 
+use crate::scalar::timestamptz::{time_zone_err, timestamptz_interval_add};
+
 #[function("generate_series(int4, int4) -> setof int4")]
 #[function("generate_series(int8, int8) -> setof int8")]
 fn generate_series<T>(start: T, stop: T) -> Result<impl Iterator<Item = T>>
@@ -54,6 +56,19 @@ fn generate_series_step_decimal(
     range_generic::<_, _, true>(start, stop, step)
 }
 
+/// The series is stepped in a timezone-aware way: the qualitative part of the step (e.g. `1 day`)
+/// applies calendar arithmetic in the given time zone, so a day may span 23 or 25 hours across a
+/// Daylight Saving switch. The time zone is recorded by the frontend as a hidden argument.
+#[function("generate_series(timestamptz, timestamptz, interval, varchar) -> setof timestamptz")]
+fn generate_series_timestamptz(
+    start: Timestamptz,
+    stop: Timestamptz,
+    step: Interval,
+    time_zone: &str,
+) -> Result<impl Iterator<Item = Result<Timestamptz>> + '_> {
+    range_timestamptz::<true>(start, stop, step, time_zone)
+}
+
 #[function("range(int4, int4) -> setof int4")]
 #[function("range(int8, int8) -> setof int8")]
 fn range<T>(start: T, stop: T) -> Result<impl Iterator<Item = T>>
@@ -82,6 +97,17 @@ where
     range_generic::<_, _, false>(start, stop, step)
 }
 
+/// See [`generate_series_timestamptz`] for the timezone-aware stepping semantics.
+#[function("range(timestamptz, timestamptz, interval, varchar) -> setof timestamptz")]
+fn range_timestamptz_step(
+    start: Timestamptz,
+    stop: Timestamptz,
+    step: Interval,
+    time_zone: &str,
+) -> Result<impl Iterator<Item = Result<Timestamptz>> + '_> {
+    range_timestamptz::<false>(start, stop, step, time_zone)
+}
+
 #[function("range(decimal, decimal, decimal) -> setof decimal")]
 fn range_step_decimal(
     start: Decimal,
@@ -125,6 +151,49 @@ where
     Ok(std::iter::from_fn(next))
 }
 
+#[inline]
+fn range_timestamptz<const INCLUSIVE: bool>(
+    start: Timestamptz,
+    stop: Timestamptz,
+    step: Interval,
+    time_zone: &str,
+) -> Result<impl Iterator<Item = Result<Timestamptz>> + '_> {
+    use num_traits::Zero as _;
+
+    if step.is_zero() {
+        return Err(ExprError::InvalidParam {
+            name: "step",
+            reason: "step size cannot equal zero".into(),
+        });
+    }
+    // Validate the time zone up front so an invalid one fails before yielding any row.
+    Timestamptz::lookup_time_zone(time_zone).map_err(time_zone_err)?;
+
+    let neg = step.is_negative();
+    let mut cur = Some(start);
+    let next = move || {
+        let ret = cur?;
+        match (INCLUSIVE, neg) {
+            (true, true) if ret < stop => return None,
+            (true, false) if ret > stop => return None,
+            (false, true) if ret <= stop => return None,
+            (false, false) if ret >= stop => return None,
+            _ => {}
+        };
+        match timestamptz_interval_add(ret, step, time_zone) {
+            Ok(n) => {
+                cur = Some(n);
+                Some(Ok(ret))
+            }
+            Err(e) => {
+                cur = None;
+                Some(Err(e))
+            }
+        }
+    };
+    Ok(std::iter::from_fn(next))
+}
+
 /// Validate decimals can not be `NaN` or `infinity`.
 #[inline]
 fn validate_range_parameters(start: Decimal, stop: Decimal, step: Decimal) -> Result<()> {
@@ -156,7 +225,9 @@ mod tests {
     use futures_util::StreamExt;
     use risingwave_common::array::DataChunk;
     use risingwave_common::types::test_utils::IntervalTestExt;
-    use risingwave_common::types::{DataType, Decimal, Interval, ScalarImpl, Timestamp};
+    use risingwave_common::types::{
+        DataType, Decimal, Interval, ScalarImpl, ScalarRefImpl, Timestamp, Timestamptz,
+    };
     use risingwave_expr::expr::{BoxedExpression, ExpressionBoxExt, LiteralExpression};
     use risingwave_expr::table_function::{build, check_error};
     use risingwave_pb::expr::table_function::PbType;
@@ -236,6 +307,72 @@ mod tests {
         assert_eq!(actual_cnt, expect_cnt);
     }
 
+    #[tokio::test]
+    async fn test_generate_series_timestamptz_dst() {
+        // Spring forward in `US/Pacific` on 2022-03-13: the local day only has 23 hours.
+        generate_series_timestamptz(
+            "2022-03-12 08:00:00+00:00",
+            "2022-03-15 07:00:00+00:00",
+            &[
+                "2022-03-12 08:00:00+00:00",
+                "2022-03-13 08:00:00+00:00",
+                "2022-03-14 07:00:00+00:00",
+                "2022-03-15 07:00:00+00:00",
+            ],
+        )
+        .await;
+        // Fall back in `US/Pacific` on 2022-11-06: the local day has 25 hours.
+        generate_series_timestamptz(
+            "2022-11-05 07:00:00+00:00",
+            "2022-11-08 08:00:00+00:00",
+            &[
+                "2022-11-05 07:00:00+00:00",
+                "2022-11-06 07:00:00+00:00",
+                "2022-11-07 08:00:00+00:00",
+                "2022-11-08 08:00:00+00:00",
+            ],
+        )
+        .await;
+    }
+
+    async fn generate_series_timestamptz(start: &str, stop: &str, expect: &[&str]) {
+        fn literal(ty: DataType, v: ScalarImpl) -> BoxedExpression {
+            LiteralExpression::new(ty, Some(v)).boxed()
+        }
+        let start = Timestamptz::from_str(start).unwrap();
+        let stop = Timestamptz::from_str(stop).unwrap();
+        let function = build(
+            PbType::GenerateSeries,
+            DataType::Timestamptz,
+            CHUNK_SIZE,
+            vec![
+                literal(DataType::Timestamptz, start.into()),
+                literal(DataType::Timestamptz, stop.into()),
+                literal(DataType::Interval, Interval::from_days(1).into()),
+                literal(DataType::Varchar, "US/Pacific".into()),
+            ],
+        )
+        .unwrap();
+
+        let dummy_chunk = DataChunk::new_dummy(1);
+        let mut actual = vec![];
+        let mut output = function.eval(&dummy_chunk).await;
+        while let Some(res) = output.next().await {
+            let chunk = res.unwrap();
+            let col = chunk.columns().last().unwrap();
+            for i in 0..chunk.capacity() {
+                if let Some(ScalarRefImpl::Timestamptz(t)) = col.value_at(i) {
+                    actual.push(t);
+                }
+            }
+        }
+        let expect = expect
+            .iter()
+            .map(|s| Timestamptz::from_str(s).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expect);
+    }
+
     #[tokio::test]
     async fn test_range_i32() {
         range_i32(2, 4, 1).await;
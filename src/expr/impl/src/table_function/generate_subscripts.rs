@@ -103,6 +103,14 @@ fn generate_subscripts_reverse(
 /// SELECT generate_subscripts(ARRAY[ARRAY['foo'], ARRAY['bar'], ARRAY[null]], 2) AS s;
 /// ----
 /// 1
+///
+/// query I
+/// SELECT generate_subscripts(ARRAY[]::int[], 1) AS s;
+/// ----
+///
+/// query I
+/// SELECT generate_subscripts(ARRAY['foo', 'bar'], 2) AS s;
+/// ----
 /// ```
 #[function("generate_subscripts(anyarray, int4) -> setof int4")]
 fn generate_subscripts(array: ListRef<'_>, dim: i32) -> impl Iterator<Item = i32> {
@@ -12,9 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::array::ListRef;
-use risingwave_common::types::ScalarRefImpl;
-use risingwave_expr::function;
+use futures_async_stream::try_stream;
+use futures_util::stream::BoxStream;
+use risingwave_common::array::{
+    Array, ArrayBuilder, ArrayBuilderImpl, ArrayImpl, DataChunk, I32ArrayBuilder, ListRef,
+    StructArray,
+};
+use risingwave_common::bitmap::Bitmap;
+use risingwave_common::types::{DataType, ScalarRefImpl, StructType};
+use risingwave_common::util::iter_util::ZipEqFast;
+use risingwave_expr::expr::BoxedExpression;
+use risingwave_expr::table_function::{BoxedTableFunction, TableFunction};
+use risingwave_expr::{build_function, function, ExprError, Result};
 
 #[function(
     "unnest(anyarray) -> setof any",
@@ -23,3 +32,220 @@ use risingwave_expr::function;
 fn unnest(list: ListRef<'_>) -> impl Iterator<Item = Option<ScalarRefImpl<'_>>> {
     list.flatten().iter()
 }
+
+/// `unnest` over multiple arrays zips them in parallel: one output column per array, one row per
+/// element position up to the longest array, with the shorter arrays padded with NULLs (PG
+/// behavior). The element types are independent, so the return type is a struct of them. Like PG,
+/// any number of arrays may be zipped.
+#[build_function(
+    "unnest(anyarray, anyarray, ...) -> setof struct",
+    type_infer = "infer_zip_type"
+)]
+fn build_unnest_zip(
+    return_type: DataType,
+    chunk_size: usize,
+    children: Vec<BoxedExpression>,
+) -> Result<BoxedTableFunction> {
+    Ok(Box::new(UnnestZip {
+        return_type,
+        chunk_size,
+        children,
+    }))
+}
+
+#[derive(Debug)]
+struct UnnestZip {
+    return_type: DataType,
+    chunk_size: usize,
+    children: Vec<BoxedExpression>,
+}
+
+#[async_trait::async_trait]
+impl TableFunction for UnnestZip {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    async fn eval<'a>(&'a self, input: &'a DataChunk) -> BoxStream<'a, Result<DataChunk>> {
+        self.eval_inner(input)
+    }
+}
+
+impl UnnestZip {
+    fn new_builders(&self) -> (I32ArrayBuilder, Vec<ArrayBuilderImpl>) {
+        (
+            I32ArrayBuilder::new(self.chunk_size),
+            self.return_type
+                .as_struct()
+                .types()
+                .map(|ty| ty.create_array_builder(self.chunk_size))
+                .collect(),
+        )
+    }
+
+    fn finish_chunk(
+        &self,
+        index_builder: I32ArrayBuilder,
+        field_builders: Vec<ArrayBuilderImpl>,
+    ) -> DataChunk {
+        let len = index_builder.len();
+        let index_array: ArrayImpl = index_builder.finish().into();
+        let value_array: ArrayImpl = StructArray::new(
+            self.return_type.as_struct().clone(),
+            field_builders.into_iter().map(|b| b.finish().into()).collect(),
+            Bitmap::ones(len),
+        )
+        .into();
+        DataChunk::new(vec![index_array.into(), value_array.into()], len)
+    }
+
+    #[try_stream(boxed, ok = DataChunk, error = ExprError)]
+    async fn eval_inner<'a>(&'a self, input: &'a DataChunk) {
+        let mut arrays = Vec::with_capacity(self.children.len());
+        for child in &self.children {
+            arrays.push(child.eval(input).await?);
+        }
+        let (mut index_builder, mut field_builders) = self.new_builders();
+        for i in 0..input.capacity() {
+            if unsafe { !input.visibility().is_set_unchecked(i) } {
+                continue;
+            }
+            // Like the fixed-arity zips before it, a NULL array yields no rows for this
+            // input row.
+            let Some(lists) = arrays
+                .iter()
+                .map(|array| array.as_list().value_at(i).map(ListRef::flatten))
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+            for row in 0..lists.iter().map(|list| list.len()).max().unwrap_or(0) {
+                index_builder.append(Some(i as i32));
+                for (builder, list) in field_builders.iter_mut().zip_eq_fast(&lists) {
+                    builder.append(list.get(row).flatten());
+                }
+                if index_builder.len() == self.chunk_size {
+                    let (new_index, new_fields) = self.new_builders();
+                    yield self.finish_chunk(
+                        std::mem::replace(&mut index_builder, new_index),
+                        std::mem::replace(&mut field_builders, new_fields),
+                    );
+                }
+            }
+        }
+        if index_builder.len() > 0 {
+            yield self.finish_chunk(index_builder, field_builders);
+        }
+    }
+}
+
+/// One `unnest` field per array argument, typed with its element type. Like PG, every output
+/// column is named `unnest`.
+fn infer_zip_type(args: &[DataType]) -> Result<DataType> {
+    Ok(DataType::Struct(StructType::new(
+        args.iter()
+            .map(|arg| ("unnest", arg.unnest_list().clone()))
+            .collect(),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::StreamExt;
+    use risingwave_common::array::{DataChunk, ListValue};
+    use risingwave_common::types::{Datum, ScalarImpl, StructValue};
+    use risingwave_expr::expr::{BoxedExpression, ExpressionBoxExt, LiteralExpression};
+    use risingwave_expr::table_function::build;
+    use risingwave_pb::expr::table_function::PbType;
+
+    use super::*;
+
+    const CHUNK_SIZE: usize = 1024;
+
+    fn list(elem: DataType, value: ListValue) -> BoxedExpression {
+        LiteralExpression::new(DataType::List(Box::new(elem)), Some(value.into())).boxed()
+    }
+
+    async fn eval_zip(args: Vec<BoxedExpression>) -> Vec<Datum> {
+        let arg_types: Vec<_> = args.iter().map(|a| a.return_type()).collect();
+        let function = build(
+            PbType::Unnest,
+            infer_zip_type(&arg_types).unwrap(),
+            CHUNK_SIZE,
+            args,
+        )
+        .unwrap();
+        let mut rows = vec![];
+        let mut output = function.eval(&DataChunk::new_dummy(1)).await;
+        while let Some(chunk) = output.next().await {
+            let chunk = chunk.unwrap();
+            for i in 0..chunk.capacity() {
+                rows.push(chunk.column_at(1).datum_at(i));
+            }
+        }
+        rows
+    }
+
+    fn row(fields: Vec<Datum>) -> Datum {
+        Some(ScalarImpl::Struct(StructValue::new(fields)))
+    }
+
+    #[tokio::test]
+    async fn test_unnest_zip_equal_length() {
+        let rows = eval_zip(vec![
+            list(DataType::Int32, ListValue::from_iter([1, 2])),
+            list(DataType::Varchar, ListValue::from_iter(["a", "b"])),
+        ])
+        .await;
+        assert_eq!(
+            rows,
+            vec![
+                row(vec![Some(1_i32.into()), Some("a".into())]),
+                row(vec![Some(2_i32.into()), Some("b".into())]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unnest_zip_ragged() {
+        // The shorter arrays are padded with NULLs up to the longest one.
+        let rows = eval_zip(vec![
+            list(DataType::Int32, ListValue::from_iter([1, 2, 3])),
+            list(DataType::Varchar, ListValue::from_iter(["a"])),
+            list(DataType::Int64, ListValue::from_iter([10_i64, 20])),
+        ])
+        .await;
+        assert_eq!(
+            rows,
+            vec![
+                row(vec![Some(1_i32.into()), Some("a".into()), Some(10_i64.into())]),
+                row(vec![Some(2_i32.into()), None, Some(20_i64.into())]),
+                row(vec![Some(3_i32.into()), None, None]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unnest_zip_many_arrays() {
+        // The arity is unbounded, like in PG.
+        let rows = eval_zip(vec![
+            list(DataType::Int32, ListValue::from_iter([1, 2])),
+            list(DataType::Varchar, ListValue::from_iter(["a"])),
+            list(DataType::Int64, ListValue::from_iter([10_i64])),
+            list(DataType::Int32, ListValue::from_iter([5, 6])),
+        ])
+        .await;
+        assert_eq!(
+            rows,
+            vec![
+                row(vec![
+                    Some(1_i32.into()),
+                    Some("a".into()),
+                    Some(10_i64.into()),
+                    Some(5_i32.into()),
+                ]),
+                row(vec![Some(2_i32.into()), None, None, Some(6_i32.into())]),
+            ]
+        );
+    }
+}
@@ -1006,10 +1006,20 @@ impl FunctionAttr {
         build_fn: bool,
     ) -> Result<TokenStream2> {
         let name = self.name.clone();
-        let mut args = Vec::with_capacity(self.args.len());
-        for ty in &self.args {
-            args.push(sig_data_type(ty));
+        let variadic = matches!(self.args.last(), Some(t) if t == "...");
+        if variadic && !build_fn {
+            return Err(Error::new(
+                Span::call_site(),
+                "variadic table function must be registered with `#[build_function]`",
+            ));
         }
+        let args = match variadic {
+            true => &self.args[..self.args.len() - 1],
+            false => &self.args[..],
+        }
+        .iter()
+        .map(|ty| sig_data_type(ty))
+        .collect_vec();
         let ret = sig_data_type(&self.ret);
 
         let pb_type = format_ident!("{}", utils::to_camel_case(&name));
@@ -1034,7 +1044,7 @@ impl FunctionAttr {
                 FuncSign {
                     name: risingwave_pb::expr::table_function::Type::#pb_type.into(),
                     inputs_type: vec![#(#args),*],
-                    variadic: false,
+                    variadic: #variadic,
                     ret_type: #ret,
                     build: FuncBuilder::Table(#build_fn),
                     type_infer: #type_infer_fn,
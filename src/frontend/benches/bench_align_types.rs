@@ -0,0 +1,49 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use risingwave_frontend::expr::{align_types, ExprImpl};
+
+fn bench_align_types(c: &mut Criterion) {
+    // The common binary case (`CASE`, binary operators), served by the single-pass fast path.
+    c.bench_function("align_types_binary", |b| {
+        b.iter_batched(
+            || vec![ExprImpl::literal_int(1), ExprImpl::literal_bigint(2)],
+            |mut exprs| align_types(exprs.iter_mut()).unwrap(),
+            BatchSize::SmallInput,
+        )
+    });
+
+    // A wider `VALUES`-like shape, served by the general `Vec`-collecting path.
+    c.bench_function("align_types_many", |b| {
+        b.iter_batched(
+            || {
+                (0..16i64)
+                    .map(|i| {
+                        if i % 2 == 0 {
+                            ExprImpl::literal_int(i as i32)
+                        } else {
+                            ExprImpl::literal_bigint(i)
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |mut exprs| align_types(exprs.iter_mut()).unwrap(),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(benches, bench_align_types);
+criterion_main!(benches);
@@ -0,0 +1,60 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use risingwave_common::types::{DataType, StructType};
+use risingwave_frontend::expr::{cast_ok, CastContext};
+
+/// `struct<f T', xs T'[]>` nested `depth` levels deep over the given leaf type, doubling the
+/// number of leaves per level — a stand-in for a wide, deeply nested schema.
+fn nested(leaf: DataType, depth: usize) -> DataType {
+    let mut ty = leaf;
+    for _ in 0..depth {
+        ty = DataType::Struct(StructType::new([
+            ("f", ty.clone()),
+            ("xs", DataType::List(Box::new(ty))),
+        ]));
+    }
+    ty
+}
+
+fn bench_cast_ok(c: &mut Criterion) {
+    // Castable pair: every leaf needs an implicit int2 -> int8 cast.
+    let source = nested(DataType::Int16, 12);
+    let target = nested(DataType::Int64, 12);
+    c.bench_function("cast_ok_nested_castable", |b| {
+        b.iter(|| cast_ok(black_box(&source), black_box(&target), CastContext::Implicit))
+    });
+
+    // Non-castable pair: the recursion still has to reach the leaves to find out.
+    let source = nested(DataType::Int32, 12);
+    let target = nested(DataType::Bytea, 12);
+    c.bench_function("cast_ok_nested_not_castable", |b| {
+        b.iter(|| cast_ok(black_box(&source), black_box(&target), CastContext::Explicit))
+    });
+
+    // Base-type baseline, which bypasses the composite cache entirely.
+    c.bench_function("cast_ok_base_types", |b| {
+        b.iter(|| {
+            cast_ok(
+                black_box(&DataType::Int16),
+                black_box(&DataType::Int64),
+                CastContext::Implicit,
+            )
+        })
+    });
+}
+
+criterion_group!(benches, bench_cast_ok);
+criterion_main!(benches);
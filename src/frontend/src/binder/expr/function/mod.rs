@@ -319,7 +319,17 @@ impl Binder {
                     "`VARIADIC` is not allowed in table function call"
                 );
                 self.ensure_table_function_allowed()?;
-                return Ok(TableFunction::new_file_scan(args)?.into());
+                let dry_run = self.session_config.read().file_scan_dry_run();
+                return Ok(TableFunction::new_file_scan(args, dry_run)?.into());
+            }
+            // `file_scan_metadata` table function
+            if func_name.eq_ignore_ascii_case("file_scan_metadata") {
+                reject_syntax!(
+                    arg_list.variadic,
+                    "`VARIADIC` is not allowed in table function call"
+                );
+                self.ensure_table_function_allowed()?;
+                return Ok(TableFunction::new_file_scan_metadata(args)?.into());
             }
             // UDTF
             if let Some(ref udf) = udf
@@ -330,7 +340,7 @@ impl Binder {
                     "`VARIADIC` is not allowed in table function call"
                 );
                 self.ensure_table_function_allowed()?;
-                return Ok(TableFunction::new_user_defined(udf.clone(), args).into());
+                return Ok(TableFunction::new_user_defined(udf.clone(), args)?.into());
             }
             // builtin table function
             if let Ok(function_type) = TableFunctionType::from_str(&func_name) {
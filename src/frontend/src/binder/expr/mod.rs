@@ -929,11 +929,79 @@ impl Binder {
             (Expr::Map { entries }, DataType::Map(m)) => self.bind_map_cast(entries, m),
             (expr, data_type) => {
                 let lhs = self.bind_expr_inner(expr)?;
+                if lhs.return_type() == DataType::Varchar
+                    && matches!(
+                        data_type,
+                        DataType::Int16
+                            | DataType::Int32
+                            | DataType::Int64
+                            | DataType::Decimal
+                            | DataType::Float32
+                            | DataType::Float64
+                    )
+                    && self.session_config.read().lenient_numeric_cast()
+                {
+                    return self.bind_lenient_numeric_cast(lhs, data_type);
+                }
+                if matches!(lhs.return_type(), DataType::Float32 | DataType::Float64)
+                    && matches!(
+                        data_type,
+                        DataType::Int16 | DataType::Int32 | DataType::Int64
+                    )
+                    && self.session_config.read().float_to_int_rounding() == "half_away_from_zero"
+                {
+                    return self.bind_float_to_int_rounding_cast(lhs, data_type);
+                }
                 lhs.cast_explicit(data_type).map_err(Into::into)
             }
         }
     }
 
+    /// Binds `CAST(<varchar> AS <numeric>)` as `parse_numeric(expr, grouping, decimal)` when
+    /// `LENIENT_NUMERIC_CAST` is on, so locale-style inputs like `'1,234.56'` are accepted.
+    /// The separators come from the `NUMERIC_SEPARATORS` session parameter and the result is
+    /// parsed as a decimal before being cast on to the requested type.
+    fn bind_lenient_numeric_cast(&self, lhs: ExprImpl, data_type: DataType) -> Result<ExprImpl> {
+        let separators = self.session_config.read().numeric_separators();
+        let mut chars = separators.chars();
+        let (Some(grouping), Some(decimal)) = (chars.next(), chars.next()) else {
+            // The check hook guarantees two characters; guard anyway for robustness.
+            return Err(ErrorCode::InternalError(format!(
+                "invalid NUMERIC_SEPARATORS: {separators:?}"
+            ))
+            .into());
+        };
+        let parsed: ExprImpl = FunctionCall::new_unchecked(
+            ExprType::ParseNumeric,
+            vec![
+                lhs,
+                ExprImpl::literal_varchar(grouping.to_string()),
+                ExprImpl::literal_varchar(decimal.to_string()),
+            ],
+            DataType::Decimal,
+        )
+        .into();
+        if data_type == DataType::Decimal {
+            Ok(parsed)
+        } else {
+            parsed.cast_explicit(data_type).map_err(Into::into)
+        }
+    }
+
+    /// Binds `CAST(<float> AS <int>)` as `cast(round(expr))` when `FLOAT_TO_INT_ROUNDING` is
+    /// `half_away_from_zero`. `round` on `float8` rounds `.5` away from zero, after which the
+    /// regular cast of the now-integral value is exact; a `float4` input is widened to
+    /// `float8` first, which is lossless.
+    fn bind_float_to_int_rounding_cast(
+        &self,
+        lhs: ExprImpl,
+        data_type: DataType,
+    ) -> Result<ExprImpl> {
+        let lhs = lhs.cast_implicit(DataType::Float64)?;
+        let rounded: ExprImpl = FunctionCall::new(ExprType::Round, vec![lhs])?.into();
+        rounded.cast_explicit(data_type).map_err(Into::into)
+    }
+
     pub fn bind_collate(&mut self, expr: Expr, collation: ObjectName) -> Result<ExprImpl> {
         if !["C", "POSIX"].contains(&collation.real_value().as_str()) {
             bail_not_implemented!("Collate collation other than `C` or `POSIX` is not implemented");
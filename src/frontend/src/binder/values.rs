@@ -23,7 +23,7 @@ use super::bind_context::Clause;
 use super::statement::RewriteExprsRecursive;
 use crate::binder::Binder;
 use crate::error::{ErrorCode, Result};
-use crate::expr::{align_types, CorrelatedId, Depth, ExprImpl};
+use crate::expr::{align_types_with_fallback, CorrelatedId, Depth, ExprImpl};
 
 #[derive(Debug, Clone)]
 pub struct BoundValues {
@@ -118,9 +118,22 @@ impl Binder {
 
                 types
             }
-            None => (0..num_columns)
-                .map(|col_index| align_types(bound.iter_mut().map(|row| &mut row[col_index])))
-                .try_collect()?,
+            None => {
+                let mut types = Vec::with_capacity(num_columns);
+                for col_index in 0..num_columns {
+                    let (ty, is_fallback) = align_types_with_fallback(
+                        bound.iter_mut().map(|row| &mut row[col_index]),
+                    )?;
+                    if is_fallback {
+                        crate::session::current::notice_to_user(format!(
+                            "column {} of VALUES contains only untyped expressions, defaulting to varchar; add an explicit cast if another type is intended",
+                            col_index + 1
+                        ));
+                    }
+                    types.push(ty);
+                }
+                types
+            }
         };
 
         let values_id = self.next_values_id();
@@ -188,4 +201,44 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_bind_values_untyped_fallback_notice() {
+        use std::any::Any;
+        use std::sync::{Arc, Weak};
+
+        use pgwire::pg_protocol::CURRENT_SESSION;
+        use pgwire::pg_server::Session;
+
+        use crate::binder::Binder;
+        use crate::session::SessionImpl;
+
+        let session = Arc::new(SessionImpl::mock());
+        let weak = Arc::downgrade(&session) as Weak<dyn Any + Send + Sync>;
+        CURRENT_SESSION
+            .scope(weak, async {
+                let mut binder = Binder::new(&session);
+
+                // All-untyped column falls back to varchar and emits a notice.
+                let values = Values(vec![
+                    vec![Expr::Value(Value::Null)],
+                    vec![Expr::Value(Value::Null)],
+                ]);
+                let res = binder.bind_values(values, None).unwrap();
+                assert_eq!(res.schema.data_types(), vec![DataType::Varchar]);
+                let notices = session.clone().take_notices();
+                assert_eq!(notices.len(), 1);
+                assert!(notices[0].contains("defaulting to varchar"), "{notices:?}");
+
+                // A typed row determines the column type without a notice.
+                let values = Values(vec![
+                    vec![Expr::Value(Value::Null)],
+                    vec![Expr::Value(Value::Number("1".to_string()))],
+                ]);
+                let res = binder.bind_values(values, None).unwrap();
+                assert_eq!(res.schema.data_types(), vec![DataType::Int32]);
+                assert!(session.clone().take_notices().is_empty());
+            })
+            .await;
+    }
 }
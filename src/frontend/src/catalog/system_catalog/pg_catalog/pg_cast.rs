@@ -12,11 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::types::{DataType, Fields};
+use risingwave_common::types::Fields;
 use risingwave_frontend_macro::system_catalog;
 
 use crate::catalog::system_catalog::SysCatalogReaderImpl;
-use crate::expr::cast_map_array;
+use crate::expr::{cast_map_array, data_type_name_to_oid};
 
 /// The catalog `pg_cast` stores data type conversion paths.
 /// Ref: [`https://www.postgresql.org/docs/current/catalog-pg-cast.html`]
@@ -38,8 +38,8 @@ fn read_pg_cast(_: &SysCatalogReaderImpl) -> Vec<PgCast> {
         .enumerate()
         .map(|(idx, (src, target, ctx))| PgCast {
             oid: idx as i32,
-            castsource: DataType::try_from(*src).unwrap().to_oid(),
-            casttarget: DataType::try_from(*target).unwrap().to_oid(),
+            castsource: data_type_name_to_oid(*src),
+            casttarget: data_type_name_to_oid(*target),
             castcontext: ctx.to_string(),
         })
         .collect()
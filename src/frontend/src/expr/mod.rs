@@ -67,7 +67,8 @@ pub use session_timezone::{SessionTimezone, TimestamptzExprFinder};
 pub use subquery::{Subquery, SubqueryKind};
 pub use table_function::{TableFunction, TableFunctionType};
 pub use type_inference::{
-    align_types, cast_map_array, cast_ok, cast_sigs, infer_some_all, infer_type, infer_type_name,
+    align_types, align_types_with_fallback, cast_map_array, cast_ok, cast_sigs,
+    data_type_name_to_oid, implicit_cast_closure, infer_some_all, infer_type, infer_type_name,
     infer_type_with_sigmap, CastContext, CastSig, FuncSign,
 };
 pub use user_defined_function::UserDefinedFunction;
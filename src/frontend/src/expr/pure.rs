@@ -251,6 +251,7 @@ impl ExprVisitor for ImpureAnalyzer {
             | Type::InetAton
             | Type::QuoteLiteral
             | Type::QuoteNullable
+            | Type::ParseNumeric
             | Type::MapFromEntries
             | Type::MapAccess
             | Type::MapKeys
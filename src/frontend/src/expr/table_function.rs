@@ -17,12 +17,15 @@ use std::sync::{Arc, LazyLock};
 use itertools::Itertools;
 use risingwave_common::array::arrow::IcebergArrowConvert;
 use risingwave_common::types::{DataType, ScalarImpl, StructType};
-use risingwave_connector::source::iceberg::{create_parquet_stream_builder, list_s3_directory};
+use risingwave_connector::source::iceberg::{
+    create_parquet_stream_builder, list_s3_directory, read_delta_table_files, read_manifest_list,
+    read_parquet_file_stats, read_text_head, retry_on_transient,
+};
 pub use risingwave_pb::expr::table_function::PbType as TableFunctionType;
 use risingwave_pb::expr::PbTableFunction;
 use tokio::runtime::Runtime;
 
-use super::{infer_type, Expr, ExprImpl, ExprRewriter, Literal, RwResult};
+use super::{cast_ok, infer_type, CastContext, Expr, ExprImpl, ExprRewriter, Literal, RwResult};
 use crate::catalog::function_catalog::{FunctionCatalog, FunctionKind};
 use crate::error::ErrorCode::BindError;
 
@@ -44,6 +47,20 @@ impl TableFunction {
     /// Create a `TableFunction` expr with the return type inferred from `func_type` and types of
     /// `inputs`.
     pub fn new(func_type: TableFunctionType, mut args: Vec<ExprImpl>) -> RwResult<Self> {
+        // A series over `timestamptz` steps in a timezone-aware way (e.g. a `1 day` step applies
+        // calendar arithmetic across a DST switch), so record the session time zone as a hidden
+        // trailing argument for the executor.
+        if matches!(
+            func_type,
+            TableFunctionType::GenerateSeries | TableFunctionType::Range
+        ) && args.len() == 3
+            && args
+                .iter()
+                .take(2)
+                .any(|arg| arg.return_type() == DataType::Timestamptz)
+        {
+            args.push(ExprImpl::literal_varchar(crate::session::current::timezone()));
+        }
         let return_type = infer_type(func_type.into(), &mut args)?;
         Ok(TableFunction {
             args,
@@ -54,21 +71,228 @@ impl TableFunction {
     }
 
     /// Create a user-defined `TableFunction`.
-    pub fn new_user_defined(catalog: Arc<FunctionCatalog>, args: Vec<ExprImpl>) -> Self {
+    ///
+    /// Returns a bind error if the catalog entry is not a table function, so that an
+    /// inconsistent catalog state surfaces to the user instead of crashing the frontend.
+    pub fn new_user_defined(catalog: Arc<FunctionCatalog>, args: Vec<ExprImpl>) -> RwResult<Self> {
         let FunctionKind::Table = &catalog.kind else {
-            panic!("not a table function");
+            return Err(BindError(format!(
+                "function {} is not a table function",
+                catalog.name
+            ))
+            .into());
         };
-        TableFunction {
+        Ok(TableFunction {
             args,
             return_type: catalog.return_type.clone(),
             function_type: TableFunctionType::UserDefined,
             user_defined: Some(catalog),
-        }
+        })
     }
 
     /// A special table function which would be transformed into `LogicalFileScan` by `TableFunctionToFileScanRule` in the optimizer.
     /// select * from `file_scan`('parquet', 's3', region, ak, sk, location)
-    pub fn new_file_scan(mut args: Vec<ExprImpl>) -> RwResult<Self> {
+    ///
+    /// With `file_scan('delta', ...)` the location is a Delta table root: the active parquet
+    /// files are resolved from its transaction log at binding time and scanned as plain parquet.
+    ///
+    /// A single credential secret can replace the region/access-key/secret-key triple:
+    /// `file_scan('parquet', 's3', 'secret:my_creds', location)`. The referenced secret
+    /// (created with `CREATE SECRET`) must hold a JSON object with `region`, `access_key`,
+    /// `secret_key` and an optional `endpoint`, so the SQL text carries one managed reference
+    /// instead of literal keys.
+    ///
+    /// With `file_scan('jsonl', ...)` (or `'ndjson'`) the files are newline-delimited JSON, one
+    /// JSON object per line. The schema is inferred at binding time by sampling the first lines
+    /// of each file and unifying the per-line field types: keys missing from a line read as
+    /// NULL, an integer meeting a double widens to double, and otherwise-conflicting types fall
+    /// back to jsonb. Files with a `.gz`/`.gzip` or `.zst`/`.zstd` extension are decompressed
+    /// transparently, both while sampling for inference and at scan time.
+    ///
+    /// An optional 7th varchar argument overrides the S3 endpoint for S3-compatible stores
+    /// (MinIO, Cloudflare R2, ...). If it contains whitespace it is instead a schema override
+    /// like `'a BIGINT, b VARCHAR'` (endpoints never contain whitespace, field definitions
+    /// always do): the named columns are scanned with the requested types in the given order.
+    /// Each requested type must be reachable from the inferred one through an assignment cast,
+    /// checked at binding time. An optional 8th boolean argument selects path-style
+    /// (`true`, the default) vs virtual-hosted-style addressing for that endpoint. An optional
+    /// 9th boolean argument (default `false`) appends a `_rw_file_path` varchar pseudo-column
+    /// carrying the full S3 key each row was read from, for lineage and debugging.
+    ///
+    /// An optional 10th integer argument `sample_rows` stops the scan after that many rows in
+    /// total, for instant previews of large directories. Which rows are returned is
+    /// non-deterministic across files: files are read in listing order and the cutoff falls
+    /// wherever the budget runs out.
+    ///
+    /// With the `FILE_SCAN_DRY_RUN` session variable on (`dry_run` here), no object-store
+    /// access happens at all: the trailing varchar argument must instead carry the schema,
+    /// e.g. `'a INT, b VARCHAR'`, so that queries can be validated offline.
+    pub fn new_file_scan(mut args: Vec<ExprImpl>, dry_run: bool) -> RwResult<Self> {
+        /// Prefix marking the credential argument as a secret reference.
+        const CREDENTIAL_SECRET_PREFIX: &str = "secret:";
+
+        // A `secret:<name>` credential in the third position stands for the whole
+        // region/access-key/secret-key triple. Resolve it and splice the fields into the
+        // canonical argument positions up front, so the positional trailing arguments below
+        // line up the same way for both forms.
+        let mut secret_endpoint: Option<String> = None;
+        if args.len() >= 4 {
+            let credential = match args[2].try_fold_const() {
+                Some(Ok(Some(ScalarImpl::Utf8(s))))
+                    if s.starts_with(CREDENTIAL_SECRET_PREFIX) =>
+                {
+                    Some(s.to_string())
+                }
+                _ => None,
+            };
+            if let Some(credential) = credential {
+                let secret_name = credential[CREDENTIAL_SECRET_PREFIX.len()..].trim();
+                if secret_name.is_empty() {
+                    return Err(BindError(
+                        "the credential secret reference of file_scan must name a secret, e.g. \
+                         'secret:my_creds'"
+                            .to_string(),
+                    )
+                    .into());
+                }
+                let value = crate::session::current::resolve_secret(secret_name)?;
+                let (region, access_key, secret_key, endpoint) =
+                    parse_credential_secret(secret_name, &value)?;
+                args[2] = ExprImpl::literal_varchar(region);
+                args.insert(3, ExprImpl::literal_varchar(access_key));
+                args.insert(4, ExprImpl::literal_varchar(secret_key));
+                secret_endpoint = endpoint;
+            }
+        }
+
+        // An optional trailing boolean argument `ordered` (default `true`) relaxes the output
+        // ordering when set to `false`, allowing the scheduler to interleave files freely.
+        let mut ordered = true;
+        let mut s3_endpoint: Option<String> = None;
+        let mut requested_schema: Option<String> = None;
+        let mut path_style_access = true;
+        let mut include_file_path = false;
+        let mut sample_rows: Option<u64> = None;
+        if args.len() == 10 {
+            let sample_arg = args.pop().unwrap();
+            if !matches!(sample_arg.return_type(), DataType::Int32 | DataType::Int64) {
+                return Err(BindError(
+                    "the 10th argument of file_scan function must be an integer".to_string(),
+                )
+                .into());
+            }
+            let n = match sample_arg.try_fold_const() {
+                Some(Ok(Some(ScalarImpl::Int32(n)))) => n as i64,
+                Some(Ok(Some(ScalarImpl::Int64(n)))) => n,
+                Some(Err(err)) => return Err(err),
+                _ => {
+                    return Err(BindError(
+                        "the 10th argument of file_scan function must be a constant non-null \
+                         integer"
+                            .to_string(),
+                    )
+                    .into());
+                }
+            };
+            if n <= 0 {
+                return Err(BindError(
+                    "the sample_rows argument of file_scan function must be positive".to_string(),
+                )
+                .into());
+            }
+            sample_rows = Some(n as u64);
+        }
+        if args.len() == 9 {
+            let include_arg = args.pop().unwrap();
+            if include_arg.return_type() != DataType::Boolean {
+                return Err(BindError(
+                    "the 9th argument of file_scan function must be a boolean".to_string(),
+                )
+                .into());
+            }
+            match include_arg.try_fold_const() {
+                Some(Ok(Some(ScalarImpl::Bool(b)))) => include_file_path = b,
+                Some(Err(err)) => return Err(err),
+                _ => {
+                    return Err(BindError(
+                        "the 9th argument of file_scan function must be a constant non-null boolean"
+                            .to_string(),
+                    )
+                    .into());
+                }
+            }
+        }
+        if args.len() == 8 {
+            let path_style_arg = args.pop().unwrap();
+            if path_style_arg.return_type() != DataType::Boolean {
+                return Err(BindError(
+                    "the 8th argument of file_scan function must be a boolean".to_string(),
+                )
+                .into());
+            }
+            match path_style_arg.try_fold_const() {
+                Some(Ok(Some(ScalarImpl::Bool(b)))) => path_style_access = b,
+                Some(Err(err)) => return Err(err),
+                _ => {
+                    return Err(BindError(
+                        "the 8th argument of file_scan function must be a constant non-null boolean"
+                            .to_string(),
+                    )
+                    .into());
+                }
+            }
+        }
+        if args.len() == 7 {
+            // A trailing boolean is the legacy `ordered` flag; a trailing varchar is the S3
+            // endpoint override.
+            match args.last().expect("checked length above").return_type() {
+                DataType::Boolean => {
+                    let ordered_arg = args.pop().unwrap();
+                    match ordered_arg.try_fold_const() {
+                        Some(Ok(Some(ScalarImpl::Bool(b)))) => ordered = b,
+                        Some(Err(err)) => return Err(err),
+                        _ => {
+                            return Err(BindError(
+                                "the 7th argument of file_scan function must be a constant \
+                                 non-null boolean"
+                                    .to_string(),
+                            )
+                            .into());
+                        }
+                    }
+                }
+                DataType::Varchar => {
+                    let endpoint_arg = args.pop().unwrap();
+                    match endpoint_arg.try_fold_const() {
+                        // Endpoints never contain whitespace and schema fields (`name type`)
+                        // always do, so the two uses of this slot are unambiguous.
+                        Some(Ok(Some(ScalarImpl::Utf8(s))))
+                            if s.contains(char::is_whitespace) =>
+                        {
+                            requested_schema = Some(s.to_string())
+                        }
+                        Some(Ok(Some(ScalarImpl::Utf8(s)))) => s3_endpoint = Some(s.to_string()),
+                        Some(Err(err)) => return Err(err),
+                        _ => {
+                            return Err(BindError(
+                                "the endpoint argument of file_scan function must be a constant \
+                                 non-null varchar"
+                                    .to_string(),
+                            )
+                            .into());
+                        }
+                    }
+                }
+                _ => {
+                    return Err(BindError(
+                        "the 7th argument of file_scan function must be a boolean (`ordered`) or \
+                         a varchar (S3 endpoint)"
+                            .to_string(),
+                    )
+                    .into());
+                }
+            }
+        }
         let return_type = {
             // arguments:
             // file format e.g. parquet
@@ -119,9 +343,13 @@ impl TableFunction {
                     }
                 }
             }
-            if !"parquet".eq_ignore_ascii_case(&eval_args[0]) {
+            let is_delta = "delta".eq_ignore_ascii_case(&eval_args[0]);
+            let is_jsonl = "jsonl".eq_ignore_ascii_case(&eval_args[0])
+                || "ndjson".eq_ignore_ascii_case(&eval_args[0]);
+            if !is_delta && !is_jsonl && !"parquet".eq_ignore_ascii_case(&eval_args[0]) {
                 return Err(BindError(
-                    "file_scan function only accepts 'parquet' as file format".to_string(),
+                    "file_scan function only accepts 'parquet', 'jsonl' or 'delta' as file format"
+                        .to_string(),
                 )
                 .into());
             }
@@ -133,6 +361,57 @@ impl TableFunction {
                 .into());
             }
 
+            if is_jsonl {
+                // Canonicalize the `ndjson` alias for the optimizer rule.
+                args[0] = ExprImpl::literal_varchar("jsonl".to_string());
+            }
+
+            if dry_run {
+                // Dry-run mode performs no listing or schema inference; the trailing varchar
+                // argument (the endpoint slot otherwise) must carry the schema instead.
+                let schema_def = requested_schema.take().ok_or_else(|| {
+                    BindError(
+                        "file_scan in dry-run mode requires a trailing schema argument, e.g. \
+                         file_scan(..., 'a INT, b VARCHAR')"
+                            .to_string(),
+                    )
+                })?;
+                let mut schema = parse_schema_arg(&schema_def)?;
+                if include_file_path {
+                    schema = append_file_path_column(schema);
+                }
+                if include_file_path || sample_rows.is_some() {
+                    let mut storage = "s3".to_string();
+                    if include_file_path {
+                        storage.push_str(";file_path=true");
+                    }
+                    if let Some(n) = sample_rows {
+                        storage.push_str(&format!(";sample_rows={}", n));
+                    }
+                    args[1] = ExprImpl::literal_varchar(storage);
+                }
+                let return_type = DataType::Struct(schema);
+                if !ordered {
+                    args.push(ExprImpl::Literal(Box::new(Literal::new(
+                        Some(ScalarImpl::Bool(false)),
+                        DataType::Boolean,
+                    ))));
+                }
+                return Ok(TableFunction {
+                    args,
+                    return_type,
+                    function_type: TableFunctionType::FileScan,
+                    user_defined: None,
+                });
+            }
+
+            // An endpoint carried in the credential secret applies unless overridden by the
+            // positional endpoint argument. (Dry-run mode above reuses that positional slot
+            // for the schema and touches no object store, so it is exempt.)
+            if s3_endpoint.is_none() {
+                s3_endpoint = secret_endpoint;
+            }
+
             #[cfg(madsim)]
             return Err(crate::error::ErrorCode::BindError(
                 "file_scan can't be used in the madsim mode".to_string(),
@@ -149,13 +428,22 @@ impl TableFunction {
                         .expect("failed to build file-scan runtime")
                 });
 
-                let files = if eval_args[5].ends_with('/') {
+                // Total attempts for the listing and schema-inference calls below, so that a
+                // transient S3 throttle during planning does not fail the whole query.
+                const FILE_SCAN_RETRY_ATTEMPTS: usize = 3;
+
+                let files = if is_delta {
+                    // For a Delta table, the active parquet files are resolved from the
+                    // transaction log here at binding time; the scan below then reads them as
+                    // plain parquet.
                     let files = tokio::task::block_in_place(|| {
                         RUNTIME.block_on(async {
-                            let files = list_s3_directory(
+                            let files = read_delta_table_files(
                                 eval_args[2].clone(),
                                 eval_args[3].clone(),
                                 eval_args[4].clone(),
+                                s3_endpoint.clone(),
+                                path_style_access,
                                 eval_args[5].clone(),
                             )
                             .await?;
@@ -164,6 +452,33 @@ impl TableFunction {
                         })
                     })?;
 
+                    if files.is_empty() {
+                        return Err(BindError(
+                            "the Delta table has no active data files".to_string(),
+                        )
+                        .into());
+                    }
+
+                    Some(files)
+                } else if eval_args[5].ends_with('/') {
+                    let files = tokio::task::block_in_place(|| {
+                        RUNTIME.block_on(async {
+                            let files = retry_on_transient(FILE_SCAN_RETRY_ATTEMPTS, || {
+                                list_s3_directory(
+                                    eval_args[2].clone(),
+                                    eval_args[3].clone(),
+                                    eval_args[4].clone(),
+                                    s3_endpoint.clone(),
+                                    path_style_access,
+                                    eval_args[5].clone(),
+                                )
+                            })
+                            .await?;
+
+                            Ok::<Vec<String>, anyhow::Error>(files)
+                        })
+                    })?;
+
                     if files.is_empty() {
                         return Err(BindError(
                             "file_scan function only accepts non-empty directory".to_string(),
@@ -171,37 +486,123 @@ impl TableFunction {
                         .into());
                     }
 
+                    Some(files)
+                } else if eval_args[5].ends_with("_manifest.txt") {
+                    // The location points to a manifest file listing the exact files to scan,
+                    // which avoids eventually-consistent directory listings.
+                    let files = tokio::task::block_in_place(|| {
+                        RUNTIME.block_on(async {
+                            let files = read_manifest_list(
+                                eval_args[2].clone(),
+                                eval_args[3].clone(),
+                                eval_args[4].clone(),
+                                s3_endpoint.clone(),
+                                path_style_access,
+                                eval_args[5].clone(),
+                            )
+                            .await?;
+
+                            Ok::<Vec<String>, anyhow::Error>(files)
+                        })
+                    })?;
+
+                    if files.is_empty() {
+                        return Err(BindError(
+                            "file_scan function only accepts a non-empty manifest file".to_string(),
+                        )
+                        .into());
+                    }
+
                     Some(files)
                 } else {
                     None
                 };
 
-                let schema = tokio::task::block_in_place(|| {
-                    RUNTIME.block_on(async {
-                        let parquet_stream_builder = create_parquet_stream_builder(
-                            eval_args[2].clone(),
-                            eval_args[3].clone(),
-                            eval_args[4].clone(),
-                            match files.as_ref() {
-                                Some(files) => files[0].clone(),
-                                None => eval_args[5].clone(),
-                            },
-                        )
-                        .await?;
+                // Sample up to this many files for schema inference. Files written over time
+                // may add columns, so a single file does not necessarily carry them all; the
+                // union of the sampled schemas is taken below and absent columns are filled
+                // with NULLs at read time.
+                const FILE_SCAN_SCHEMA_SAMPLE_FILES: usize = 8;
 
-                        let mut rw_types = vec![];
-                        for field in parquet_stream_builder.schema().fields() {
-                            rw_types.push((
-                                field.name().to_string(),
-                                IcebergArrowConvert.type_from_field(field)?,
-                            ));
-                        }
+                let sample_locations: Vec<String> = match files.as_ref() {
+                    Some(files) => files
+                        .iter()
+                        .take(FILE_SCAN_SCHEMA_SAMPLE_FILES)
+                        .cloned()
+                        .collect(),
+                    None => vec![eval_args[5].clone()],
+                };
 
-                        Ok::<risingwave_common::types::DataType, anyhow::Error>(DataType::Struct(
-                            StructType::new(rw_types),
-                        ))
-                    })
-                })?;
+                // Number of lines sampled from each NDJSON file for schema inference.
+                const FILE_SCAN_JSONL_SAMPLE_LINES: usize = 100;
+
+                let mut unioned = if is_jsonl {
+                    // NDJSON carries no schema, so infer one from the first lines of each
+                    // sampled file. All sampled lines are unified together, since types may
+                    // diverge across lines as well as across files.
+                    let lines = tokio::task::block_in_place(|| {
+                        RUNTIME.block_on(async {
+                            let mut lines = vec![];
+                            for location in sample_locations {
+                                let head = retry_on_transient(FILE_SCAN_RETRY_ATTEMPTS, || {
+                                    read_text_head(
+                                        eval_args[2].clone(),
+                                        eval_args[3].clone(),
+                                        eval_args[4].clone(),
+                                        s3_endpoint.clone(),
+                                        path_style_access,
+                                        location.clone(),
+                                        FILE_SCAN_JSONL_SAMPLE_LINES,
+                                    )
+                                })
+                                .await?;
+                                lines.extend(head);
+                            }
+                            Ok::<Vec<String>, anyhow::Error>(lines)
+                        })
+                    })?;
+                    infer_jsonl_schema(&lines)?
+                } else {
+                    let file_schemas = tokio::task::block_in_place(|| {
+                        RUNTIME.block_on(async {
+                            let mut file_schemas = vec![];
+                            for location in sample_locations {
+                                let parquet_stream_builder =
+                                    retry_on_transient(FILE_SCAN_RETRY_ATTEMPTS, || {
+                                        create_parquet_stream_builder(
+                                            eval_args[2].clone(),
+                                            eval_args[3].clone(),
+                                            eval_args[4].clone(),
+                                            s3_endpoint.clone(),
+                                            path_style_access,
+                                            location.clone(),
+                                        )
+                                    })
+                                    .await?;
+
+                                let mut rw_types = vec![];
+                                for field in parquet_stream_builder.schema().fields() {
+                                    rw_types.push((
+                                        field.name().to_string(),
+                                        IcebergArrowConvert.type_from_field(field)?,
+                                    ));
+                                }
+                                file_schemas.push(rw_types);
+                            }
+
+                            Ok::<Vec<Vec<(String, DataType)>>, anyhow::Error>(file_schemas)
+                        })
+                    })?;
+                    union_file_schemas(file_schemas)?
+                };
+                if let Some(def) = requested_schema {
+                    let requested = parse_schema_arg(&def)?;
+                    unioned = validate_schema_override(&unioned, requested)?;
+                }
+                if include_file_path {
+                    unioned = append_file_path_column(unioned);
+                }
+                let schema = DataType::Struct(unioned);
 
                 if let Some(files) = files {
                     // if the file location is a directory, we need to remove the last argument and add all files in the directory as arguments
@@ -214,10 +615,45 @@ impl TableFunction {
                     }
                 }
 
+                if is_delta {
+                    // The transaction log has been fully resolved into concrete parquet files
+                    // above, so hand off to the optimizer as a plain parquet scan.
+                    args[0] = ExprImpl::literal_varchar("parquet".to_string());
+                }
+
+                if s3_endpoint.is_some() || include_file_path || sample_rows.is_some() {
+                    // Fold the optional flags into the storage-type argument, since the
+                    // trailing positions are taken by the expanded file locations above.
+                    // `TableFunctionToFileScanRule` parses this back out.
+                    let mut storage = "s3".to_string();
+                    if let Some(endpoint) = &s3_endpoint {
+                        storage.push_str(&format!(
+                            ";endpoint={};path_style={}",
+                            endpoint, path_style_access
+                        ));
+                    }
+                    if include_file_path {
+                        storage.push_str(";file_path=true");
+                    }
+                    if let Some(n) = sample_rows {
+                        storage.push_str(&format!(";sample_rows={}", n));
+                    }
+                    args[1] = ExprImpl::literal_varchar(storage);
+                }
+
                 schema
             }
         };
 
+        if !ordered {
+            // Re-append the flag as a trailing boolean literal so that
+            // `TableFunctionToFileScanRule` can pick it up after the file expansion above.
+            args.push(ExprImpl::Literal(Box::new(Literal::new(
+                Some(ScalarImpl::Bool(false)),
+                DataType::Boolean,
+            ))));
+        }
+
         Ok(TableFunction {
             args,
             return_type,
@@ -226,6 +662,184 @@ impl TableFunction {
         })
     }
 
+    /// A sibling of [`Self::new_file_scan`] which returns per-file statistics instead of the
+    /// file contents, so skew can be debugged without reading any data pages. It is transformed
+    /// into `LogicalValues` by `TableFunctionToFileScanMetadataRule` in the optimizer: the stats
+    /// are collected from the parquet footers at binding time and carried as literal arguments.
+    ///
+    /// select * from `file_scan_metadata`('parquet', 's3', region, ak, sk, location)
+    ///
+    /// Like [`Self::new_file_scan`], an optional 7th varchar argument overrides the S3 endpoint
+    /// and an optional 8th boolean argument selects path-style (`true`, the default) vs
+    /// virtual-hosted-style addressing for that endpoint.
+    pub fn new_file_scan_metadata(mut args: Vec<ExprImpl>) -> RwResult<Self> {
+        if !(6..=8).contains(&args.len()) {
+            return Err(BindError("file_scan_metadata function only accepts 6 to 8 arguments: file_scan_metadata('parquet', 's3', s3 region, s3 access key, s3 secret key, file location [, endpoint [, path_style ]])".to_string()).into());
+        }
+        let mut s3_endpoint: Option<String> = None;
+        let mut path_style_access = true;
+        if args.len() == 8 {
+            let path_style_arg = args.pop().unwrap();
+            if path_style_arg.return_type() != DataType::Boolean {
+                return Err(BindError(
+                    "the 8th argument of file_scan_metadata function must be a boolean"
+                        .to_string(),
+                )
+                .into());
+            }
+            match path_style_arg.try_fold_const() {
+                Some(Ok(Some(ScalarImpl::Bool(b)))) => path_style_access = b,
+                Some(Err(err)) => return Err(err),
+                _ => {
+                    return Err(BindError(
+                        "the 8th argument of file_scan_metadata function must be a constant \
+                         non-null boolean"
+                            .to_string(),
+                    )
+                    .into());
+                }
+            }
+        }
+        if args.len() == 7 {
+            let endpoint_arg = args.pop().unwrap();
+            match endpoint_arg.try_fold_const() {
+                Some(Ok(Some(ScalarImpl::Utf8(s)))) => s3_endpoint = Some(s.to_string()),
+                Some(Err(err)) => return Err(err),
+                _ => {
+                    return Err(BindError(
+                        "the 7th argument of file_scan_metadata function must be a constant \
+                         non-null varchar"
+                            .to_string(),
+                    )
+                    .into());
+                }
+            }
+        }
+        let mut eval_args: Vec<String> = vec![];
+        for arg in &args {
+            if arg.return_type() != DataType::Varchar {
+                return Err(BindError(
+                    "file_scan_metadata function only accepts string arguments".to_string(),
+                )
+                .into());
+            }
+            match arg.try_fold_const() {
+                Some(Ok(value)) => match value {
+                    Some(ScalarImpl::Utf8(s)) => {
+                        eval_args.push(s.to_string());
+                    }
+                    _ => {
+                        return Err(BindError(
+                            "file_scan_metadata function does not accept null arguments"
+                                .to_string(),
+                        )
+                        .into())
+                    }
+                },
+                Some(Err(err)) => {
+                    return Err(err);
+                }
+                None => {
+                    return Err(BindError(
+                        "file_scan_metadata function only accepts constant arguments".to_string(),
+                    )
+                    .into());
+                }
+            }
+        }
+        if !"parquet".eq_ignore_ascii_case(&eval_args[0]) {
+            return Err(BindError(
+                "file_scan_metadata function only accepts 'parquet' as file format".to_string(),
+            )
+            .into());
+        }
+        if !"s3".eq_ignore_ascii_case(&eval_args[1]) {
+            return Err(BindError(
+                "file_scan_metadata function only accepts 's3' as storage type".to_string(),
+            )
+            .into());
+        }
+
+        #[cfg(madsim)]
+        return Err(crate::error::ErrorCode::BindError(
+            "file_scan_metadata can't be used in the madsim mode".to_string(),
+        )
+        .into());
+
+        #[cfg(not(madsim))]
+        {
+            static RUNTIME: LazyLock<Runtime> = LazyLock::new(|| {
+                tokio::runtime::Builder::new_multi_thread()
+                    .thread_name("rw-file-scan-metadata")
+                    .enable_all()
+                    .build()
+                    .expect("failed to build file-scan-metadata runtime")
+            });
+
+            let stats = tokio::task::block_in_place(|| {
+                RUNTIME.block_on(async {
+                    let files = if eval_args[5].ends_with('/') {
+                        list_s3_directory(
+                            eval_args[2].clone(),
+                            eval_args[3].clone(),
+                            eval_args[4].clone(),
+                            s3_endpoint.clone(),
+                            path_style_access,
+                            eval_args[5].clone(),
+                        )
+                        .await?
+                    } else {
+                        vec![eval_args[5].clone()]
+                    };
+
+                    let mut stats = Vec::with_capacity(files.len());
+                    for file in files {
+                        let file_stats = read_parquet_file_stats(
+                            eval_args[2].clone(),
+                            eval_args[3].clone(),
+                            eval_args[4].clone(),
+                            s3_endpoint.clone(),
+                            path_style_access,
+                            file.clone(),
+                        )
+                        .await?;
+                        stats.push((file, file_stats));
+                    }
+                    Ok::<_, anyhow::Error>(stats)
+                })
+            })?;
+
+            // Flatten the stats into literal arguments, one row of 4 literals per file, for the
+            // optimizer rule to reassemble.
+            let args = stats
+                .into_iter()
+                .flat_map(|(file, s)| {
+                    [
+                        Literal::new(Some(ScalarImpl::Utf8(file.into())), DataType::Varchar),
+                        Literal::new(Some(ScalarImpl::Int64(s.num_rows)), DataType::Int64),
+                        Literal::new(Some(ScalarImpl::Int64(s.file_size)), DataType::Int64),
+                        Literal::new(Some(ScalarImpl::Int32(s.num_row_groups)), DataType::Int32),
+                    ]
+                })
+                .map(|l| ExprImpl::Literal(Box::new(l)))
+                .collect();
+
+            let return_type = DataType::Struct(StructType::new(vec![
+                ("file_name", DataType::Varchar),
+                ("num_rows", DataType::Int64),
+                ("file_size", DataType::Int64),
+                ("num_row_groups", DataType::Int32),
+            ]));
+
+            Ok(TableFunction {
+                args,
+                return_type,
+                function_type: TableFunctionType::FileScanMetadata,
+                user_defined: None,
+            })
+        }
+    }
+
     pub fn to_protobuf(&self) -> PbTableFunction {
         PbTableFunction {
             function_type: self.function_type as i32,
@@ -255,6 +869,258 @@ impl TableFunction {
     }
 }
 
+/// The name of the pseudo-column carrying the full S3 key each `file_scan` row was read from.
+pub const FILE_SCAN_FILE_PATH_COLUMN: &str = "_rw_file_path";
+
+/// Appends the `_rw_file_path` pseudo-column to a `file_scan` schema. The column is populated
+/// per file at read time and must therefore stay the last column.
+fn append_file_path_column(schema: StructType) -> StructType {
+    StructType::new(
+        schema
+            .iter()
+            .map(|(n, t)| (n.to_string(), t.clone()))
+            .chain(std::iter::once((
+                FILE_SCAN_FILE_PATH_COLUMN.to_string(),
+                DataType::Varchar,
+            )))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Unions the schemas sampled from several files into the `file_scan` return type, matching
+/// columns by name. Columns keep their first-seen order and columns only present in some files
+/// are read as NULL from the others. The same name appearing with two different types is an
+/// error, since there is no principled way to pick one at read time.
+fn union_file_schemas(file_schemas: Vec<Vec<(String, DataType)>>) -> RwResult<StructType> {
+    let mut fields: Vec<(String, DataType)> = vec![];
+    for file_schema in file_schemas {
+        for (name, data_type) in file_schema {
+            match fields.iter().find(|(n, _)| *n == name) {
+                None => fields.push((name, data_type)),
+                Some((_, existing)) if *existing == data_type => {}
+                Some((_, existing)) => {
+                    return Err(BindError(format!(
+                        "file_scan column \"{}\" has conflicting types across files: {} vs {}",
+                        name, existing, data_type
+                    ))
+                    .into());
+                }
+            }
+        }
+    }
+    Ok(StructType::new(fields))
+}
+
+/// Infers the `file_scan` return type from sampled NDJSON lines.
+///
+/// Every line must be a JSON object; a key's first appearance adds a column. A field's type is
+/// inferred per line by [`infer_json_type`] and unified across lines by [`unify_json_types`].
+/// Keys missing from a line simply read as NULL there, so they do not constrain the type; a
+/// key that is null in every sampled line falls back to jsonb, which can hold whatever shows
+/// up past the sample.
+fn infer_jsonl_schema(lines: &[String]) -> RwResult<StructType> {
+    let mut fields: Vec<(String, Option<DataType>)> = vec![];
+    for line in lines {
+        let value: serde_json::Value = serde_json::from_str(line).map_err(|e| {
+            BindError(format!("failed to parse sampled NDJSON line as JSON: {}", e))
+        })?;
+        let serde_json::Value::Object(object) = value else {
+            return Err(BindError(format!(
+                "each NDJSON line must be a JSON object, got a JSON {}",
+                json_type_name(&value)
+            ))
+            .into());
+        };
+        for (name, field_value) in &object {
+            let inferred = infer_json_type(field_value);
+            match fields.iter_mut().find(|(n, _)| n == name) {
+                None => fields.push((name.clone(), inferred)),
+                Some((_, known)) => match (known.take(), inferred) {
+                    (Some(a), Some(b)) => *known = Some(unify_json_types(a, b)),
+                    (a, b) => *known = a.or(b),
+                },
+            }
+        }
+    }
+    if fields.is_empty() {
+        return Err(BindError(
+            "could not infer a schema: the sampled NDJSON lines carry no object keys".to_string(),
+        )
+        .into());
+    }
+    Ok(StructType::new(
+        fields
+            .into_iter()
+            .map(|(name, data_type)| (name, data_type.unwrap_or(DataType::Jsonb)))
+            .collect::<Vec<_>>(),
+    ))
+}
+
+/// Infers the column type for one NDJSON field value. JSON `null` carries no type information
+/// (`None`); arrays and nested objects are read as jsonb as-is.
+fn infer_json_type(value: &serde_json::Value) -> Option<DataType> {
+    match value {
+        serde_json::Value::Null => None,
+        serde_json::Value::Bool(_) => Some(DataType::Boolean),
+        serde_json::Value::Number(n) if n.is_f64() => Some(DataType::Float64),
+        serde_json::Value::Number(_) => Some(DataType::Int64),
+        serde_json::Value::String(_) => Some(DataType::Varchar),
+        serde_json::Value::Array(_) | serde_json::Value::Object(_) => Some(DataType::Jsonb),
+    }
+}
+
+/// Unifies the types inferred for the same NDJSON field across lines: equal types stay, an
+/// integer meeting a double widens to double, and any other conflict falls back to jsonb,
+/// which represents each of the conflicting values losslessly.
+fn unify_json_types(a: DataType, b: DataType) -> DataType {
+    match (a, b) {
+        (a, b) if a == b => a,
+        (DataType::Int64, DataType::Float64) | (DataType::Float64, DataType::Int64) => {
+            DataType::Float64
+        }
+        _ => DataType::Jsonb,
+    }
+}
+
+/// The JSON type name of a value, for error messages.
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(_) => "number",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    }
+}
+
+/// Parses a dry-run schema argument like `a INT, b VARCHAR` into the struct return type of
+/// `file_scan`. Commas nested in a type (e.g. `struct<x INT, y INT>`) do not split fields.
+fn parse_schema_arg(def: &str) -> RwResult<StructType> {
+    let mut parts = vec![];
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in def.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&def[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&def[start..]);
+
+    let mut fields = vec![];
+    for part in parts {
+        let part = part.trim();
+        let Some((name, ty)) = part.split_once(char::is_whitespace) else {
+            return Err(BindError(format!(
+                "invalid field \"{}\" in file_scan schema argument, expected \"name type\"",
+                part
+            ))
+            .into());
+        };
+        let data_type = ty.trim().parse::<DataType>().map_err(|_| {
+            BindError(format!(
+                "invalid data type \"{}\" in file_scan schema argument",
+                ty.trim()
+            ))
+        })?;
+        fields.push((name.to_string(), data_type));
+    }
+    Ok(StructType::new(fields))
+}
+
+/// Validates a requested `file_scan` schema override against the inferred schema and returns
+/// it as the scan output type.
+///
+/// Each requested column must be present in the inferred schema, and a requested type that
+/// differs from the inferred one must be reachable through an assignment cast ([`cast_ok`]
+/// with [`CastContext::Assign`]) — the same rule `INSERT` applies — so e.g. an inferred `int`
+/// column can be requested as `bigint`, but not as `boolean`. Coercions that pass here are
+/// applied at read time; inferred columns left out of the override are simply not scanned.
+fn validate_schema_override(inferred: &StructType, requested: StructType) -> RwResult<StructType> {
+    for (name, requested_type) in requested.iter() {
+        let Some(inferred_type) = inferred
+            .iter()
+            .find_map(|(n, t)| (n == name).then_some(t))
+        else {
+            return Err(BindError(format!(
+                "column \"{}\" in the file_scan schema override was not found in the inferred \
+                 schema",
+                name
+            ))
+            .into());
+        };
+        if inferred_type != requested_type
+            && !cast_ok(inferred_type, requested_type, CastContext::Assign)
+        {
+            return Err(BindError(format!(
+                "cannot coerce file_scan column \"{}\" from inferred type {} to requested type {}",
+                name, inferred_type, requested_type
+            ))
+            .into());
+        }
+    }
+    Ok(requested)
+}
+
+/// Parses the resolved credential secret of a `file_scan` into `(region, access key, secret
+/// key, endpoint)`. The secret must be a JSON object with non-empty string fields `region`,
+/// `access_key` and `secret_key`; `endpoint` is optional. Anything else errors out naming the
+/// secret and the offending field, without echoing the secret contents.
+fn parse_credential_secret(
+    secret_name: &str,
+    value: &[u8],
+) -> RwResult<(String, String, String, Option<String>)> {
+    let text = std::str::from_utf8(value).map_err(|_| {
+        BindError(format!(
+            "credential secret \"{}\" of file_scan is not valid UTF-8",
+            secret_name
+        ))
+    })?;
+    let json: serde_json::Value = serde_json::from_str(text).map_err(|_| {
+        BindError(format!(
+            "credential secret \"{}\" of file_scan is not valid JSON",
+            secret_name
+        ))
+    })?;
+    let serde_json::Value::Object(fields) = json else {
+        return Err(BindError(format!(
+            "credential secret \"{}\" of file_scan must be a JSON object",
+            secret_name
+        ))
+        .into());
+    };
+
+    let get_string = |key: &str| match fields.get(key) {
+        Some(serde_json::Value::String(s)) if !s.is_empty() => Ok(Some(s.clone())),
+        None | Some(serde_json::Value::Null) => Ok(None),
+        Some(_) => Err(BindError(format!(
+            "field \"{}\" of credential secret \"{}\" must be a non-empty string",
+            key, secret_name
+        ))),
+    };
+    let get_required = |key: &str| {
+        get_string(key)?.ok_or_else(|| {
+            BindError(format!(
+                "credential secret \"{}\" of file_scan is missing field \"{}\"",
+                secret_name, key
+            ))
+        })
+    };
+
+    Ok((
+        get_required("region")?,
+        get_required("access_key")?,
+        get_required("secret_key")?,
+        get_string("endpoint")?,
+    ))
+}
+
 impl std::fmt::Debug for TableFunction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         if f.alternate() {
@@ -283,3 +1149,344 @@ impl Expr for TableFunction {
         unreachable!("Table function should not be converted to ExprNode")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file_scan_args(extra: impl IntoIterator<Item = ExprImpl>) -> Vec<ExprImpl> {
+        // Deliberately bogus credentials and location: a dry run must not touch them.
+        ["parquet", "s3", "no-such-region", "ak", "sk", "s3://no-such-bucket/dir/"]
+            .map(|s| ExprImpl::literal_varchar(s.to_string()))
+            .into_iter()
+            .chain(extra)
+            .collect()
+    }
+
+    #[test]
+    fn test_new_file_scan_dry_run() {
+        // With the schema provided, binding succeeds without any object-store access — the
+        // bogus region and bucket above would fail any listing or schema-inference call.
+        let args = file_scan_args([ExprImpl::literal_varchar("a INT, b VARCHAR".to_string())]);
+        let function = TableFunction::new_file_scan(args, true).unwrap();
+        assert_eq!(function.function_type, TableFunctionType::FileScan);
+        assert_eq!(
+            function.return_type,
+            DataType::Struct(StructType::new(vec![
+                ("a", DataType::Int32),
+                ("b", DataType::Varchar),
+            ]))
+        );
+        // The schema argument is consumed; the remaining args are the usual six.
+        assert_eq!(function.args.len(), 6);
+
+        // Without the schema argument the dry run fails instead of hitting the network.
+        TableFunction::new_file_scan(file_scan_args([]), true).unwrap_err();
+    }
+
+    #[test]
+    fn test_new_file_scan_file_path_column() {
+        // 7th = schema (dry run), 8th = path-style, 9th = include `_rw_file_path`.
+        let args = file_scan_args([
+            ExprImpl::literal_varchar("a INT".to_string()),
+            ExprImpl::literal_bool(true),
+            ExprImpl::literal_bool(true),
+        ]);
+        let function = TableFunction::new_file_scan(args, true).unwrap();
+        // The pseudo-column is appended as the last column...
+        assert_eq!(
+            function.return_type,
+            DataType::Struct(StructType::new(vec![
+                ("a", DataType::Int32),
+                (FILE_SCAN_FILE_PATH_COLUMN, DataType::Varchar),
+            ]))
+        );
+        // ... and the flag is folded into the storage-type argument for the optimizer rule.
+        assert_eq!(
+            function.args[1].as_literal().unwrap().get_data(),
+            &Some(ScalarImpl::Utf8("s3;file_path=true".into()))
+        );
+
+        // With the flag off, the schema and the storage argument are untouched.
+        let args = file_scan_args([
+            ExprImpl::literal_varchar("a INT".to_string()),
+            ExprImpl::literal_bool(true),
+            ExprImpl::literal_bool(false),
+        ]);
+        let function = TableFunction::new_file_scan(args, true).unwrap();
+        assert_eq!(
+            function.return_type,
+            DataType::Struct(StructType::new(vec![("a", DataType::Int32)]))
+        );
+        assert_eq!(
+            function.args[1].as_literal().unwrap().get_data(),
+            &Some(ScalarImpl::Utf8("s3".into()))
+        );
+    }
+
+    #[test]
+    fn test_new_file_scan_sample_rows() {
+        // 7th = schema (dry run), 8th = path-style, 9th = include `_rw_file_path`,
+        // 10th = sample_rows.
+        let args = file_scan_args([
+            ExprImpl::literal_varchar("a INT".to_string()),
+            ExprImpl::literal_bool(true),
+            ExprImpl::literal_bool(false),
+            ExprImpl::literal_int(10),
+        ]);
+        let function = TableFunction::new_file_scan(args, true).unwrap();
+        // The limit is folded into the storage-type argument for the optimizer rule.
+        assert_eq!(
+            function.args[1].as_literal().unwrap().get_data(),
+            &Some(ScalarImpl::Utf8("s3;sample_rows=10".into()))
+        );
+
+        // A non-positive limit is rejected at binding time.
+        let args = file_scan_args([
+            ExprImpl::literal_varchar("a INT".to_string()),
+            ExprImpl::literal_bool(true),
+            ExprImpl::literal_bool(false),
+            ExprImpl::literal_int(0),
+        ]);
+        let err = TableFunction::new_file_scan(args, true).unwrap_err();
+        assert!(err.to_string().contains("must be positive"), "{err}");
+    }
+
+    #[test]
+    fn test_parse_schema_arg() {
+        let schema = parse_schema_arg("a INT, b VARCHAR, tags INT[]").unwrap();
+        assert_eq!(
+            schema,
+            StructType::new(vec![
+                ("a", DataType::Int32),
+                ("b", DataType::Varchar),
+                ("tags", DataType::List(Box::new(DataType::Int32))),
+            ])
+        );
+
+        // A comma nested in a struct type does not split fields.
+        let schema = parse_schema_arg("s struct<x INT, y INT>, z INT").unwrap();
+        assert_eq!(schema.names().count(), 2);
+
+        parse_schema_arg("missing_type").unwrap_err();
+        parse_schema_arg("a NOT_A_TYPE").unwrap_err();
+    }
+
+    #[test]
+    fn test_validate_schema_override() {
+        let inferred = StructType::new(vec![
+            ("a", DataType::Int32),
+            ("b", DataType::Varchar),
+            ("c", DataType::Float64),
+        ]);
+
+        // Widening `a` to bigint is an assignment cast; leaving out `b` and `c` projects them
+        // away. The requested schema becomes the output type verbatim.
+        let requested = StructType::new(vec![("a", DataType::Int64)]);
+        assert_eq!(
+            validate_schema_override(&inferred, requested.clone()).unwrap(),
+            requested
+        );
+
+        // varchar -> int only exists as an explicit cast, so it is rejected at bind time.
+        let err = validate_schema_override(
+            &inferred,
+            StructType::new(vec![("b", DataType::Int32)]),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "cannot coerce file_scan column \"b\" from inferred type character varying to \
+                 requested type integer"
+            ),
+            "{}",
+            err
+        );
+
+        // Columns absent from the inferred schema have nothing to coerce from.
+        let err = validate_schema_override(
+            &inferred,
+            StructType::new(vec![("missing", DataType::Int32)]),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("column \"missing\""), "{}", err);
+    }
+
+    #[test]
+    fn test_new_user_defined_rejects_non_table_kind() {
+        // A malformed catalog entry (e.g. a scalar function bound as a table function) must
+        // produce a bind error instead of panicking.
+        let catalog = Arc::new(FunctionCatalog {
+            id: 1.into(),
+            name: "not_a_tf".to_string(),
+            owner: 1,
+            kind: FunctionKind::Scalar,
+            arg_names: vec![],
+            arg_types: vec![],
+            return_type: DataType::Int32,
+            language: "sql".to_string(),
+            identifier: None,
+            body: None,
+            link: None,
+            compressed_binary: None,
+            always_retry_on_network_error: false,
+            function_type: None,
+            runtime: None,
+        });
+
+        let err = TableFunction::new_user_defined(catalog.clone(), vec![]).unwrap_err();
+        assert!(err.to_string().contains("not a table function"), "{err}");
+
+        // The same entry with the table kind binds fine.
+        let mut catalog = (*catalog).clone();
+        catalog.kind = FunctionKind::Table;
+        let function = TableFunction::new_user_defined(Arc::new(catalog), vec![]).unwrap();
+        assert_eq!(function.function_type, TableFunctionType::UserDefined);
+    }
+
+    #[test]
+    fn test_union_file_schemas() {
+        let schema = |fields: &[(&str, DataType)]| {
+            fields
+                .iter()
+                .map(|(n, t)| (n.to_string(), t.clone()))
+                .collect::<Vec<_>>()
+        };
+
+        // Two files with divergent columns: the union keeps first-seen order and appends the
+        // newer file's extra column.
+        let old_file = schema(&[("a", DataType::Int32), ("b", DataType::Varchar)]);
+        let new_file = schema(&[
+            ("a", DataType::Int32),
+            ("b", DataType::Varchar),
+            ("c", DataType::Float64),
+        ]);
+        let unioned = union_file_schemas(vec![old_file.clone(), new_file.clone()]).unwrap();
+        assert_eq!(
+            unioned,
+            StructType::new(vec![
+                ("a", DataType::Int32),
+                ("b", DataType::Varchar),
+                ("c", DataType::Float64),
+            ])
+        );
+        // The order of the sampled files does not change the result set of columns.
+        assert_eq!(
+            union_file_schemas(vec![new_file, old_file]).unwrap(),
+            unioned
+        );
+
+        // The same name with two different types is rejected.
+        union_file_schemas(vec![
+            schema(&[("a", DataType::Int32)]),
+            schema(&[("a", DataType::Varchar)]),
+        ])
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_infer_jsonl_schema() {
+        let lines =
+            |lines: &[&str]| lines.iter().map(|l| l.to_string()).collect::<Vec<String>>();
+
+        // Uniform lines: each key gets its natural scalar type; arrays stay jsonb.
+        let schema = infer_jsonl_schema(&lines(&[
+            r#"{"id": 1, "name": "a", "ok": true, "score": 1.5, "tags": [1, 2]}"#,
+            r#"{"id": 2, "name": "b", "ok": false, "score": 2.5, "tags": []}"#,
+        ]))
+        .unwrap();
+        assert_eq!(
+            schema,
+            StructType::new(vec![
+                ("id", DataType::Int64),
+                ("name", DataType::Varchar),
+                ("ok", DataType::Boolean),
+                ("score", DataType::Float64),
+                ("tags", DataType::Jsonb),
+            ])
+        );
+
+        // Heterogeneous lines: keys missing from a line don't constrain the type, an integer
+        // meeting a double widens, an integer meeting a string falls back to jsonb, and an
+        // always-null key falls back to jsonb too.
+        let schema = infer_jsonl_schema(&lines(&[
+            r#"{"a": 1, "b": 1, "c": "x", "d": null}"#,
+            r#"{"a": 2.5, "b": "oops", "d": null, "e": true}"#,
+        ]))
+        .unwrap();
+        assert_eq!(
+            schema,
+            StructType::new(vec![
+                ("a", DataType::Float64),
+                ("b", DataType::Jsonb),
+                ("c", DataType::Varchar),
+                ("d", DataType::Jsonb),
+                ("e", DataType::Boolean),
+            ])
+        );
+
+        // A null defers to a typed value seen later.
+        let schema = infer_jsonl_schema(&lines(&[r#"{"a": null}"#, r#"{"a": 7}"#])).unwrap();
+        assert_eq!(schema, StructType::new(vec![("a", DataType::Int64)]));
+
+        // Non-object lines, malformed JSON and key-less samples are all rejected.
+        infer_jsonl_schema(&lines(&["[1, 2]"])).unwrap_err();
+        infer_jsonl_schema(&lines(&[r#"{"a": "#])).unwrap_err();
+        infer_jsonl_schema(&lines(&["{}"])).unwrap_err();
+    }
+
+    #[test]
+    fn test_parse_credential_secret() {
+        // Happy path, with and without the optional endpoint.
+        let full = br#"{
+            "region": "us-east-1",
+            "access_key": "ak",
+            "secret_key": "sk",
+            "endpoint": "http://minio:9000"
+        }"#;
+        assert_eq!(
+            parse_credential_secret("my_creds", full).unwrap(),
+            (
+                "us-east-1".to_string(),
+                "ak".to_string(),
+                "sk".to_string(),
+                Some("http://minio:9000".to_string())
+            )
+        );
+        let minimal = br#"{"region": "eu-west-1", "access_key": "a", "secret_key": "s"}"#;
+        assert_eq!(
+            parse_credential_secret("my_creds", minimal).unwrap(),
+            (
+                "eu-west-1".to_string(),
+                "a".to_string(),
+                "s".to_string(),
+                None
+            )
+        );
+
+        // Malformed or mistyped secrets fail with an error naming the secret (and field), but
+        // never echoing the secret contents.
+        for (value, needle) in [
+            (&b"not json"[..], "not valid JSON"),
+            (&br#""just a string""#[..], "must be a JSON object"),
+            (
+                &br#"{"access_key": "a", "secret_key": "s"}"#[..],
+                "missing field \"region\"",
+            ),
+            (
+                &br#"{"region": "r", "access_key": 42, "secret_key": "s"}"#[..],
+                "field \"access_key\"",
+            ),
+            (
+                &br#"{"region": "r", "access_key": "a", "secret_key": "s", "endpoint": 1}"#[..],
+                "field \"endpoint\"",
+            ),
+        ] {
+            let err = parse_credential_secret("my_creds", value).unwrap_err();
+            let msg = err.to_string();
+            assert!(msg.contains("my_creds"), "{}", msg);
+            assert!(msg.contains(needle), "{}", msg);
+            assert!(!msg.contains("secret_key\": \"s"), "{}", msg);
+        }
+    }
+}
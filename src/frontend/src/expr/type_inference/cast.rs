@@ -12,12 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::BTreeMap;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::LazyLock;
 
 use itertools::Itertools as _;
 use parse_display::Display;
-use risingwave_common::types::{DataType, DataTypeName};
+use risingwave_common::types::{DataType, DataTypeName, StructType};
 use risingwave_common::util::iter_util::ZipEqFast;
 
 use crate::error::ErrorCode;
@@ -32,26 +33,125 @@ use crate::expr::{Expr as _, ExprImpl, InputRef, Literal};
 ///
 /// Note: be careful that literal strings are considered untyped.
 /// e.g., `align_types(1, '1')` will be `Int32`, but `least_restrictive(Int32, Varchar)` will return error.
+///
+/// Unlike PG's `numeric(p, s)`, RisingWave's [`DataType::Decimal`] does not carry a declared
+/// precision/scale, so combining two differently-scaled numerics hits the equal-types fast path
+/// and can never truncate to the narrower declaration.
 fn least_restrictive(lhs: DataType, rhs: DataType) -> std::result::Result<DataType, ErrorCode> {
-    if lhs == rhs {
-        Ok(lhs)
-    } else if cast_ok(&lhs, &rhs, CastContext::Implicit) {
-        Ok(rhs)
-    } else if cast_ok(&rhs, &lhs, CastContext::Implicit) {
-        Ok(lhs)
-    } else {
-        Err(ErrorCode::BindError(format!(
+    match (lhs, rhs) {
+        (lhs, rhs) if lhs == rhs => Ok(lhs),
+        // Composite types are unified element/field-wise, so e.g. `UNION` of `(int, int)` and
+        // `(int, bigint)` structs yields `(int, bigint)` instead of requiring one side to be
+        // wholly castable into the other.
+        (DataType::Struct(lhs), DataType::Struct(rhs)) => least_restrictive_struct(lhs, rhs),
+        (DataType::List(lhs), DataType::List(rhs)) => {
+            Ok(DataType::List(Box::new(least_restrictive(*lhs, *rhs)?)))
+        }
+        (lhs, rhs) if cast_ok(&lhs, &rhs, CastContext::Implicit) => Ok(rhs),
+        (lhs, rhs) if cast_ok(&rhs, &lhs, CastContext::Implicit) => Ok(lhs),
+        (lhs, rhs) => Err(ErrorCode::BindError(format!(
             "types {:?} and {:?} cannot be matched",
             lhs, rhs
-        )))
+        ))),
     }
 }
 
+/// Field-wise [`least_restrictive`] over two structs of the same length, producing a widened
+/// struct type. Field names follow the left-hand side, like `UNION` taking column names from
+/// its first branch.
+fn least_restrictive_struct(
+    lhs: StructType,
+    rhs: StructType,
+) -> std::result::Result<DataType, ErrorCode> {
+    if lhs.len() != rhs.len() {
+        return Err(ErrorCode::BindError(format!(
+            "types {:?} and {:?} cannot be matched",
+            DataType::Struct(lhs),
+            DataType::Struct(rhs)
+        )));
+    }
+    let fields = lhs
+        .iter()
+        .zip_eq_fast(rhs.types())
+        .map(|((name, lty), rty)| {
+            Ok((
+                name.to_string(),
+                least_restrictive(lty.clone(), rty.clone())?,
+            ))
+        })
+        .collect::<std::result::Result<Vec<_>, ErrorCode>>()?;
+    Ok(DataType::Struct(StructType::new(fields)))
+}
+
 /// Find the `least_restrictive` type over a list of `exprs`, and add implicit cast when necessary.
 /// Used by `VALUES`, `CASE`, `UNION`, etc. See [PG](https://www.postgresql.org/docs/current/typeconv-union-case.html).
 pub fn align_types<'a>(
     exprs: impl Iterator<Item = &'a mut ExprImpl>,
 ) -> std::result::Result<DataType, ErrorCode> {
+    align_types_with_fallback(exprs).map(|(ret_type, _)| ret_type)
+}
+
+/// Same as [`align_types`], but additionally reports whether the result fell back to `Varchar`
+/// because every expression was untyped (e.g. an all-`NULL` `VALUES` column), so that callers can
+/// notify the user about the silently chosen type.
+pub fn align_types_with_fallback<'a>(
+    exprs: impl Iterator<Item = &'a mut ExprImpl>,
+) -> std::result::Result<(DataType, bool), ErrorCode> {
+    let mut exprs = exprs.fuse();
+    // Specialize the common 1- and 2-element cases (`CASE`, binary operators) to avoid
+    // collecting the iterator into a `Vec` on hot binding paths.
+    match (exprs.next(), exprs.next(), exprs.next()) {
+        (None, _, _) => Ok((DataType::Varchar, true)),
+        (Some(e), None, _) => {
+            let (ret_type, is_fallback) = match e.is_untyped() {
+                true => (DataType::Varchar, true),
+                false => (e.return_type(), false),
+            };
+            e.cast_implicit_mut(ret_type.clone()).unwrap();
+            Ok((ret_type, is_fallback))
+        }
+        (Some(lhs), Some(rhs), None) => {
+            let ret_type = match (lhs.is_untyped(), rhs.is_untyped()) {
+                (true, true) => None,
+                (false, true) => Some(lhs.return_type()),
+                (true, false) => Some(rhs.return_type()),
+                (false, false) => Some(least_restrictive(lhs.return_type(), rhs.return_type())?),
+            };
+            let is_fallback = ret_type.is_none();
+            let ret_type = ret_type.unwrap_or(DataType::Varchar);
+            // unwrap: cast to least_restrictive type always succeeds
+            lhs.cast_implicit_mut(ret_type.clone()).unwrap();
+            rhs.cast_implicit_mut(ret_type.clone()).unwrap();
+            Ok((ret_type, is_fallback))
+        }
+        (Some(a), Some(b), Some(c)) => align_types_general([a, b, c].into_iter().chain(exprs)),
+    }
+}
+
+/// Same as [`align_types`], but additionally reports which exprs were actually cast: the flag
+/// at each position is `true` iff the expr's return type changed to reach the common type, or
+/// the expr was untyped and got coerced. Useful for diagnostics (e.g. accurate `EXPLAIN`) and
+/// tests; the primary in-place API stays unchanged.
+pub fn align_types_report<'a>(
+    exprs: impl Iterator<Item = &'a mut ExprImpl>,
+) -> std::result::Result<(DataType, Vec<bool>), ErrorCode> {
+    let exprs = exprs.collect_vec();
+    let types_before: Vec<Option<DataType>> = exprs
+        .iter()
+        .map(|e| (!e.is_untyped()).then(|| e.return_type()))
+        .collect();
+    let (ret_type, _) = align_types_with_fallback(exprs.into_iter())?;
+    let casted = types_before
+        .iter()
+        .map(|ty| ty.as_ref() != Some(&ret_type))
+        .collect();
+    Ok((ret_type, casted))
+}
+
+/// The general, `Vec`-collecting path of [`align_types_with_fallback`] for 3 or more exprs.
+fn align_types_general<'a>(
+    exprs: impl Iterator<Item = &'a mut ExprImpl>,
+) -> std::result::Result<(DataType, bool), ErrorCode> {
     let exprs = exprs.collect_vec();
     // Essentially a filter_map followed by a try_reduce, which is unstable.
     let mut ret_type = None;
@@ -64,12 +164,13 @@ pub fn align_types<'a>(
             Some(t) => Some(least_restrictive(t, e.return_type())?),
         };
     }
+    let is_fallback = ret_type.is_none();
     let ret_type = ret_type.unwrap_or(DataType::Varchar);
     for e in exprs {
         // unwrap: cast to least_restrictive type always succeeds
         e.cast_implicit_mut(ret_type.clone()).unwrap();
     }
-    Ok(ret_type)
+    Ok((ret_type, is_fallback))
 }
 
 /// Aligns an array and an element by returning a possible common array type and casting them into
@@ -114,8 +215,48 @@ pub fn align_array_and_element(
     Ok(array_type)
 }
 
+thread_local! {
+    /// Memoized [`cast_ok`] results for composite types. Struct, array and map casts recurse
+    /// per field, so planning a schema with hundreds of nested fields would otherwise recompute
+    /// the same sub-results over and over. The key includes the session's strict-struct-cast
+    /// flag, which the struct rule depends on. Bounded in practice by the set of distinct types
+    /// appearing in queries on this thread.
+    static COMPOSITE_CAST_OK_CACHE: RefCell<HashMap<(DataType, DataType, CastContext, bool), bool>> =
+        RefCell::new(HashMap::new());
+}
+
 /// Checks whether casting from `source` to `target` is ok in `allows` context.
+///
+/// Results for composite (struct/array/map) types are memoized per thread, including the
+/// intermediate results of the field-wise recursion. Base-type casts are a single map lookup
+/// and bypass the cache.
 pub fn cast_ok(source: &DataType, target: &DataType, allows: CastContext) -> bool {
+    let composite = |ty: &DataType| {
+        matches!(
+            ty,
+            DataType::Struct(_) | DataType::List(_) | DataType::Map(_)
+        )
+    };
+    if !composite(source) && !composite(target) {
+        return cast_ok_uncached(source, target, allows);
+    }
+    let key = (
+        source.clone(),
+        target.clone(),
+        allows,
+        crate::session::current::strict_struct_cast(),
+    );
+    if let Some(ok) = COMPOSITE_CAST_OK_CACHE.with_borrow(|cache| cache.get(&key).copied()) {
+        return ok;
+    }
+    let ok = cast_ok_uncached(source, target, allows);
+    COMPOSITE_CAST_OK_CACHE.with_borrow_mut(|cache| cache.insert(key, ok));
+    ok
+}
+
+/// The uncached recursion behind [`cast_ok`]. Note that the recursive calls on fields and
+/// elements go through the memoized [`cast_ok`], so nested sub-results are shared.
+fn cast_ok_uncached(source: &DataType, target: &DataType, allows: CastContext) -> bool {
     cast_ok_struct(source, target, allows)
         || cast_ok_array(source, target, allows)
         || cast_ok_map(source, target, allows)
@@ -124,11 +265,51 @@ pub fn cast_ok(source: &DataType, target: &DataType, allows: CastContext) -> boo
 
 /// Checks whether casting from `source` to `target` is ok in `allows` context.
 /// Both `source` and `target` must be base types, i.e. not struct or array.
+///
+/// The lookup order is deterministic: the static [`CAST_MAP`] is consulted first and is
+/// authoritative for built-ins; only on a miss is the extension map populated by
+/// [`register_extension_cast`] consulted.
 pub fn cast_ok_base(source: &DataType, target: &DataType, allows: CastContext) -> bool {
-    matches!(CAST_MAP.get(&(source.into(), target.into())), Some(context) if *context <= allows)
+    if let Some(context) = CAST_MAP.get(&(source.into(), target.into())) {
+        return *context <= allows;
+    }
+    matches!(
+        EXTENSION_CAST_MAP.read().get(&(source.into(), target.into())),
+        Some(context) if *context <= allows
+    )
+}
+
+/// Extra cast edges registered at startup for extension types compiled into the binary,
+/// consulted by [`cast_ok_base`] only when the static [`CAST_MAP`] has no entry.
+static EXTENSION_CAST_MAP: LazyLock<parking_lot::RwLock<CastMap>> =
+    LazyLock::new(Default::default);
+
+/// Registers an additional cast edge for an extension type, to be called once at startup.
+///
+/// The static [`CAST_MAP`] stays authoritative for built-ins: it is consulted first, so an
+/// edge it already contains can be neither loosened nor tightened here. Registering the same
+/// extension edge twice keeps the last context. The edge only affects the single-hop
+/// [`cast_ok`] checks; it does not participate in the implicit-cast chains used by type
+/// inference (e.g. [`implicit_cast_closure`]).
+pub fn register_extension_cast(source: DataTypeName, target: DataTypeName, context: CastContext) {
+    EXTENSION_CAST_MAP.write().insert((source, target), context);
 }
 
 fn cast_ok_struct(source: &DataType, target: &DataType, allows: CastContext) -> bool {
+    cast_ok_struct_inner(
+        source,
+        target,
+        allows,
+        crate::session::current::strict_struct_cast(),
+    )
+}
+
+fn cast_ok_struct_inner(
+    source: &DataType,
+    target: &DataType,
+    allows: CastContext,
+    strict: bool,
+) -> bool {
     match (source, target) {
         (DataType::Struct(lty), DataType::Struct(rty)) => {
             if lty.is_empty() || rty.is_empty() {
@@ -138,6 +319,15 @@ fn cast_ok_struct(source: &DataType, target: &DataType, allows: CastContext) ->
                 // only cast structs of the same length
                 return false;
             }
+            // In strict mode, a struct carrying a named-type identity only casts to the same
+            // named type, unless the cast is explicit.
+            if strict
+                && allows < CastContext::Explicit
+                && (lty.identity().is_some() || rty.identity().is_some())
+                && lty.identity() != rty.identity()
+            {
+                return false;
+            }
             // ... and all fields are castable
             lty.types()
                 .zip_eq_fast(rty.types())
@@ -156,6 +346,7 @@ fn cast_ok_array(source: &DataType, target: &DataType, allows: CastContext) -> b
     match (source, target) {
         (DataType::List(source_elem), DataType::List(target_elem)) => {
             cast_ok(source_elem, target_elem, allows)
+                || cast_ok_array_nesting(source_elem, target_elem, allows)
         }
         // The automatic casts to string types are treated as assignment casts, while the automatic
         // casts from string types are explicit-only.
@@ -166,6 +357,41 @@ fn cast_ok_array(source: &DataType, target: &DataType, allows: CastContext) -> b
     }
 }
 
+/// Checks explicit-only casts between array types of different dimensionality.
+///
+/// - Flatten, e.g. `int[][]::int[]`: removes one nesting level by concatenating the inner arrays
+///   in row-major order. `NULL` inner arrays contribute no elements.
+/// - Nest, e.g. `int[]::int[][]`: adds one nesting level by wrapping each element (including
+///   `NULL`s) in a singleton inner array.
+///
+/// Only one nesting level is changed per cast, and the element-wise cast takes precedence when
+/// both could apply; in particular, casts whose element side is `varchar` keep their existing
+/// to-text / parse semantics.
+fn cast_ok_array_nesting(
+    source_elem: &DataType,
+    target_elem: &DataType,
+    allows: CastContext,
+) -> bool {
+    if allows < CastContext::Explicit {
+        return false;
+    }
+    match (source_elem, target_elem) {
+        // Flatten: `T[][] -> U[]` where `T` casts to `U`.
+        (DataType::List(inner), target)
+            if !matches!(target, DataType::List(_) | DataType::Varchar) =>
+        {
+            **inner == *target || cast_ok(inner, target, allows)
+        }
+        // Nest: `T[] -> U[][]` where `T` casts to `U`.
+        (source, DataType::List(inner))
+            if !matches!(source, DataType::List(_) | DataType::Varchar) =>
+        {
+            *source == **inner || cast_ok(source, inner, allows)
+        }
+        _ => false,
+    }
+}
+
 fn cast_ok_map(source: &DataType, target: &DataType, allows: CastContext) -> bool {
     match (source, target) {
         (DataType::Map(source_elem), DataType::Map(target_elem)) => cast_ok(
@@ -173,10 +399,167 @@ fn cast_ok_map(source: &DataType, target: &DataType, allows: CastContext) -> boo
             &target_elem.clone().into_list(),
             allows,
         ),
+        // A map can always be serialized into a JSON object.
+        (DataType::Map(_), DataType::Jsonb) => CastContext::Explicit <= allows,
+        // A flat JSON object can be parsed back into a map, as long as the object keys (always
+        // strings) and values (jsonb) can be coerced to the map's key and value types. Nested
+        // objects/arrays are rejected at runtime unless the value type is jsonb itself.
+        (DataType::Jsonb, DataType::Map(target_elem)) => {
+            CastContext::Explicit <= allows
+                && (target_elem.key() == &DataType::Varchar
+                    || cast_ok(&DataType::Varchar, target_elem.key(), CastContext::Explicit))
+                && (target_elem.value() == &DataType::Jsonb
+                    || cast_ok(&DataType::Jsonb, target_elem.value(), CastContext::Explicit))
+        }
         _ => false,
     }
 }
 
+/// A precise reason for a refused cast, as produced by [`cast_reason`]. The path walks from
+/// the outermost type to the failing position (e.g. `field "a"`, then `element`), so the
+/// binder can report nested failures exactly instead of reconstructing them from the boolean
+/// returned by [`cast_ok`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CastFailure {
+    path: Vec<String>,
+    reason: String,
+}
+
+impl CastFailure {
+    fn new(reason: String) -> Self {
+        Self {
+            path: vec![],
+            reason,
+        }
+    }
+
+    /// Prefixes the path with the position in the enclosing composite type.
+    fn at(mut self, segment: impl Into<String>) -> Self {
+        self.path.insert(0, segment.into());
+        self
+    }
+}
+
+impl std::fmt::Display for CastFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.reason)
+        } else {
+            write!(f, "{}: {}", self.path.join(", "), self.reason)
+        }
+    }
+}
+
+/// Like [`cast_ok`], but explains a refusal: the returned [`CastFailure`] pinpoints the
+/// element/field path that fails and whether the cast is impossible or merely needs a stronger
+/// context. Mirrors the `cast_ok_*` recursion structure.
+pub fn cast_reason(
+    source: &DataType,
+    target: &DataType,
+    allows: CastContext,
+) -> std::result::Result<(), CastFailure> {
+    if cast_ok(source, target, allows) {
+        return Ok(());
+    }
+    Err(cast_failure(source, target, allows))
+}
+
+/// Human-readable name of the weakest context a cast is allowed in, for diagnostics.
+fn context_name(context: CastContext) -> &'static str {
+    match context {
+        CastContext::Implicit => "implicit",
+        CastContext::Assign => "assignment",
+        CastContext::Explicit => "explicit",
+    }
+}
+
+/// Pinpoints why [`cast_ok`] returned `false`. Only meaningful for failing combinations.
+fn cast_failure(source: &DataType, target: &DataType, allows: CastContext) -> CastFailure {
+    debug_assert!(!cast_ok(source, target, allows));
+    let needs_context = |required: CastContext| {
+        CastFailure::new(format!(
+            "cast from {} to {} is only allowed in {} contexts",
+            source,
+            target,
+            context_name(required)
+        ))
+    };
+    match (source, target) {
+        (DataType::Struct(lty), DataType::Struct(rty)) => {
+            if lty.len() != rty.len() {
+                return CastFailure::new(format!(
+                    "cannot cast between structs of {} and {} fields",
+                    lty.len(),
+                    rty.len()
+                ));
+            }
+            if crate::session::current::strict_struct_cast()
+                && allows < CastContext::Explicit
+                && (lty.identity().is_some() || rty.identity().is_some())
+                && lty.identity() != rty.identity()
+            {
+                return CastFailure::new(
+                    "casting between distinct named struct types requires an explicit cast \
+                     under strict struct casting"
+                        .to_string(),
+                );
+            }
+            for ((name, src), dst) in lty.iter().zip_eq_fast(rty.types()) {
+                if src != dst && !cast_ok(src, dst, allows) {
+                    return cast_failure(src, dst, allows).at(format!("field \"{}\"", name));
+                }
+            }
+            CastFailure::new(format!("cannot cast {} to {}", source, target))
+        }
+        (DataType::Varchar, DataType::Struct(_) | DataType::List(_)) => {
+            needs_context(CastContext::Explicit)
+        }
+        (DataType::Struct(_) | DataType::List(_), DataType::Varchar) => {
+            needs_context(CastContext::Assign)
+        }
+        (DataType::List(source_elem), DataType::List(target_elem)) => {
+            // A dimensionality-changing rescue would have made `cast_ok` succeed, so the
+            // element cast itself is at fault.
+            cast_failure(source_elem, target_elem, allows).at("element")
+        }
+        (DataType::Map(source_elem), DataType::Map(target_elem)) => {
+            let source_kv = source_elem.clone().into_list();
+            let target_kv = target_elem.clone().into_list();
+            cast_failure(&source_kv, &target_kv, allows)
+        }
+        (DataType::Map(_), DataType::Jsonb) => needs_context(CastContext::Explicit),
+        (DataType::Jsonb, DataType::Map(target_elem)) => {
+            if allows < CastContext::Explicit {
+                return needs_context(CastContext::Explicit);
+            }
+            let key = target_elem.key();
+            if key != &DataType::Varchar && !cast_ok(&DataType::Varchar, key, CastContext::Explicit)
+            {
+                return cast_failure(&DataType::Varchar, key, CastContext::Explicit)
+                    .at("map key (a JSON object key is a string)");
+            }
+            cast_failure(&DataType::Jsonb, target_elem.value(), CastContext::Explicit)
+                .at("map value")
+        }
+        _ => {
+            // Static entries first, then extension edges, like `cast_ok_base`.
+            let known = CAST_MAP
+                .get(&(source.into(), target.into()))
+                .copied()
+                .or_else(|| {
+                    EXTENSION_CAST_MAP
+                        .read()
+                        .get(&(source.into(), target.into()))
+                        .copied()
+                });
+            match known {
+                Some(required) => needs_context(required),
+                None => CastFailure::new(format!("cannot cast {} to {}", source, target)),
+            }
+        }
+    }
+}
+
 pub fn cast_map_array() -> Vec<(DataTypeName, DataTypeName, CastContext)> {
     CAST_MAP
         .iter()
@@ -191,10 +574,30 @@ pub struct CastSig {
     pub context: CastContext,
 }
 
+impl CastSig {
+    /// The PG-compatible type OIDs of the source and target types, as exposed by the
+    /// `castsource` / `casttarget` columns of `pg_cast`.
+    pub fn to_oids(&self) -> (i32, i32) {
+        (
+            data_type_name_to_oid(self.from_type),
+            data_type_name_to_oid(self.to_type),
+        )
+    }
+}
+
+/// Maps a [`DataTypeName`] to its PG-compatible type OID, consistent with [`DataType::to_oid`].
+///
+/// All types appearing in [`CAST_MAP`] are base types, for which the conversion never fails.
+pub fn data_type_name_to_oid(name: DataTypeName) -> i32 {
+    DataType::try_from(name)
+        .unwrap_or_else(|_| panic!("no corresponding data type for {name:?}"))
+        .to_oid()
+}
+
 /// The context a cast operation is invoked in. An implicit cast operation is allowed in a context
 /// that allows explicit casts, but not vice versa. See details in
 /// [PG](https://www.postgresql.org/docs/current/catalog-pg-cast.html).
-#[derive(Clone, Copy, Debug, Display, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Display, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum CastContext {
     #[display("i")]
     Implicit,
@@ -206,6 +609,72 @@ pub enum CastContext {
 
 pub type CastMap = BTreeMap<(DataTypeName, DataTypeName), CastContext>;
 
+/// Returns the set of types reachable from `from` through any chain of implicit casts in
+/// [`CAST_MAP`], including `from` itself. Unlike the single-hop [`cast_ok`], this computes the
+/// transitive closure, e.g. for query rewriting that may stack several implicit casts.
+pub fn implicit_cast_closure(from: DataTypeName) -> HashSet<DataTypeName> {
+    implicit_cast_closure_in(&CAST_MAP, from)
+}
+
+/// BFS over the implicit edges of `map`, so that even a (hypothetical) cycle in the map cannot
+/// loop forever.
+fn implicit_cast_closure_in(map: &CastMap, from: DataTypeName) -> HashSet<DataTypeName> {
+    let mut reachable = HashSet::from([from]);
+    let mut queue = vec![from];
+    while let Some(ty) = queue.pop() {
+        for ((source, target), context) in map {
+            if *source == ty && *context == CastContext::Implicit && reachable.insert(*target) {
+                queue.push(*target);
+            }
+        }
+    }
+    reachable
+}
+
+/// The base types covered by [`CAST_MAP`], in the order used by the rows and columns of
+/// [`cast_matrix`]. The order is stable across releases so that rendered matrices stay
+/// comparable; new types are appended at the end.
+pub const CAST_MATRIX_TYPES: &[DataTypeName] = &[
+    DataTypeName::Boolean,
+    DataTypeName::Int16,
+    DataTypeName::Int32,
+    DataTypeName::Int64,
+    DataTypeName::Decimal,
+    DataTypeName::Float32,
+    DataTypeName::Float64,
+    DataTypeName::Int256,
+    DataTypeName::Date,
+    DataTypeName::Timestamp,
+    DataTypeName::Timestamptz,
+    DataTypeName::Time,
+    DataTypeName::Interval,
+    DataTypeName::Jsonb,
+    DataTypeName::Bytea,
+    DataTypeName::Varchar,
+    DataTypeName::Serial,
+];
+
+/// Renders the full single-hop castability matrix for the given context, for docs generation
+/// and admin tooling. Each row pairs a source type with one boolean per target type, both in
+/// [`CAST_MATRIX_TYPES`] order; the diagonal (same-type "casts") is `false`, consistent with
+/// [`cast_ok`].
+pub fn cast_matrix(allows: CastContext) -> Vec<(DataTypeName, Vec<bool>)> {
+    CAST_MATRIX_TYPES
+        .iter()
+        .map(|source| {
+            let source_ty = DataType::try_from(*source).expect("base type");
+            let row = CAST_MATRIX_TYPES
+                .iter()
+                .map(|target| {
+                    let target_ty = DataType::try_from(*target).expect("base type");
+                    cast_ok(&source_ty, &target_ty, allows)
+                })
+                .collect();
+            (*source, row)
+        })
+        .collect()
+}
+
 pub fn cast_sigs() -> impl Iterator<Item = CastSig> {
     CAST_MAP
         .iter()
@@ -224,31 +693,35 @@ pub static CAST_MAP: LazyLock<CastMap> = LazyLock::new(|| {
     //    1. int2 -> int4 -> int8 -> numeric -> float4 -> float8
     //    2. date -> timestamp -> timestamptz
     //    3. time -> interval
-    // 2. any -> varchar is assign and varchar -> any is explicit
-    // 3. jsonb -> bool/number is explicit
-    // 4. int32 <-> bool is explicit
+    // 2. any -> varchar is assign and varchar -> any is explicit, except varchar -> jsonb
+    //    which is assign so that JSON text columns can be ingested into jsonb columns without
+    //    an explicit cast (the text is still validated as JSON at runtime)
+    // 3. jsonb -> bool/number is explicit; timestamp/timestamptz <-> jsonb is explicit,
+    //    through ISO 8601 JSON strings
+    // 4. int32 -> bool is explicit, bool -> int2/int4/int8 is assign
     // 5. timestamp/timestamptz -> time is assign
-    // 6. int2/int4/int8 -> int256 is implicit and int256 -> float8 is explicit
+    // 6. int2/int4/int8 -> int256 is implicit and int256 -> float8/decimal is explicit
+    // 7. serial is an alias for int8: serial -> int8 is implicit and int8 -> serial is assign
     use DataTypeName::*;
     const CAST_TABLE: &[(&str, DataTypeName)] = &[
         // 123456789ABCDEF
-        (". e            a ", Boolean),     // 0
+        (".aaa           a ", Boolean),     // 0
         (" .iiiiii       a ", Int16),       // 1
         ("ea.iiiii       a ", Int32),       // 2
-        (" aa.iiii       a ", Int64),       // 3
-        (" aaa.ii        a ", Decimal),     // 4
+        (" aa.iiii       aa", Int64),       // 3
+        (" aaa.iie       a ", Decimal),     // 4
         (" aaaa.i        a ", Float32),     // 5
         (" aaaaa.        a ", Float64),     // 6
-        ("      e.       a ", Int256),      // 7
+        ("    e e.       a ", Int256),      // 7
         ("        .ii    a ", Date),        // 8
-        ("        a.ia   a ", Timestamp),   // 9
-        ("        aa.a   a ", Timestamptz), // A
+        ("        a.ia e a ", Timestamp),   // 9
+        ("        aa.a e a ", Timestamptz), // A
         ("           .i  a ", Time),        // B
         ("           a.  a ", Interval),    // C
-        ("eeeeeee      . a ", Jsonb),       // D
+        ("eeeeeee  ee  . a ", Jsonb),       // D
         ("              .a ", Bytea),       // E
-        ("eeeeeeeeeeeeeee. ", Varchar),     // F
-        ("   e            .", Serial),
+        ("eeeeeeeeeeeeeae. ", Varchar),     // F
+        ("   i           a.", Serial),
     ];
     let mut map = BTreeMap::new();
     for (row, source) in CAST_TABLE {
@@ -281,12 +754,14 @@ mod tests {
             T::Decimal,
             T::Float32,
             T::Float64,
+            T::Int256,
             T::Varchar,
             T::Date,
             T::Timestamp,
             T::Timestamptz,
             T::Time,
             T::Interval,
+            T::Serial,
         ];
         all_types
             .iter()
@@ -302,6 +777,313 @@ mod tests {
             .collect_vec()
     }
 
+    #[test]
+    fn test_least_restrictive_composite() {
+        let struct_of = |fields: &[(&str, DataType)]| {
+            DataType::Struct(StructType::new(
+                fields
+                    .iter()
+                    .map(|(n, t)| (n.to_string(), t.clone()))
+                    .collect::<Vec<_>>(),
+            ))
+        };
+        let list_of = |t: DataType| DataType::List(Box::new(t));
+
+        // Field-wise widening, even when neither struct is wholly castable into the other.
+        assert_eq!(
+            least_restrictive(
+                struct_of(&[("a", DataType::Int32), ("b", DataType::Int64)]),
+                struct_of(&[("a", DataType::Int64), ("b", DataType::Int32)]),
+            )
+            .unwrap(),
+            struct_of(&[("a", DataType::Int64), ("b", DataType::Int64)])
+        );
+
+        // Element-wise widening for lists, including nested ones.
+        assert_eq!(
+            least_restrictive(list_of(DataType::Int32), list_of(DataType::Int64)).unwrap(),
+            list_of(DataType::Int64)
+        );
+        assert_eq!(
+            least_restrictive(
+                list_of(struct_of(&[("a", DataType::Int32)])),
+                list_of(struct_of(&[("a", DataType::Float64)])),
+            )
+            .unwrap(),
+            list_of(struct_of(&[("a", DataType::Float64)]))
+        );
+
+        // Incompatible arity and incompatible field types are errors.
+        least_restrictive(
+            struct_of(&[("a", DataType::Int32)]),
+            struct_of(&[("a", DataType::Int32), ("b", DataType::Int32)]),
+        )
+        .unwrap_err();
+        least_restrictive(
+            struct_of(&[("a", DataType::Int32)]),
+            struct_of(&[("a", DataType::Bytea)]),
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_align_types_report() {
+        use risingwave_common::types::ScalarImpl;
+
+        use crate::expr::Literal;
+
+        // A mix of types: the int32 widens to int64, the int64s stay as they are.
+        let mut exprs: Vec<ExprImpl> = vec![
+            Literal::new(Some(ScalarImpl::Int32(1)), DataType::Int32).into(),
+            Literal::new(Some(ScalarImpl::Int64(2)), DataType::Int64).into(),
+            Literal::new(Some(ScalarImpl::Int64(3)), DataType::Int64).into(),
+        ];
+        let (ret_type, casted) = align_types_report(exprs.iter_mut()).unwrap();
+        assert_eq!(ret_type, DataType::Int64);
+        assert_eq!(casted, vec![true, false, false]);
+        // The exprs themselves are aligned in place, same as `align_types`.
+        for e in &exprs {
+            assert_eq!(e.return_type(), DataType::Int64);
+        }
+
+        // All exprs already share the common type: nothing is cast.
+        let mut exprs: Vec<ExprImpl> = vec![
+            Literal::new(Some(ScalarImpl::Int32(1)), DataType::Int32).into(),
+            Literal::new(Some(ScalarImpl::Int32(2)), DataType::Int32).into(),
+        ];
+        let (ret_type, casted) = align_types_report(exprs.iter_mut()).unwrap();
+        assert_eq!(ret_type, DataType::Int32);
+        assert_eq!(casted, vec![false, false]);
+    }
+
+    #[test]
+    fn test_cast_ok_memoized_matches_uncached() {
+        use risingwave_common::types::StructType;
+
+        // struct<f T', xs T'[]> nested `depth` levels deep over the given leaf type.
+        fn nested(leaf: DataType, depth: usize) -> DataType {
+            let mut ty = leaf;
+            for _ in 0..depth {
+                ty = DataType::Struct(StructType::new([
+                    ("f", ty.clone()),
+                    ("xs", DataType::List(Box::new(ty))),
+                ]));
+            }
+            ty
+        }
+
+        let pairs = [
+            (nested(DataType::Int16, 8), nested(DataType::Int64, 8)), // ok (implicit per field)
+            (nested(DataType::Int64, 8), nested(DataType::Int16, 8)), // ok (assign per field)
+            (nested(DataType::Int32, 8), nested(DataType::Bytea, 8)), // not castable
+            (nested(DataType::Int32, 8), nested(DataType::Int32, 4)), // shape mismatch
+            (nested(DataType::Varchar, 6), DataType::Varchar),        // struct -> text
+        ];
+        for (source, target) in &pairs {
+            for allows in [
+                CastContext::Implicit,
+                CastContext::Assign,
+                CastContext::Explicit,
+            ] {
+                let expected = cast_ok_uncached(source, target, allows);
+                // The first call populates the cache, the second is served from it.
+                assert_eq!(cast_ok(source, target, allows), expected);
+                assert_eq!(cast_ok(source, target, allows), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_strict_struct_cast() {
+        use risingwave_common::types::StructType;
+
+        let fields = vec![("a", DataType::Int32), ("b", DataType::Varchar)];
+        let point = DataType::Struct(StructType::new_with_identity("point", fields.clone()));
+        let pair = DataType::Struct(StructType::new_with_identity("pair", fields.clone()));
+        let anonymous = DataType::Struct(StructType::new(fields));
+
+        // Structural casting is unaffected when strict mode is off.
+        for allows in [CastContext::Implicit, CastContext::Assign] {
+            assert!(cast_ok_struct_inner(&point, &pair, allows, false));
+            assert!(cast_ok_struct_inner(&point, &anonymous, allows, false));
+        }
+
+        // Strict mode rejects non-explicit casts between differently-named structs, even if
+        // they are structurally equal.
+        for allows in [CastContext::Implicit, CastContext::Assign] {
+            assert!(!cast_ok_struct_inner(&point, &pair, allows, true));
+            assert!(!cast_ok_struct_inner(&point, &anonymous, allows, true));
+            assert!(!cast_ok_struct_inner(&anonymous, &pair, allows, true));
+        }
+        // ... but the same named type and explicit casts are still fine.
+        assert!(cast_ok_struct_inner(
+            &point,
+            &point,
+            CastContext::Implicit,
+            true
+        ));
+        assert!(cast_ok_struct_inner(
+            &point,
+            &pair,
+            CastContext::Explicit,
+            true
+        ));
+    }
+
+    #[test]
+    fn test_cast_ok_map_jsonb() {
+        use risingwave_common::types::MapType;
+
+        let map = |k: DataType, v: DataType| DataType::Map(MapType::from_kv(k, v));
+
+        // Map -> Jsonb is explicit-only, regardless of the value type.
+        let m = map(DataType::Varchar, DataType::Int32);
+        assert!(cast_ok(&m, &DataType::Jsonb, CastContext::Explicit));
+        assert!(!cast_ok(&m, &DataType::Jsonb, CastContext::Assign));
+        assert!(!cast_ok(&m, &DataType::Jsonb, CastContext::Implicit));
+
+        // Jsonb -> Map requires the key and value types to be coercible.
+        assert!(cast_ok(&DataType::Jsonb, &m, CastContext::Explicit));
+        assert!(!cast_ok(&DataType::Jsonb, &m, CastContext::Assign));
+        // JSON object keys are strings, and varchar is explicitly castable to int.
+        let m = map(DataType::Int32, DataType::Float64);
+        assert!(cast_ok(&DataType::Jsonb, &m, CastContext::Explicit));
+        // jsonb values stay as-is.
+        let m = map(DataType::Varchar, DataType::Jsonb);
+        assert!(cast_ok(&DataType::Jsonb, &m, CastContext::Explicit));
+        // There is no jsonb -> interval cast, so the value type is not reachable.
+        let m = map(DataType::Varchar, DataType::Interval);
+        assert!(!cast_ok(&DataType::Jsonb, &m, CastContext::Explicit));
+    }
+
+    #[test]
+    fn test_cast_reason() {
+        let list = |elem: DataType| DataType::List(Box::new(elem));
+        let struct_of = |fields: &[(&str, DataType)]| {
+            DataType::Struct(StructType::new(
+                fields
+                    .iter()
+                    .map(|(n, t)| (n.to_string(), t.clone()))
+                    .collect::<Vec<_>>(),
+            ))
+        };
+
+        // Allowed casts report no failure.
+        cast_reason(&DataType::Int32, &DataType::Int64, CastContext::Implicit).unwrap();
+        cast_reason(
+            &list(DataType::Int32),
+            &list(DataType::Int64),
+            CastContext::Implicit,
+        )
+        .unwrap();
+
+        // A failing list element is pinpointed, including whether a stronger context would
+        // have allowed it.
+        let err = cast_reason(
+            &list(DataType::Int32),
+            &list(DataType::Boolean),
+            CastContext::Implicit,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "element: cast from integer to boolean is only allowed in explicit contexts"
+        );
+
+        // A failing struct field is pinpointed by name, nested paths included.
+        let err = cast_reason(
+            &struct_of(&[("a", DataType::Int32), ("b", DataType::Bytea)]),
+            &struct_of(&[("a", DataType::Int64), ("b", DataType::Boolean)]),
+            CastContext::Implicit,
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "field \"b\": cannot cast bytea to boolean");
+        let err = cast_reason(
+            &struct_of(&[("a", list(DataType::Int32))]),
+            &struct_of(&[("a", list(DataType::Timestamp))]),
+            CastContext::Explicit,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "field \"a\", element: cannot cast integer to timestamp without time zone"
+        );
+
+        // Arity mismatches are reported as such, not as a field failure.
+        let err = cast_reason(
+            &struct_of(&[("a", DataType::Int32)]),
+            &struct_of(&[("a", DataType::Int32), ("b", DataType::Int32)]),
+            CastContext::Explicit,
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "cannot cast between structs of 1 and 2 fields");
+    }
+
+    #[test]
+    fn test_align_decimals_no_truncation() {
+        use std::str::FromStr;
+
+        use risingwave_common::types::Decimal;
+
+        // Aligning two differently-scaled numerics (e.g. the two sides of a `UNION`) keeps the
+        // values as-is: both are plain `Decimal`, so no cast is inserted and neither value is
+        // truncated to the other's scale.
+        let literal = |s: &str| {
+            ExprImpl::from(Literal::new(
+                Some(Decimal::from_str(s).unwrap().into()),
+                DataType::Decimal,
+            ))
+        };
+        let mut exprs = [literal("1.25"), literal("2.1234")];
+        assert_eq!(align_types(exprs.iter_mut()).unwrap(), DataType::Decimal);
+        for (expr, expected) in exprs.iter().zip_eq_fast(["1.25", "2.1234"]) {
+            let data = expr.as_literal().unwrap().get_data().clone().unwrap();
+            assert_eq!(data.as_decimal().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn test_cast_sig_oids() {
+        // Spot-check a few well-known PG type OIDs.
+        assert_eq!(data_type_name_to_oid(DataTypeName::Boolean), 16);
+        assert_eq!(data_type_name_to_oid(DataTypeName::Int32), 23);
+        assert_eq!(data_type_name_to_oid(DataTypeName::Varchar), 1043);
+
+        // Every entry in `CAST_MAP` maps to a valid OID.
+        for sig in cast_sigs() {
+            let (castsource, casttarget) = sig.to_oids();
+            assert!(castsource > 0, "invalid source oid for {sig:?}");
+            assert!(casttarget > 0, "invalid target oid for {sig:?}");
+        }
+    }
+
+    #[test]
+    fn test_cast_ok_array_nesting() {
+        let list = |elem: DataType| DataType::List(Box::new(elem));
+        let int_1d = list(DataType::Int32);
+        let int_2d = list(int_1d.clone());
+        let int_3d = list(int_2d.clone());
+        let bigint_1d = list(DataType::Int64);
+
+        // Flatten and nest are explicit-only.
+        assert!(cast_ok(&int_2d, &int_1d, CastContext::Explicit));
+        assert!(cast_ok(&int_1d, &int_2d, CastContext::Explicit));
+        assert!(!cast_ok(&int_2d, &int_1d, CastContext::Assign));
+        assert!(!cast_ok(&int_1d, &int_2d, CastContext::Implicit));
+
+        // The element type may be casted along the way.
+        assert!(cast_ok(&int_2d, &bigint_1d, CastContext::Explicit));
+        assert!(cast_ok(&int_1d, &list(bigint_1d.clone()), CastContext::Explicit));
+
+        // Only one nesting level changes per cast.
+        assert!(!cast_ok(&int_3d, &int_1d, CastContext::Explicit));
+        assert!(!cast_ok(&int_1d, &int_3d, CastContext::Explicit));
+
+        // Casts involving varchar elements keep their to-text / parse semantics and are still
+        // element-wise, not dimensionality-changing.
+        assert!(cast_ok(&int_2d, &list(DataType::Varchar), CastContext::Assign));
+    }
+
     #[test]
     fn test_cast_ok() {
         // With the help of a script we can obtain the 3 expected cast tables from PG. They are
@@ -312,58 +1094,296 @@ mod tests {
         assert_eq!(
             actual,
             vec![
-                "             ", // bool
-                "  TTTTT      ",
-                "   TTTT      ",
-                "    TTT      ",
-                "     TT      ",
-                "      T      ",
-                "             ",
-                "             ", // varchar
-                "         TT  ",
-                "          T  ",
-                "             ",
-                "            T",
-                "             ",
+                "               ", // bool
+                "  TTTTTT       ",
+                "   TTTTT       ",
+                "    TTTT       ",
+                "     TT        ",
+                "      T        ",
+                "               ",
+                "               ", // int256
+                "               ", // varchar
+                "          TT   ",
+                "           T   ",
+                "               ",
+                "             T ",
+                "               ",
+                "   T           ", // serial
             ]
         );
         let actual = gen_cast_table(CastContext::Assign);
         assert_eq!(
             actual,
             vec![
-                "       T     ", // bool
-                "  TTTTTT     ",
-                " T TTTTT     ",
-                " TT TTTT     ",
-                " TTT TTT     ",
-                " TTTT TT     ",
-                " TTTTT T     ",
-                "             ", // varchar
-                "       T TT  ",
-                "       TT TT ",
-                "       TTT T ",
-                "       T    T",
-                "       T   T ",
+                " TTT    T      ", // bool
+                "  TTTTTTT      ",
+                " T TTTTTT      ",
+                " TT TTTTT     T",
+                " TTT TT T      ",
+                " TTTT T T      ",
+                " TTTTT  T      ",
+                "        T      ", // int256
+                "               ", // varchar
+                "        T TT   ",
+                "        TT TT  ",
+                "        TTT T  ",
+                "        T    T ",
+                "        T   T  ",
+                "   T    T      ", // serial
             ]
         );
         let actual = gen_cast_table(CastContext::Explicit);
         assert_eq!(
             actual,
             vec![
-                "  T    T     ", // bool
-                "  TTTTTT     ",
-                "TT TTTTT     ",
-                " TT TTTT     ",
-                " TTT TTT     ",
-                " TTTT TT     ",
-                " TTTTT T     ",
-                "TTTTTTT TTTTT", // varchar
-                "       T TT  ",
-                "       TT TT ",
-                "       TTT T ",
-                "       T    T",
-                "       T   T ",
+                " TTT    T      ", // bool
+                "  TTTTTTT      ",
+                "TT TTTTTT      ",
+                " TT TTTTT     T",
+                " TTT TTTT      ",
+                " TTTT T T      ",
+                " TTTTT  T      ",
+                "    T T T      ", // int256
+                "TTTTTTTT TTTTT ", // varchar
+                "        T TT   ",
+                "        TT TT  ",
+                "        TTT T  ",
+                "        T    T ",
+                "        T   T  ",
+                "   T    T      ", // serial
             ]
         );
     }
+
+    #[test]
+    fn test_cast_matrix_matches_gen_cast_table() {
+        use DataTypeName as N;
+
+        // The type order `gen_cast_table` renders (a subset of `CAST_MATRIX_TYPES` in a
+        // different order), so the snapshots in `test_cast_ok` double as expectations here.
+        let order = [
+            N::Boolean,
+            N::Int16,
+            N::Int32,
+            N::Int64,
+            N::Decimal,
+            N::Float32,
+            N::Float64,
+            N::Int256,
+            N::Varchar,
+            N::Date,
+            N::Timestamp,
+            N::Timestamptz,
+            N::Time,
+            N::Interval,
+            N::Serial,
+        ];
+        let idx = |t: N| {
+            CAST_MATRIX_TYPES
+                .iter()
+                .position(|x| *x == t)
+                .expect("type missing from CAST_MATRIX_TYPES")
+        };
+
+        for allows in [
+            CastContext::Implicit,
+            CastContext::Assign,
+            CastContext::Explicit,
+        ] {
+            let matrix = cast_matrix(allows);
+            // Rows come in the documented stable order.
+            assert_eq!(
+                matrix.iter().map(|(t, _)| *t).collect::<Vec<_>>(),
+                CAST_MATRIX_TYPES
+            );
+            let derived: Vec<String> = order
+                .iter()
+                .map(|source| {
+                    let row = &matrix[idx(*source)].1;
+                    order
+                        .iter()
+                        .map(|target| if row[idx(*target)] { 'T' } else { ' ' })
+                        .collect()
+                })
+                .collect();
+            assert_eq!(derived, gen_cast_table(allows));
+        }
+    }
+
+    #[test]
+    fn test_date_to_timestamptz_direct_edge() {
+        // `date -> timestamptz` has a direct implicit edge in `CAST_MAP`, so single-hop checks
+        // like `cast_ok_base` succeed without chaining through `timestamp`. The timezone
+        // interpretation is also consistent with `date -> timestamp -> timestamptz`: the
+        // session timezone rewrite in `SessionTimezone` lowers this cast to a cast to
+        // `timestamp` followed by `AT TIME ZONE`.
+        assert!(cast_ok_base(
+            &DataType::Date,
+            &DataType::Timestamptz,
+            CastContext::Implicit
+        ));
+        // The direct edge is no stronger than the two hops it shortcuts.
+        assert!(cast_ok_base(
+            &DataType::Date,
+            &DataType::Timestamp,
+            CastContext::Implicit
+        ));
+        assert!(cast_ok_base(
+            &DataType::Timestamp,
+            &DataType::Timestamptz,
+            CastContext::Implicit
+        ));
+    }
+
+    #[test]
+    fn test_varchar_to_jsonb_assign() {
+        // JSON text columns can be inserted into jsonb columns without an explicit cast; the
+        // text is still validated as JSON at runtime. The cast stays out of implicit contexts,
+        // where it would make e.g. comparing a varchar to a jsonb silently parse the string.
+        assert!(cast_ok_base(
+            &DataType::Varchar,
+            &DataType::Jsonb,
+            CastContext::Assign
+        ));
+        assert!(!cast_ok_base(
+            &DataType::Varchar,
+            &DataType::Jsonb,
+            CastContext::Implicit
+        ));
+        // Other from-varchar casts remain explicit-only.
+        assert!(!cast_ok_base(
+            &DataType::Varchar,
+            &DataType::Int32,
+            CastContext::Assign
+        ));
+    }
+
+    #[test]
+    fn test_register_extension_cast() {
+        // The synthetic edge uses bytea, which `gen_cast_table` does not render, so the global
+        // registration cannot disturb the snapshot tests running in the same process.
+        assert!(!cast_ok_base(
+            &DataType::Bytea,
+            &DataType::Int256,
+            CastContext::Explicit
+        ));
+        register_extension_cast(DataTypeName::Bytea, DataTypeName::Int256, CastContext::Explicit);
+        // The edge is honored, both directly and through `cast_ok`, with its context bound.
+        assert!(cast_ok_base(
+            &DataType::Bytea,
+            &DataType::Int256,
+            CastContext::Explicit
+        ));
+        assert!(cast_ok(
+            &DataType::Bytea,
+            &DataType::Int256,
+            CastContext::Explicit
+        ));
+        assert!(!cast_ok_base(
+            &DataType::Bytea,
+            &DataType::Int256,
+            CastContext::Assign
+        ));
+
+        // The static map is consulted first, so a built-in edge cannot be loosened: int4 ->
+        // varchar stays assign even after registering it as implicit.
+        register_extension_cast(DataTypeName::Int32, DataTypeName::Varchar, CastContext::Implicit);
+        assert!(!cast_ok_base(
+            &DataType::Int32,
+            &DataType::Varchar,
+            CastContext::Implicit
+        ));
+        assert!(cast_ok_base(
+            &DataType::Int32,
+            &DataType::Varchar,
+            CastContext::Assign
+        ));
+    }
+
+    #[test]
+    fn test_implicit_cast_closure() {
+        use DataTypeName::*;
+
+        assert_eq!(
+            implicit_cast_closure(Int16),
+            HashSet::from([Int16, Int32, Int64, Decimal, Float32, Float64, Int256])
+        );
+        // `Float64` has no outgoing implicit casts, so the closure is just itself.
+        assert_eq!(implicit_cast_closure(Float64), HashSet::from([Float64]));
+
+        // A cycle in the map must not make the BFS loop forever.
+        let cyclic = CastMap::from([
+            ((Int16, Int32), CastContext::Implicit),
+            ((Int32, Int16), CastContext::Implicit),
+        ]);
+        assert_eq!(
+            implicit_cast_closure_in(&cyclic, Int16),
+            HashSet::from([Int16, Int32])
+        );
+    }
+
+    #[test]
+    fn test_cast_map_symmetry() {
+        for ((source, target), context) in CAST_MAP.iter() {
+            if *context != CastContext::Implicit {
+                continue;
+            }
+            // Every implicit cast is also allowed in assign and explicit contexts.
+            let source_ty = DataType::try_from(*source).unwrap();
+            let target_ty = DataType::try_from(*target).unwrap();
+            assert!(cast_ok_base(&source_ty, &target_ty, CastContext::Assign));
+            assert!(cast_ok_base(&source_ty, &target_ty, CastContext::Explicit));
+
+            // Implicit casts have a reverse-direction cast of at least assign strength, per the
+            // rules documented on `CAST_MAP`. `Int256` is the documented exception (rule 6): it
+            // can be cast into implicitly but only escapes explicitly, towards `float8`.
+            if *target == DataTypeName::Int256 {
+                continue;
+            }
+            assert!(
+                matches!(CAST_MAP.get(&(*target, *source)), Some(c) if *c <= CastContext::Assign),
+                "implicit cast {source:?} -> {target:?} lacks an assign-or-stronger reverse"
+            );
+        }
+    }
+
+    #[test]
+    fn test_align_types_fast_path_matches_general() {
+        let untyped = || ExprImpl::from(Literal::new_untyped(Some("1".into())));
+        let shapes: Vec<Vec<ExprImpl>> = vec![
+            vec![],
+            vec![ExprImpl::literal_int(1)],
+            vec![untyped()],
+            vec![ExprImpl::literal_int(1), ExprImpl::literal_bigint(2)],
+            vec![ExprImpl::literal_bigint(2), ExprImpl::literal_int(1)],
+            vec![untyped(), ExprImpl::literal_int(1)],
+            vec![ExprImpl::literal_int(1), untyped()],
+            vec![untyped(), untyped()],
+            vec![ExprImpl::literal_int(1), ExprImpl::literal_f64(1.0)],
+            // not implicitly castable in either direction
+            vec![
+                ExprImpl::literal_int(1),
+                ExprImpl::literal_varchar("a".into()),
+            ],
+            vec![
+                ExprImpl::literal_int(1),
+                ExprImpl::literal_bigint(2),
+                untyped(),
+            ],
+        ];
+        for exprs in shapes {
+            let mut fast = exprs.clone();
+            let mut general = exprs.clone();
+            let fast_res = align_types_with_fallback(fast.iter_mut());
+            let general_res = align_types_general(general.iter_mut());
+            match (fast_res, general_res) {
+                (Ok(f), Ok(g)) => {
+                    assert_eq!(f, g, "return type mismatch for {exprs:?}");
+                    assert_eq!(fast, general, "casted exprs mismatch for {exprs:?}");
+                }
+                (Err(_), Err(_)) => {}
+                (f, g) => panic!("result mismatch for {exprs:?}: {f:?} vs {g:?}"),
+            }
+        }
+    }
 }
@@ -722,6 +722,31 @@ fn infer_type_for_special_table_function(
                 _ => Ok(None),
             }
         }
+        PbTableFuncType::Unnest => {
+            if inputs.len() <= 1 {
+                // let signature map handle this
+                return Ok(None);
+            }
+            // `unnest` over multiple arrays zips them in parallel, padding the shorter ones
+            // with NULLs, and returns a struct with one `unnest` field per array. The element
+            // types are independent, so the polymorphic `anyarray` signatures cannot unify
+            // them; compute the struct return type here instead.
+            let fields = inputs
+                .iter()
+                .map(|input| {
+                    let return_type = input.return_type();
+                    if matches!(return_type, DataType::List(_)) {
+                        Ok(("unnest", return_type.unnest_list().clone()))
+                    } else {
+                        Err(ErrorCode::BindError(format!(
+                            "could not determine polymorphic type because input has type {}",
+                            return_type
+                        )))
+                    }
+                })
+                .try_collect()?;
+            Ok(Some(DataType::Struct(StructType::new(fields))))
+        }
         _ => Ok(None),
     }
 }
@@ -1343,4 +1368,52 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_multi_array_unnest() {
+        fn array_input(elem: DataType) -> ExprImpl {
+            crate::expr::Literal::new(None, DataType::List(Box::new(elem))).into()
+        }
+
+        // Arrays of independent element types zip into a struct with one `unnest` field each.
+        let mut inputs = vec![array_input(DataType::Int32), array_input(DataType::Varchar)];
+        let ret = infer_type(PbTableFuncType::Unnest.into(), &mut inputs).unwrap();
+        assert_eq!(
+            ret,
+            DataType::Struct(StructType::new(vec![
+                ("unnest", DataType::Int32),
+                ("unnest", DataType::Varchar),
+            ]))
+        );
+
+        // A single array keeps the plain element return type from the signature map.
+        let mut inputs = vec![array_input(DataType::Int32)];
+        let ret = infer_type(PbTableFuncType::Unnest.into(), &mut inputs).unwrap();
+        assert_eq!(ret, DataType::Int32);
+
+        // The arity is unbounded, like in PG.
+        let mut inputs = vec![
+            array_input(DataType::Int32),
+            array_input(DataType::Varchar),
+            array_input(DataType::Int64),
+            array_input(DataType::Boolean),
+        ];
+        let ret = infer_type(PbTableFuncType::Unnest.into(), &mut inputs).unwrap();
+        assert_eq!(
+            ret,
+            DataType::Struct(StructType::new(vec![
+                ("unnest", DataType::Int32),
+                ("unnest", DataType::Varchar),
+                ("unnest", DataType::Int64),
+                ("unnest", DataType::Boolean),
+            ]))
+        );
+
+        // Every argument must be an array.
+        let mut inputs = vec![
+            array_input(DataType::Int32),
+            crate::expr::Literal::new(None, DataType::Int32).into(),
+        ];
+        assert!(infer_type(PbTableFuncType::Unnest.into(), &mut inputs).is_err());
+    }
 }
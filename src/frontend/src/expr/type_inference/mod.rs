@@ -18,6 +18,7 @@
 mod cast;
 mod func;
 pub use cast::{
-    align_types, cast_map_array, cast_ok, cast_ok_base, cast_sigs, CastContext, CastSig,
+    align_types, align_types_report, align_types_with_fallback, cast_map_array, cast_ok,
+    cast_ok_base, cast_sigs, data_type_name_to_oid, implicit_cast_closure, CastContext, CastSig,
 };
 pub use func::{infer_some_all, infer_type, infer_type_name, infer_type_with_sigmap, FuncSign};
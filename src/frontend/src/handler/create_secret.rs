@@ -16,10 +16,12 @@ use pgwire::pg_response::{PgResponse, StatementType};
 use prost::Message;
 use risingwave_common::bail_not_implemented;
 use risingwave_common::license::Feature;
+use risingwave_common::system_param::reader::SystemParamsRead;
 use risingwave_sqlparser::ast::{CreateSecretStatement, SqlOption, Value};
 
 use crate::error::{ErrorCode, Result};
 use crate::handler::{HandlerArgs, RwPgResponse};
+use crate::session::SessionImpl;
 use crate::{Binder, WithOptions};
 
 const SECRET_BACKEND_KEY: &str = "backend";
@@ -27,6 +29,10 @@ const SECRET_BACKEND_KEY: &str = "backend";
 const SECRET_BACKEND_META: &str = "meta";
 const SECRET_BACKEND_HASHICORP_VAULT: &str = "hashicorp_vault";
 
+const SECRET_ENCODING_KEY: &str = "encoding";
+
+const SECRET_ENCODING_BASE64: &str = "base64";
+
 pub async fn handle_create_secret(
     handler_args: HandlerArgs,
     stmt: CreateSecretStatement,
@@ -42,18 +48,41 @@ pub async fn handle_create_secret(
 
     if let Err(e) = session.check_secret_name_duplicated(stmt.secret_name.clone()) {
         return if stmt.if_not_exists {
+            // Surface the existing secret's backend when it differs from the requested one,
+            // so that a silent backend mismatch does not confuse debugging later.
+            let notice = match mismatched_backend(&session, schema_name, &connection_name, &stmt) {
+                Some(backend) => format!(
+                    "secret \"{}\" exists (backend: {}), skipping",
+                    connection_name, backend
+                ),
+                None => format!("secret \"{}\" exists, skipping", connection_name),
+            };
             Ok(PgResponse::builder(StatementType::CREATE_SECRET)
-                .notice(format!("secret \"{}\" exists, skipping", connection_name))
+                .notice(notice)
                 .into())
         } else {
             Err(e)
         };
     }
 
-    let secret = secret_to_str(&stmt.credential)?.as_bytes().to_vec();
-
-    // check if the secret backend is supported
     let with_props = WithOptions::try_from(stmt.with_properties.0.as_ref() as &[SqlOption])?;
+
+    let mut secret = secret_to_str(&stmt.credential)?.as_bytes().to_vec();
+
+    // binary secrets can be passed as a base64-encoded string with `encoding = 'base64'`
+    if let Some(encoding) = with_props.get(SECRET_ENCODING_KEY) {
+        secret = apply_secret_encoding(secret, encoding)?;
+    }
+
+    // check the size of the secret against the system parameter, so that an accidentally
+    // pasted huge payload does not bloat the meta store
+    let max_secret_size_bytes = session
+        .env()
+        .system_params_manager()
+        .get_params()
+        .load()
+        .max_secret_size_bytes();
+    check_secret_size(secret.len() as u64, max_secret_size_bytes)?;
     let secret_payload: Vec<u8> = {
         if let Some(backend) = with_props.get(SECRET_BACKEND_KEY) {
             match backend.to_lowercase().as_ref() {
@@ -108,6 +137,31 @@ pub async fn handle_create_secret(
     Ok(PgResponse::empty_result(StatementType::CREATE_SECRET))
 }
 
+/// The existing secret's backend name, if it differs from the one requested in the statement.
+/// Best effort: any lookup or parse failure falls back to the plain notice.
+fn mismatched_backend(
+    session: &SessionImpl,
+    schema_name: Option<String>,
+    secret_name: &str,
+    stmt: &CreateSecretStatement,
+) -> Option<&'static str> {
+    let with_props = WithOptions::try_from(stmt.with_properties.0.as_ref() as &[SqlOption]).ok()?;
+    let requested = with_props.get(SECRET_BACKEND_KEY)?.to_lowercase();
+    let existing = session.get_secret_by_name(schema_name, secret_name).ok()?;
+    let existing = secret_backend_name(&existing.value)?;
+    (existing != requested).then_some(existing)
+}
+
+/// Decodes the backend name out of a stored secret payload.
+fn secret_backend_name(value: &[u8]) -> Option<&'static str> {
+    use risingwave_pb::secret::secret::SecretBackend;
+    let secret = risingwave_pb::secret::Secret::decode(value).ok()?;
+    match secret.secret_backend? {
+        SecretBackend::Meta(_) => Some(SECRET_BACKEND_META),
+        SecretBackend::HashicorpVault(_) => Some(SECRET_BACKEND_HASHICORP_VAULT),
+    }
+}
+
 fn secret_to_str(value: &Value) -> Result<String> {
     match value {
         Value::DoubleQuotedString(s) | Value::SingleQuotedString(s) => Ok(s.to_string()),
@@ -117,3 +171,93 @@ fn secret_to_str(value: &Value) -> Result<String> {
         .into()),
     }
 }
+
+fn apply_secret_encoding(secret: Vec<u8>, encoding: &str) -> Result<Vec<u8>> {
+    match encoding.to_lowercase().as_ref() {
+        SECRET_ENCODING_BASE64 => {
+            use base64::prelude::{Engine, BASE64_STANDARD};
+            BASE64_STANDARD.decode(&secret).map_err(|e| {
+                ErrorCode::InvalidParameterValue(format!(
+                    "fail to decode base64 secret value: {}",
+                    e
+                ))
+                .into()
+            })
+        }
+        _ => Err(ErrorCode::InvalidParameterValue(format!(
+            "secret encoding \"{}\" is not supported. Supported encodings are: {}",
+            encoding, SECRET_ENCODING_BASE64
+        ))
+        .into()),
+    }
+}
+
+fn check_secret_size(size_bytes: u64, max_size_bytes: u64) -> Result<()> {
+    if size_bytes > max_size_bytes {
+        return Err(ErrorCode::InvalidParameterValue(format!(
+            "secret size ({} bytes) exceeds the maximum allowed size ({} bytes), \
+             adjust the `max_secret_size_bytes` system parameter to store larger secrets",
+            size_bytes, max_size_bytes
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::LocalFrontend;
+
+    #[tokio::test]
+    async fn test_create_secret_if_not_exists_notice() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("CREATE SECRET sec WITH (backend = 'meta') AS 'demo_secret'")
+            .await
+            .unwrap();
+
+        // Same backend: the plain notice.
+        let response = frontend
+            .run_sql("CREATE SECRET IF NOT EXISTS sec WITH (backend = 'meta') AS 'demo_secret'")
+            .await
+            .unwrap();
+        assert_eq!(response.notices(), ["secret \"sec\" exists, skipping"]);
+
+        // Different backend: the notice names the existing one.
+        let response = frontend
+            .run_sql(
+                "CREATE SECRET IF NOT EXISTS sec WITH (backend = 'hashicorp_vault') AS 'demo_secret'",
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            response.notices(),
+            ["secret \"sec\" exists (backend: meta), skipping"]
+        );
+
+        // Without `IF NOT EXISTS` the duplicate is still an error.
+        frontend
+            .run_sql("CREATE SECRET sec WITH (backend = 'meta') AS 'demo_secret'")
+            .await
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_apply_secret_encoding() {
+        // `0x00 0xff` is not a valid UTF-8 string, so it can only be stored via base64.
+        let decoded = apply_secret_encoding(b"AP8=".to_vec(), "base64").unwrap();
+        assert_eq!(decoded, vec![0x00, 0xff]);
+
+        assert!(apply_secret_encoding(b"not base64!".to_vec(), "base64").is_err());
+        assert!(apply_secret_encoding(b"AP8=".to_vec(), "hex").is_err());
+    }
+
+    #[test]
+    fn test_check_secret_size() {
+        let limit = 1024;
+        assert!(check_secret_size(limit - 1, limit).is_ok());
+        assert!(check_secret_size(limit, limit).is_ok());
+        assert!(check_secret_size(limit + 1, limit).is_err());
+    }
+}
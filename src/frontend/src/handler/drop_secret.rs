@@ -16,8 +16,9 @@ use pgwire::pg_response::StatementType;
 use risingwave_common::license::Feature;
 use risingwave_sqlparser::ast::ObjectName;
 
-use crate::catalog::root_catalog::SchemaPath;
-use crate::error::Result;
+use crate::catalog::root_catalog::{Catalog, SchemaPath};
+use crate::catalog::SecretId;
+use crate::error::{ErrorCode, Result};
 use crate::handler::{HandlerArgs, RwPgResponse};
 use crate::Binder;
 
@@ -25,6 +26,7 @@ pub async fn handle_drop_secret(
     handler_args: HandlerArgs,
     secret_name: ObjectName,
     if_exists: bool,
+    cascade: bool,
 ) -> Result<RwPgResponse> {
     Feature::SecretManagement
         .check_available()
@@ -57,6 +59,14 @@ pub async fn handle_drop_secret(
             };
         session.check_privilege_for_drop_alter(schema_name, &**secret)?;
 
+        // Refuse to drop a secret that is still referenced by other objects, unless `CASCADE`
+        // is specified. The authoritative check lives in the meta service; this one exists to
+        // report the dependents by name.
+        if !cascade {
+            let dependents = dependent_relation_names(&reader, db_name, secret.id);
+            check_secret_dependents(secret_name.as_str(), dependents)?;
+        }
+
         secret.id
     };
 
@@ -65,3 +75,58 @@ pub async fn handle_drop_secret(
 
     Ok(RwPgResponse::empty_result(StatementType::DROP_SECRET))
 }
+
+/// Collect the names of sources and sinks in the database that reference the secret.
+fn dependent_relation_names(catalog: &Catalog, db_name: &str, secret_id: SecretId) -> Vec<String> {
+    let mut dependents = vec![];
+    let Ok(database) = catalog.get_database_by_name(db_name) else {
+        return dependents;
+    };
+    for schema in database.iter_schemas() {
+        for source in schema.iter_source() {
+            let (_, secret_refs) = source.with_properties.clone().into_parts();
+            if secret_refs
+                .values()
+                .any(|secret_ref| secret_ref.secret_id == secret_id.secret_id())
+            {
+                dependents.push(source.name.clone());
+            }
+        }
+        for sink in schema.iter_sink() {
+            if sink
+                .secret_refs
+                .values()
+                .any(|secret_ref| secret_ref.secret_id == secret_id.secret_id())
+            {
+                dependents.push(sink.name.clone());
+            }
+        }
+    }
+    dependents
+}
+
+fn check_secret_dependents(secret_name: &str, dependents: Vec<String>) -> Result<()> {
+    if !dependents.is_empty() {
+        return Err(ErrorCode::PermissionDenied(format!(
+            "cannot drop secret \"{}\" because other objects depend on it: {}. \
+             Use DROP SECRET ... CASCADE to drop it anyway.",
+            secret_name,
+            dependents.join(", ")
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_secret_dependents() {
+        assert!(check_secret_dependents("my_secret", vec![]).is_ok());
+
+        let err = check_secret_dependents("my_secret", vec!["my_source".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("my_source"));
+    }
+}
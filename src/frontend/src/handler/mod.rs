@@ -442,14 +442,14 @@ pub async fn handle(
                     | ObjectType::Source
                     | ObjectType::Subscription
                     | ObjectType::Index
-                    | ObjectType::Table => {
+                    | ObjectType::Table
+                    | ObjectType::Secret => {
                         cascade = true;
                     }
                     ObjectType::Schema
                     | ObjectType::Database
                     | ObjectType::User
-                    | ObjectType::Connection
-                    | ObjectType::Secret => {
+                    | ObjectType::Connection => {
                         bail_not_implemented!("DROP CASCADE");
                     }
                 };
@@ -517,7 +517,8 @@ pub async fn handle(
                         .await
                 }
                 ObjectType::Secret => {
-                    drop_secret::handle_drop_secret(handler_args, object_name, if_exists).await
+                    drop_secret::handle_drop_secret(handler_args, object_name, if_exists, cascade)
+                        .await
                 }
             }
         }
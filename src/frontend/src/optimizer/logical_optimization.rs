@@ -130,8 +130,9 @@ static TABLE_FUNCTION_CONVERT: LazyLock<OptimizationStage> = LazyLock::new(|| {
     OptimizationStage::new(
         "Table Function Convert",
         vec![
-            // Apply file scan rule first
+            // Apply file scan rules first
             TableFunctionToFileScanRule::create(),
+            TableFunctionToFileScanMetadataRule::create(),
             TableFunctionToProjectSetRule::create(),
         ],
         ApplyOrder::TopDown,
@@ -141,7 +142,10 @@ static TABLE_FUNCTION_CONVERT: LazyLock<OptimizationStage> = LazyLock::new(|| {
 static TABLE_FUNCTION_TO_FILE_SCAN: LazyLock<OptimizationStage> = LazyLock::new(|| {
     OptimizationStage::new(
         "Table Function To FileScan",
-        vec![TableFunctionToFileScanRule::create()],
+        vec![
+            TableFunctionToFileScanRule::create(),
+            TableFunctionToFileScanMetadataRule::create(),
+        ],
         ApplyOrder::TopDown,
     )
 });
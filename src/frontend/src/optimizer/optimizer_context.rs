@@ -168,6 +168,10 @@ impl OptimizerContext {
         self.explain_options.verbose
     }
 
+    pub fn is_explain_storage_verbose(&self) -> bool {
+        self.explain_options.verbose_storage
+    }
+
     pub fn is_explain_trace(&self) -> bool {
         self.explain_options.trace
     }
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use pretty_xmlish::XmlNode;
+use pretty_xmlish::{Pretty, XmlNode};
 use risingwave_pb::batch_plan::file_scan_node::{FileFormat, StorageType};
 use risingwave_pb::batch_plan::plan_node::NodeBody;
 use risingwave_pb::batch_plan::FileScanNode;
@@ -58,7 +58,13 @@ impl_plan_tree_node_for_leaf! { BatchFileScan }
 
 impl Distill for BatchFileScan {
     fn distill<'a>(&self) -> XmlNode<'a> {
-        let fields = vec![("columns", column_names_pretty(self.schema()))];
+        let mut fields = vec![("columns", column_names_pretty(self.schema()))];
+        if !self.core.ordered {
+            fields.push(("ordered", Pretty::debug(&false)));
+        }
+        if let Some(n) = self.core.sample_rows {
+            fields.push(("sample_rows", Pretty::debug(&n)));
+        }
         childless_record("BatchFileScan", fields)
     }
 }
@@ -86,6 +92,7 @@ impl ToBatchPb for BatchFileScan {
                 .collect(),
             file_format: match self.core.file_format {
                 generic::FileFormat::Parquet => FileFormat::Parquet as i32,
+                generic::FileFormat::Jsonl => FileFormat::Jsonl as i32,
             },
             storage_type: match self.core.storage_type {
                 generic::StorageType::S3 => StorageType::S3 as i32,
@@ -93,6 +100,10 @@ impl ToBatchPb for BatchFileScan {
             s3_region: self.core.s3_region.clone(),
             s3_access_key: self.core.s3_access_key.clone(),
             s3_secret_key: self.core.s3_secret_key.clone(),
+            s3_endpoint: self.core.s3_endpoint.clone().unwrap_or_default(),
+            path_style_access: self.core.path_style_access,
+            include_file_path: self.core.include_file_path,
+            sample_rows: self.core.sample_rows.unwrap_or(0),
             file_location: self.core.file_location.clone(),
         })
     }
@@ -27,11 +27,13 @@ use super::utils::{childless_record, to_pb_time_travel_as_of, Distill};
 use super::{generic, ExprRewritable, PlanBase, PlanRef, ToDistributedBatch};
 use crate::catalog::ColumnId;
 use crate::error::Result;
-use crate::expr::{ExprRewriter, ExprVisitor};
+use crate::expr::{Expr, ExprRewriter, ExprVisitor};
 use crate::optimizer::plan_node::expr_visitable::ExprVisitable;
 use crate::optimizer::plan_node::{ToLocalBatch, TryToBatchPb};
 use crate::optimizer::property::{Distribution, DistributionDisplay, Order};
+use crate::optimizer::rule::TableScanIoEstimator;
 use crate::scheduler::SchedulerResult;
+use crate::utils::{Condition, ConditionDisplay};
 
 /// `BatchSeqScan` implements [`super::LogicalScan`] to scan from a row-oriented table
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -41,6 +43,24 @@ pub struct BatchSeqScan {
     scan_ranges: Vec<ScanRange>,
     limit: Option<u64>,
     as_of: Option<AsOf>,
+    /// A committed epoch pinned by the planner for CDC backfill, so that the snapshot scan reads
+    /// exactly the epoch at which the streaming job started consuming the changelog. Distinct
+    /// from a user-provided `AS OF`, which it overrides during serialization.
+    backfill_epoch: Option<u64>,
+    /// The residual (non-key) predicate evaluated against the output columns during the scan.
+    /// `Condition::true_cond()` when there is nothing to evaluate.
+    residual_filter: Condition,
+    /// Return only the latest version per primary-key prefix: within each group of rows sharing
+    /// all but the last order column, only the first row in scan order is emitted. Meaningful
+    /// for append-only tables whose trailing key column encodes a version, ordered so that the
+    /// latest version sorts first. Set programmatically by the planner; never derived from user
+    /// syntax.
+    dedup_latest: bool,
+    /// Iterate the scan range backward, yielding rows in descending primary-key order. The
+    /// range bounds keep their forward meaning; only the iteration direction flips. Only
+    /// meaningful with at most one scan range, where the plan node's order is reversed
+    /// accordingly.
+    reversed: bool,
 }
 
 impl BatchSeqScan {
@@ -49,15 +69,22 @@ impl BatchSeqScan {
         dist: Distribution,
         scan_ranges: Vec<ScanRange>,
         limit: Option<u64>,
+        residual_filter: Condition,
+        reversed: bool,
     ) -> Self {
         let order = if scan_ranges.len() > 1 {
             Order::any()
+        } else if reversed {
+            core.get_out_column_index_order().reverse()
         } else {
             core.get_out_column_index_order()
         };
         let base = PlanBase::new_batch_with_core(&core, dist, order);
 
         {
+            // With multiple ranges rows of different ranges interleave, so a reverse scan would
+            // not produce the descending order it claims.
+            assert!(!reversed || scan_ranges.len() <= 1, "invalid reverse scan");
             // validate scan_range
             scan_ranges.iter().for_each(|scan_range| {
                 assert!(!scan_range.is_full_table_scan());
@@ -78,12 +105,28 @@ impl BatchSeqScan {
             scan_ranges,
             limit,
             as_of,
+            backfill_epoch: None,
+            residual_filter,
+            dedup_latest: false,
+            reversed,
         }
     }
 
-    pub fn new(core: generic::TableScan, scan_ranges: Vec<ScanRange>, limit: Option<u64>) -> Self {
+    pub fn new(
+        core: generic::TableScan,
+        scan_ranges: Vec<ScanRange>,
+        limit: Option<u64>,
+        residual_filter: Condition,
+    ) -> Self {
         // Use `Single` by default, will be updated later with `clone_with_dist`.
-        Self::new_inner(core, Distribution::Single, scan_ranges, limit)
+        Self::new_inner(
+            core,
+            Distribution::Single,
+            scan_ranges,
+            limit,
+            residual_filter,
+            false,
+        )
     }
 
     pub fn new_with_dist(
@@ -91,38 +134,95 @@ impl BatchSeqScan {
         dist: Distribution,
         scan_ranges: Vec<ScanRange>,
         limit: Option<u64>,
+        residual_filter: Condition,
     ) -> Self {
-        Self::new_inner(core, dist, scan_ranges, limit)
+        Self::new_inner(core, dist, scan_ranges, limit, residual_filter, false)
+    }
+
+    /// Pins the snapshot read to the given committed epoch. Set by the planner for CDC backfill;
+    /// never derived from user syntax.
+    #[must_use]
+    pub fn with_backfill_epoch(&self, backfill_epoch: u64) -> Self {
+        Self {
+            backfill_epoch: Some(backfill_epoch),
+            ..self.clone()
+        }
+    }
+
+    pub fn backfill_epoch(&self) -> Option<u64> {
+        self.backfill_epoch
+    }
+
+    /// Enables latest-version-per-key dedup during the storage scan. See the field doc for the
+    /// semantics relative to the scan order.
+    #[must_use]
+    pub fn with_dedup_latest(&self) -> Self {
+        Self {
+            dedup_latest: true,
+            ..self.clone()
+        }
+    }
+
+    pub fn dedup_latest(&self) -> bool {
+        self.dedup_latest
+    }
+
+    /// Flips the scan to iterate its range backward. See the field doc; the plan node's order
+    /// is recomputed to the reversed primary-key order.
+    #[must_use]
+    pub fn with_reversed(&self) -> Self {
+        Self {
+            backfill_epoch: self.backfill_epoch,
+            dedup_latest: self.dedup_latest,
+            ..Self::new_inner(
+                self.core.clone(),
+                self.distribution().clone(),
+                self.scan_ranges.clone(),
+                self.limit,
+                self.residual_filter.clone(),
+                true,
+            )
+        }
+    }
+
+    pub fn reversed(&self) -> bool {
+        self.reversed
     }
 
     fn clone_with_dist(&self) -> Self {
-        Self::new_inner(
-            self.core.clone(),
-            match self.core.distribution_key() {
-                None => Distribution::SomeShard,
-                Some(distribution_key) => {
-                    if distribution_key.is_empty() {
-                        Distribution::Single
-                    } else {
-                        // For other batch operators, `HashShard` is a simple hashing, i.e.,
-                        // `target_shard = hash(dist_key) % shard_num`
-                        //
-                        // But MV is actually sharded by consistent hashing, i.e.,
-                        // `target_shard = vnode_mapping.map(hash(dist_key) % vnode_num)`
-                        //
-                        // They are incompatible, so we just specify its distribution as
-                        // `SomeShard` to force an exchange is
-                        // inserted.
-                        Distribution::UpstreamHashShard(
-                            distribution_key,
-                            self.core.table_desc.table_id,
-                        )
+        Self {
+            backfill_epoch: self.backfill_epoch,
+            dedup_latest: self.dedup_latest,
+            ..Self::new_inner(
+                self.core.clone(),
+                match self.core.distribution_key() {
+                    None => Distribution::SomeShard,
+                    Some(distribution_key) => {
+                        if distribution_key.is_empty() {
+                            Distribution::Single
+                        } else {
+                            // For other batch operators, `HashShard` is a simple hashing, i.e.,
+                            // `target_shard = hash(dist_key) % shard_num`
+                            //
+                            // But MV is actually sharded by consistent hashing, i.e.,
+                            // `target_shard = vnode_mapping.map(hash(dist_key) % vnode_num)`
+                            //
+                            // They are incompatible, so we just specify its distribution as
+                            // `SomeShard` to force an exchange is
+                            // inserted.
+                            Distribution::UpstreamHashShard(
+                                distribution_key,
+                                self.core.table_desc.table_id,
+                            )
+                        }
                     }
-                }
-            },
-            self.scan_ranges.clone(),
-            self.limit,
-        )
+                },
+                self.scan_ranges.clone(),
+                self.limit,
+                self.residual_filter.clone(),
+                self.reversed,
+            )
+        }
     }
 
     /// Get a reference to the batch seq scan's logical.
@@ -143,6 +243,18 @@ impl BatchSeqScan {
         let mut range_strs = vec![];
 
         let explain_max_range = 20;
+        // A long `IN`-list on the key lowers to many pure-eq ranges on the same prefix. Render
+        // them as a single multi-point entry instead of truncating at `explain_max_range`.
+        if self.scan_ranges.len() > explain_max_range
+            && let Some(prefix_len) = multi_point_prefix_len(&self.scan_ranges)
+        {
+            return vec![multi_point_to_string(
+                &self.scan_ranges,
+                &order_names,
+                prefix_len,
+                explain_max_range,
+            )];
+        }
         for scan_range in self.scan_ranges.iter().take(explain_max_range) {
             #[expect(clippy::disallowed_methods)]
             let mut range_str = scan_range
@@ -169,6 +281,134 @@ impl BatchSeqScan {
     pub fn limit(&self) -> &Option<u64> {
         &self.limit
     }
+
+    pub fn residual_filter(&self) -> &Condition {
+        &self.residual_filter
+    }
+
+    /// Whether the storage scan may stop reading as soon as `limit` rows have been returned,
+    /// rather than reading the whole range and truncating above.
+    ///
+    /// With [`Self::dedup_latest`], `limit` counts deduplicated output rows while the storage
+    /// iterator reads raw versions, so early stopping is disabled and the limit is applied to
+    /// the deduplicated stream instead.
+    pub fn stop_after_limit(&self) -> bool {
+        stop_after_limit(
+            self.limit,
+            self.scan_ranges.len(),
+            !self.order().is_any(),
+            self.dedup_latest,
+        )
+    }
+
+    /// Whether every scan range pins the full primary key, so each range yields at most one row
+    /// and the executor may serve the whole node with point-gets.
+    pub fn point_lookup(&self) -> bool {
+        is_point_lookup(
+            &self.scan_ranges,
+            self.core.table_desc.order_column_indices().len(),
+        )
+    }
+
+    /// The chunk size the executor should use for this scan, targeting the per-chunk memory
+    /// budget of `BATCH_SCAN_CHUNK_SIZE_TARGET_BYTES`: wide rows get smaller chunks, narrow
+    /// rows larger ones. `None` when the budget is unset (the default), letting the executor's
+    /// configured chunk size apply.
+    pub fn chunk_size_hint(&self) -> Option<u32> {
+        let target_bytes = self
+            .base
+            .ctx()
+            .session_ctx()
+            .config()
+            .batch_scan_chunk_size_target_bytes();
+        if target_bytes == 0 {
+            return None;
+        }
+        let row_size = TableScanIoEstimator::estimate_table_row_size(&self.core.table_desc) as u64;
+        Some((target_bytes / row_size.max(1)).clamp(1, MAX_CHUNK_SIZE_HINT) as u32)
+    }
+}
+
+/// Upper bound of [`BatchSeqScan::chunk_size_hint`], so a large memory budget over a narrow
+/// table does not degenerate into one huge chunk.
+const MAX_CHUNK_SIZE_HINT: u64 = 65536;
+
+/// Whether all `scan_ranges` are pure-eq lookups pinning the full primary key of length
+/// `pk_len`. Empty `scan_ranges` mean a full table scan and never qualify.
+fn is_point_lookup(scan_ranges: &[ScanRange], pk_len: usize) -> bool {
+    !scan_ranges.is_empty()
+        && scan_ranges
+            .iter()
+            .all(|r| r.eq_conds.len() == pk_len && is_full_range(&r.range))
+}
+
+/// The common eq-prefix length if all `scan_ranges` are pure-eq on the same key prefix — the
+/// shape produced by `IN`-list pushdown — or `None` if any range carries a bound or the prefix
+/// lengths differ.
+fn multi_point_prefix_len(scan_ranges: &[ScanRange]) -> Option<usize> {
+    let prefix_len = scan_ranges.first()?.eq_conds.len();
+    (prefix_len > 0
+        && scan_ranges
+            .iter()
+            .all(|r| r.eq_conds.len() == prefix_len && is_full_range(&r.range)))
+    .then_some(prefix_len)
+}
+
+/// Renders pure-eq `scan_ranges` as a single `IN`-style entry, showing at most `max_shown`
+/// points followed by the total count.
+fn multi_point_to_string(
+    scan_ranges: &[ScanRange],
+    order_names: &[String],
+    prefix_len: usize,
+    max_shown: usize,
+) -> String {
+    let fmt_point = |r: &ScanRange| {
+        let values = r
+            .eq_conds
+            .iter()
+            .map(|v| match v {
+                Some(v) => format!("{:?}", v),
+                None => "null".to_string(),
+            })
+            .join(", ");
+        if prefix_len > 1 {
+            format!("({})", values)
+        } else {
+            values
+        }
+    };
+    let key = if prefix_len > 1 {
+        format!("({})", order_names[..prefix_len].join(", "))
+    } else {
+        order_names[0].clone()
+    };
+    let shown = scan_ranges.iter().take(max_shown).map(fmt_point).join(", ");
+    format!("{} IN ({}, ... {} values)", key, shown, scan_ranges.len())
+}
+
+/// Decides whether a scan with the pushed-down `limit` may stop reading after `limit` rows.
+///
+/// Only sound for at most one ordered scan range: with multiple ranges the output order is
+/// [`Order::any`] and rows of different ranges interleave, so the limit must be applied above
+/// the scan instead. Also disabled under `dedup_latest`, where the limit counts deduplicated
+/// output rows rather than the raw versions the iterator reads.
+fn stop_after_limit(
+    limit: Option<u64>,
+    num_scan_ranges: usize,
+    ordered: bool,
+    dedup_latest: bool,
+) -> bool {
+    limit.is_some() && num_scan_ranges <= 1 && ordered && !dedup_latest
+}
+
+/// The `as_of` to serialize into `RowSeqScanNode`. A backfill-pinned epoch takes precedence
+/// over any user-provided `as_of` and is carried exactly in its own field, so the serialized
+/// `as_of` is suppressed to keep the executor from resolving a competing snapshot.
+fn effective_as_of(backfill_epoch: Option<u64>, as_of: &Option<AsOf>) -> Option<AsOf> {
+    match backfill_epoch {
+        Some(_) => None,
+        None => as_of.clone(),
+    }
 }
 
 impl_plan_tree_node_for_leaf! { BatchSeqScan }
@@ -207,6 +447,19 @@ impl Distill for BatchSeqScan {
         vec.push(("table", Pretty::from(self.core.table_name.clone())));
         vec.push(("columns", self.core.columns_pretty(verbose)));
 
+        // The physical column order of the underlying table, for correlating with the storage
+        // encoding. Gated behind `EXPLAIN (STORAGE)` to keep normal verbose output readable.
+        if self.base.ctx().is_explain_storage_verbose() {
+            let storage_columns = self
+                .core
+                .table_desc
+                .columns
+                .iter()
+                .map(|c| Pretty::from(format!("{} #{}", c.name, c.column_id)))
+                .collect();
+            vec.push(("storage_columns", Pretty::Array(storage_columns)));
+        }
+
         if !self.scan_ranges.is_empty() {
             let range_strs = self.scan_ranges_as_strs(verbose);
             vec.push((
@@ -219,6 +472,45 @@ impl Distill for BatchSeqScan {
             vec.push(("limit", Pretty::display(limit)));
         }
 
+        if self.reversed {
+            vec.push(("reversed", Pretty::debug(&true)));
+        }
+
+        // Only rendered when `BATCH_SCAN_CHUNK_SIZE_TARGET_BYTES` is set.
+        if let Some(chunk_size_hint) = self.chunk_size_hint() {
+            vec.push(("chunk_size_hint", Pretty::display(&chunk_size_hint)));
+        }
+
+        // A time-travel scan would otherwise look identical to a normal one; render the
+        // requested point in time. Scans without `AS OF` omit the line.
+        if let Some(as_of) = &self.as_of {
+            let as_of = match as_of {
+                AsOf::ProcessTime => "proctime()".to_string(),
+                AsOf::ProcessTimeWithInterval((value, leading_field)) => {
+                    format!("now() - {} {}", value, leading_field)
+                }
+                AsOf::TimestampNum(ts) => format!("timestamp {}", ts),
+                AsOf::TimestampString(ts) => format!("timestamp '{}'", ts),
+                AsOf::VersionNum(v) => format!("version {}", v),
+                AsOf::VersionString(v) => format!("version '{}'", v),
+            };
+            vec.push(("as_of", Pretty::from(as_of)));
+        }
+
+        if self.dedup_latest {
+            vec.push(("dedup_latest", Pretty::debug(&true)));
+        }
+
+        if !self.residual_filter.always_true() {
+            vec.push((
+                "residual_filter",
+                Pretty::display(&ConditionDisplay {
+                    condition: &self.residual_filter,
+                    input_schema: self.base.schema(),
+                }),
+            ));
+        }
+
         if verbose {
             let dist = Pretty::display(&DistributionDisplay {
                 distribution: self.distribution(),
@@ -252,7 +544,17 @@ impl TryToBatchPb for BatchSeqScan {
             vnode_bitmap: None,
             ordered: !self.order().is_any(),
             limit: *self.limit(),
-            as_of: to_pb_time_travel_as_of(&self.as_of)?,
+            as_of: to_pb_time_travel_as_of(&effective_as_of(self.backfill_epoch, &self.as_of))?,
+            residual_filter: self
+                .residual_filter
+                .as_expr_unless_true()
+                .map(|expr| expr.to_expr_proto()),
+            stop_after_limit: self.stop_after_limit(),
+            point_lookup: self.point_lookup(),
+            dedup_latest: self.dedup_latest,
+            reversed: self.reversed,
+            chunk_size_hint: self.chunk_size_hint(),
+            backfill_epoch: self.backfill_epoch,
         }))
     }
 }
@@ -268,12 +570,18 @@ impl ToLocalBatch for BatchSeqScan {
             // scan.
             Distribution::SomeShard
         };
-        Ok(Self::new_inner(
-            self.core.clone(),
-            dist,
-            self.scan_ranges.clone(),
-            self.limit,
-        )
+        Ok(Self {
+            backfill_epoch: self.backfill_epoch,
+            dedup_latest: self.dedup_latest,
+            ..Self::new_inner(
+                self.core.clone(),
+                dist,
+                self.scan_ranges.clone(),
+                self.limit,
+                self.residual_filter.clone(),
+                self.reversed,
+            )
+        }
         .into())
     }
 }
@@ -286,12 +594,132 @@ impl ExprRewritable for BatchSeqScan {
     fn rewrite_exprs(&self, r: &mut dyn ExprRewriter) -> PlanRef {
         let mut core = self.core.clone();
         core.rewrite_exprs(r);
-        Self::new(core, self.scan_ranges.clone(), self.limit).into()
+        let scan = Self::new(
+            core,
+            self.scan_ranges.clone(),
+            self.limit,
+            self.residual_filter.clone().rewrite_expr(r),
+        );
+        if self.reversed {
+            scan.with_reversed().into()
+        } else {
+            scan.into()
+        }
     }
 }
 
 impl ExprVisitable for BatchSeqScan {
     fn visit_exprs(&self, v: &mut dyn ExprVisitor) {
         self.core.visit_exprs(v);
+        self.residual_filter.visit_expr(v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::ScalarImpl;
+    use risingwave_common::util::epoch::Epoch;
+    use risingwave_common::util::scan_range::full_range;
+
+    use super::{
+        effective_as_of, is_point_lookup, multi_point_prefix_len, multi_point_to_string,
+        stop_after_limit, AsOf, ScanRange,
+    };
+
+    #[test]
+    fn test_stop_after_limit() {
+        // Single ordered range (or ordered full scan) with a pushed-down limit.
+        assert!(stop_after_limit(Some(1), 0, true, false));
+        assert!(stop_after_limit(Some(1), 1, true, false));
+        // No limit pushed down.
+        assert!(!stop_after_limit(None, 1, true, false));
+        // Multiple ranges imply `Order::any()`, which must not set the flag.
+        assert!(!stop_after_limit(Some(1), 2, false, false));
+        assert!(!stop_after_limit(Some(1), 1, false, false));
+        // Under dedup the limit counts deduplicated output rows, not raw versions read, so the
+        // scan may not stop early.
+        assert!(!stop_after_limit(Some(1), 1, true, true));
+    }
+
+    #[test]
+    fn test_backfill_epoch_overrides_as_of() {
+        let epoch = Epoch::from_unix_millis(1_700_000_000_000);
+
+        // A backfill-pinned epoch suppresses any user-provided `AS OF`: the raw epoch travels
+        // in its own `RowSeqScanNode` field, without truncation to whole seconds.
+        assert_eq!(effective_as_of(Some(epoch.0), &Some(AsOf::ProcessTime)), None);
+        assert_eq!(effective_as_of(Some(epoch.0), &None), None);
+
+        // Without a pinned epoch, the user `AS OF` passes through unchanged.
+        let user_as_of = Some(AsOf::TimestampNum(42));
+        assert_eq!(effective_as_of(None, &user_as_of), user_as_of);
+        assert_eq!(effective_as_of(None, &None), None);
+    }
+
+    #[test]
+    fn test_point_lookup_hint() {
+        use std::ops::Bound;
+
+        // Equality on every column of a 2-column key pins a single row: a point get.
+        let full_pk = ScanRange {
+            eq_conds: vec![Some(ScalarImpl::Int64(1)), Some(ScalarImpl::Int64(2))],
+            range: full_range(),
+        };
+        assert!(is_point_lookup(&[full_pk.clone()], 2));
+
+        // Equality on a strict key prefix is a range scan, with or without a bound on the
+        // next column.
+        let prefix = ScanRange {
+            eq_conds: vec![Some(ScalarImpl::Int64(1))],
+            range: full_range(),
+        };
+        assert!(!is_point_lookup(&[prefix], 2));
+        let bounded = ScanRange {
+            eq_conds: vec![Some(ScalarImpl::Int64(1))],
+            range: (
+                Bound::Included(ScalarImpl::Int64(0)),
+                Bound::Unbounded,
+            ),
+        };
+        assert!(!is_point_lookup(&[bounded.clone()], 2));
+
+        // A batch of ranges qualifies only if every one of them pins the full key.
+        assert!(!is_point_lookup(&[full_pk, bounded], 2));
+    }
+
+    #[test]
+    fn test_in_list_multi_point_lookup() {
+        // A 1000-element `IN`-list on a single-column key lowers to 1000 pure-eq ranges.
+        let ranges: Vec<ScanRange> = (0..1000i64)
+            .map(|i| ScanRange {
+                eq_conds: vec![Some(ScalarImpl::Int64(i))],
+                range: full_range(),
+            })
+            .collect();
+        assert_eq!(multi_point_prefix_len(&ranges), Some(1));
+
+        // Every range pins the full key, so the point-get hint is set...
+        assert!(is_point_lookup(&ranges, 1));
+        // ...but not when a second key column is left unpinned.
+        assert!(!is_point_lookup(&ranges, 2));
+        assert!(!is_point_lookup(&[], 1));
+
+        // The explain output shows a compact multi-point entry instead of a truncated list.
+        assert_eq!(
+            multi_point_to_string(&ranges, &["id".to_string()], 1, 3),
+            "id IN (Int64(0), Int64(1), Int64(2), ... 1000 values)"
+        );
+
+        // A range-bearing entry disables both the compact form and the hint.
+        let mut mixed = ranges.clone();
+        mixed.push(ScanRange {
+            eq_conds: vec![],
+            range: (
+                std::ops::Bound::Included(ScalarImpl::Int64(0)),
+                std::ops::Bound::Unbounded,
+            ),
+        });
+        assert_eq!(multi_point_prefix_len(&mixed), None);
+        assert!(!is_point_lookup(&mixed, 1));
     }
 }
@@ -45,12 +45,35 @@ impl BatchTopN {
         BatchTopN { base, core }
     }
 
+    /// If `input` is a forward [`super::BatchSeqScan`] whose reversed order satisfies the Top-N
+    /// order (e.g. a scan of a lower-bounded pk range under `ORDER BY pk DESC`), returns the
+    /// scan flipped to iterate backward, so that the Top-N degenerates into a plain limit and
+    /// the limit can be pushed into the scan. Otherwise returns `input` unchanged.
+    fn try_reverse_input_scan(&self, input: PlanRef) -> PlanRef {
+        if input.order().satisfies(&self.core.order) || self.core.limit_attr.with_ties() {
+            return input;
+        }
+        if let Some(scan) = input.as_batch_seq_scan()
+            && !scan.reversed()
+            // With multiple ranges rows of different ranges interleave, so reversing cannot
+            // produce an ordered result (and `with_reversed` asserts against it).
+            && scan.scan_ranges().len() <= 1
+        {
+            let reversed = scan.with_reversed();
+            if reversed.order().satisfies(&self.core.order) {
+                return reversed.into();
+            }
+        }
+        input
+    }
+
     fn two_phase_topn(&self, input: PlanRef) -> Result<PlanRef> {
         let new_limit = TopNLimit::new(
             self.core.limit_attr.limit() + self.core.offset,
             self.core.limit_attr.with_ties(),
         );
         let new_offset = 0;
+        let input = self.try_reverse_input_scan(input);
         let partial_input: PlanRef = if input.order().satisfies(&self.core.order)
             && !self.core.limit_attr.with_ties()
         {
@@ -72,6 +95,7 @@ impl BatchTopN {
     }
 
     fn one_phase_topn(&self, input: PlanRef) -> Result<PlanRef> {
+        let input = self.try_reverse_input_scan(input);
         if input.order().satisfies(&self.core.order) && !self.core.limit_attr.with_ties() {
             let logical_limit =
                 generic::Limit::new(input, self.core.limit_attr.limit(), self.core.offset);
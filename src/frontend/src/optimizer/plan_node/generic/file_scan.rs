@@ -22,6 +22,8 @@ use crate::optimizer::property::FunctionalDependencySet;
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum FileFormat {
     Parquet,
+    /// Newline-delimited JSON (NDJSON): one JSON object per line.
+    Jsonl,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -38,7 +40,23 @@ pub struct FileScan {
     pub s3_region: String,
     pub s3_access_key: String,
     pub s3_secret_key: String,
+    /// Endpoint override for S3-compatible stores (MinIO, Cloudflare R2, ...). `None` targets
+    /// AWS S3.
+    pub s3_endpoint: Option<String>,
+    /// Whether to address the endpoint path-style (`endpoint/bucket/key`) instead of
+    /// virtual-hosted-style (`bucket.endpoint/key`). Only meaningful with an endpoint override.
+    pub path_style_access: bool,
+    /// Whether the output carries the `_rw_file_path` pseudo-column, populated with the source
+    /// S3 key per file at read time instead of being read from the file. Column pruning may
+    /// move it within [`Self::schema`] or drop it; the executor locates it by name.
+    pub include_file_path: bool,
+    /// Stop the scan after this many rows in total, for quick previews. Which rows are returned
+    /// is non-deterministic across files.
+    pub sample_rows: Option<u64>,
     pub file_location: Vec<String>,
+    /// Whether the output must preserve the per-file read order. When `false`, the scheduler is
+    /// free to interleave files across parallel readers.
+    pub ordered: bool,
 
     #[educe(PartialEq(ignore))]
     #[educe(Hash(ignore))]
@@ -12,17 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use pretty_xmlish::XmlNode;
+use pretty_xmlish::{Pretty, XmlNode};
 use risingwave_common::bail;
 use risingwave_common::catalog::Schema;
+use risingwave_common::types::{DefaultPartialOrd, ScalarImpl};
 
 use super::generic::GenericPlanRef;
 use super::utils::{childless_record, Distill};
 use super::{
-    generic, BatchFileScan, ColPrunable, ExprRewritable, Logical, LogicalProject, PlanBase,
-    PlanRef, PredicatePushdown, ToBatch, ToStream,
+    generic, BatchFileScan, ColPrunable, ExprRewritable, Logical, PlanBase, PlanRef,
+    PredicatePushdown, ToBatch, ToStream,
 };
 use crate::error::Result;
+use crate::expr::{Expr, ExprType};
 use crate::optimizer::plan_node::expr_visitable::ExprVisitable;
 use crate::optimizer::plan_node::utils::column_names_pretty;
 use crate::optimizer::plan_node::{
@@ -47,19 +49,35 @@ impl LogicalFileScan {
         s3_region: String,
         s3_access_key: String,
         s3_secret_key: String,
+        s3_endpoint: Option<String>,
+        path_style_access: bool,
+        include_file_path: bool,
+        sample_rows: Option<u64>,
         file_location: Vec<String>,
+        ordered: bool,
     ) -> Self {
-        assert!("parquet".eq_ignore_ascii_case(&file_format));
+        let file_format = if "parquet".eq_ignore_ascii_case(&file_format) {
+            generic::FileFormat::Parquet
+        } else if "jsonl".eq_ignore_ascii_case(&file_format) {
+            generic::FileFormat::Jsonl
+        } else {
+            panic!("unsupported file format: {file_format}");
+        };
         assert!("s3".eq_ignore_ascii_case(&storage_type));
 
         let core = generic::FileScan {
             schema,
-            file_format: generic::FileFormat::Parquet,
+            file_format,
             storage_type: generic::StorageType::S3,
             s3_region,
             s3_access_key,
             s3_secret_key,
+            s3_endpoint,
+            path_style_access,
+            include_file_path,
+            sample_rows,
             file_location,
+            ordered,
             ctx,
         };
 
@@ -72,14 +90,30 @@ impl LogicalFileScan {
 impl_plan_tree_node_for_leaf! {LogicalFileScan}
 impl Distill for LogicalFileScan {
     fn distill<'a>(&self) -> XmlNode<'a> {
-        let fields = vec![("columns", column_names_pretty(self.schema()))];
+        let mut fields = vec![("columns", column_names_pretty(self.schema()))];
+        if !self.core.ordered {
+            fields.push(("ordered", Pretty::debug(&false)));
+        }
+        if let Some(n) = self.core.sample_rows {
+            fields.push(("sample_rows", Pretty::debug(&n)));
+        }
         childless_record("LogicalFileScan", fields)
     }
 }
 
 impl ColPrunable for LogicalFileScan {
     fn prune_col(&self, required_cols: &[usize], _ctx: &mut ColumnPruningContext) -> PlanRef {
-        LogicalProject::with_out_col_idx(self.clone().into(), required_cols.iter().cloned()).into()
+        // Push the projection into the scan itself, so that only the required column chunks
+        // are fetched from the parquet files.
+        let mut core = self.core.clone();
+        core.schema = Schema::new(
+            required_cols
+                .iter()
+                .map(|&i| self.schema().fields()[i].clone())
+                .collect(),
+        );
+        let base = PlanBase::new_logical_with_core(&core);
+        LogicalFileScan { base, core }.into()
     }
 }
 
@@ -93,8 +127,20 @@ impl PredicatePushdown for LogicalFileScan {
         predicate: Condition,
         _ctx: &mut PredicatePushdownContext,
     ) -> PlanRef {
-        // No pushdown.
-        LogicalFilter::create(self.clone().into(), predicate)
+        // Hive-style partitioned layouts encode column values in the path (`.../dt=2024-01-01/...`),
+        // so files whose partition contradicts the predicate can be dropped without ever being
+        // opened. The predicate itself stays in the plan: it still filters the surviving files
+        // row by row.
+        let pruned = prune_files_by_partition(self.schema(), &predicate, &self.core.file_location);
+        let this = if pruned.len() != self.core.file_location.len() {
+            let mut core = self.core.clone();
+            core.file_location = pruned;
+            let base = PlanBase::new_logical_with_core(&core);
+            LogicalFileScan { base, core }
+        } else {
+            self.clone()
+        };
+        LogicalFilter::create(this.into(), predicate)
     }
 }
 
@@ -116,3 +162,193 @@ impl ToStream for LogicalFileScan {
         bail!("file_scan function is not supported in streaming mode")
     }
 }
+
+/// Drops file locations whose Hive-style path segments (`key=value`) contradict the predicate.
+///
+/// Only conjuncts of the form `column <cmp> constant` are considered, and only for files whose
+/// path actually encodes that column; anything that cannot be decided from the path alone keeps
+/// the file, so pruning is always conservative.
+fn prune_files_by_partition(schema: &Schema, predicate: &Condition, files: &[String]) -> Vec<String> {
+    let mut constraints = vec![];
+    for conjunct in &predicate.conjunctions {
+        let (input_ref, cmp, constant) = if let Some((input_ref, constant)) = conjunct.as_eq_const()
+        {
+            (input_ref, ExprType::Equal, constant)
+        } else if let Some((input_ref, cmp, constant)) = conjunct.as_comparison_const() {
+            (input_ref, cmp, constant)
+        } else {
+            continue;
+        };
+        // The partition value is parsed as the column's own type, so the literal must be of
+        // that type as well for the comparison below to be meaningful.
+        if constant.return_type() != input_ref.return_type() {
+            continue;
+        }
+        let Some(Ok(Some(value))) = constant.try_fold_const() else {
+            continue;
+        };
+        constraints.push((
+            schema.fields()[input_ref.index()].name.clone(),
+            input_ref.return_type(),
+            cmp,
+            value,
+        ));
+    }
+    if constraints.is_empty() {
+        return files.to_vec();
+    }
+
+    files
+        .iter()
+        .filter(|path| {
+            constraints.iter().all(|(name, data_type, cmp, value)| {
+                let Some(raw) = partition_value(path, name) else {
+                    return true;
+                };
+                let Ok(partition) = ScalarImpl::from_text(raw, data_type) else {
+                    // A malformed partition value cannot be compared; keep the file.
+                    return true;
+                };
+                let Some(ord) = partition.default_partial_cmp(value) else {
+                    return true;
+                };
+                match cmp {
+                    ExprType::Equal => ord.is_eq(),
+                    ExprType::LessThan => ord.is_lt(),
+                    ExprType::LessThanOrEqual => ord.is_le(),
+                    ExprType::GreaterThan => ord.is_gt(),
+                    ExprType::GreaterThanOrEqual => ord.is_ge(),
+                    _ => true,
+                }
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+/// Returns the Hive-style partition value encoded in `path` for `key`, if any.
+fn partition_value<'a>(path: &'a str, key: &str) -> Option<&'a str> {
+    path.split('/').find_map(|segment| {
+        let (k, v) = segment.split_once('=')?;
+        (k == key).then_some(v)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::Field;
+    use risingwave_common::types::DataType;
+
+    use super::*;
+    use crate::expr::{ExprImpl, FunctionCall, InputRef, Literal};
+
+    fn schema() -> Schema {
+        Schema::new(vec![
+            Field::with_name(DataType::Varchar, "dt"),
+            Field::with_name(DataType::Int32, "hour"),
+            Field::with_name(DataType::Int64, "v"),
+        ])
+    }
+
+    fn cmp(func_type: ExprType, col: usize, data_type: DataType, value: ScalarImpl) -> ExprImpl {
+        FunctionCall::new(
+            func_type,
+            vec![
+                InputRef::new(col, data_type.clone()).into(),
+                Literal::new(Some(value), data_type).into(),
+            ],
+        )
+        .unwrap()
+        .into()
+    }
+
+    fn files() -> Vec<String> {
+        vec![
+            "s3://b/t/dt=2024-01-01/hour=03/a.parquet".to_string(),
+            "s3://b/t/dt=2024-01-01/hour=15/b.parquet".to_string(),
+            "s3://b/t/dt=2024-01-02/hour=03/c.parquet".to_string(),
+            "s3://b/t/unpartitioned.parquet".to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_prune_files_by_partition_equality() {
+        let predicate = Condition {
+            conjunctions: vec![cmp(
+                ExprType::Equal,
+                0,
+                DataType::Varchar,
+                ScalarImpl::from("2024-01-01"),
+            )],
+        };
+        // Files of other partitions are dropped; files without the key are kept.
+        assert_eq!(
+            prune_files_by_partition(&schema(), &predicate, &files()),
+            vec![
+                "s3://b/t/dt=2024-01-01/hour=03/a.parquet".to_string(),
+                "s3://b/t/dt=2024-01-01/hour=15/b.parquet".to_string(),
+                "s3://b/t/unpartitioned.parquet".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_prune_files_by_partition_multi_key_range() {
+        // Partition values are compared as the column's type: `hour=03` parses to 3.
+        let predicate = Condition {
+            conjunctions: vec![
+                cmp(
+                    ExprType::Equal,
+                    0,
+                    DataType::Varchar,
+                    ScalarImpl::from("2024-01-01"),
+                ),
+                cmp(
+                    ExprType::GreaterThanOrEqual,
+                    1,
+                    DataType::Int32,
+                    ScalarImpl::Int32(12),
+                ),
+            ],
+        };
+        assert_eq!(
+            prune_files_by_partition(&schema(), &predicate, &files()),
+            vec![
+                "s3://b/t/dt=2024-01-01/hour=15/b.parquet".to_string(),
+                "s3://b/t/unpartitioned.parquet".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_prune_files_by_partition_is_conservative() {
+        // A predicate on a column that never appears in the paths prunes nothing.
+        let predicate = Condition {
+            conjunctions: vec![cmp(
+                ExprType::LessThan,
+                2,
+                DataType::Int64,
+                ScalarImpl::Int64(10),
+            )],
+        };
+        assert_eq!(
+            prune_files_by_partition(&schema(), &predicate, &files()),
+            files()
+        );
+
+        // A partition value that doesn't parse as the column's type keeps the file.
+        let predicate = Condition {
+            conjunctions: vec![cmp(
+                ExprType::LessThan,
+                1,
+                DataType::Int32,
+                ScalarImpl::Int32(6),
+            )],
+        };
+        let malformed = vec!["s3://b/t/hour=morning/a.parquet".to_string()];
+        assert_eq!(
+            prune_files_by_partition(&schema(), &predicate, &malformed),
+            malformed
+        );
+    }
+}
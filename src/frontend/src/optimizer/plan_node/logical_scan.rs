@@ -433,8 +433,9 @@ impl PredicatePushdown for LogicalScan {
 impl LogicalScan {
     fn to_batch_inner_with_required(&self, required_order: &Order) -> Result<PlanRef> {
         if self.predicate().always_true() {
-            required_order
-                .enforce_if_not_satisfies(BatchSeqScan::new(self.core.clone(), vec![], None).into())
+            required_order.enforce_if_not_satisfies(
+                BatchSeqScan::new(self.core.clone(), vec![], None, Condition::true_cond()).into(),
+            )
         } else {
             let (scan_ranges, predicate) = self.predicate().clone().split_to_scan_ranges(
                 self.core.table_desc.clone(),
@@ -448,10 +449,25 @@ impl LogicalScan {
             } else {
                 let (scan, predicate, project_expr) = scan.predicate_pull_up();
 
-                let mut plan: PlanRef = BatchSeqScan::new(scan, scan_ranges, None).into();
-                if !predicate.always_true() {
-                    plan = BatchFilter::new(generic::Filter::new(predicate, plan)).into();
-                }
+                // With residual filter pushdown enabled, the non-key predicate is evaluated
+                // inside the storage scan instead of a `BatchFilter` above it.
+                let push_residual = !predicate.always_true()
+                    && self
+                        .base
+                        .ctx()
+                        .session_ctx()
+                        .config()
+                        .batch_enable_residual_filter_pushdown();
+                let mut plan: PlanRef = if push_residual {
+                    BatchSeqScan::new(scan, scan_ranges, None, predicate).into()
+                } else {
+                    let mut plan: PlanRef =
+                        BatchSeqScan::new(scan, scan_ranges, None, Condition::true_cond()).into();
+                    if !predicate.always_true() {
+                        plan = BatchFilter::new(generic::Filter::new(predicate, plan)).into();
+                    }
+                    plan
+                };
                 if let Some(exprs) = project_expr {
                     plan = BatchProject::new(generic::Project::new(exprs, plan)).into()
                 }
@@ -45,6 +45,18 @@ impl Order {
     pub fn len(&self) -> usize {
         self.column_orders.len()
     }
+
+    /// The order with every column's direction and nulls placement flipped. Iterating an input
+    /// sorted by `self` backward yields rows in exactly this order.
+    pub fn reverse(&self) -> Self {
+        Self {
+            column_orders: self
+                .column_orders
+                .iter()
+                .map(|o| ColumnOrder::new(o.column_index, o.order_type.reverse()))
+                .collect(),
+        }
+    }
 }
 
 impl fmt::Display for Order {
@@ -34,13 +34,24 @@ impl Rule for BatchPushLimitToScanRule {
         if scan.limit().is_some() {
             return None;
         }
+        // The limit applies after the residual filter, so it cannot be pushed below one.
+        if !scan.residual_filter().always_true() {
+            return None;
+        }
         let pushed_limit = limit.limit() + limit.offset();
         let new_scan = BatchSeqScan::new_with_dist(
             scan.core().clone(),
             scan.base.distribution().clone(),
             scan.scan_ranges().iter().cloned().collect_vec(),
             Some(pushed_limit),
+            scan.residual_filter().clone(),
         );
+        // Keep the iteration direction of a reverse scan.
+        let new_scan = if scan.reversed() {
+            new_scan.with_reversed()
+        } else {
+            new_scan
+        };
         Some(limit.clone_with_input(new_scan.into()).into())
     }
 }
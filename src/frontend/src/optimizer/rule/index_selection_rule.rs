@@ -52,7 +52,7 @@ use std::collections::{BTreeMap, HashMap};
 use std::rc::Rc;
 
 use itertools::Itertools;
-use risingwave_common::catalog::Schema;
+use risingwave_common::catalog::{Schema, TableDesc};
 use risingwave_common::types::{
     DataType, Date, Decimal, Int256, Interval, Serial, Time, Timestamp, Timestamptz,
 };
@@ -712,7 +712,7 @@ impl IndexSelectionRule {
     }
 }
 
-struct TableScanIoEstimator<'a> {
+pub struct TableScanIoEstimator<'a> {
     table_scan: &'a LogicalScan,
     row_size: usize,
     cost: Option<IndexCost>,
@@ -728,9 +728,15 @@ impl<'a> TableScanIoEstimator<'a> {
     }
 
     pub fn estimate_row_size(table_scan: &LogicalScan) -> usize {
+        Self::estimate_table_row_size(table_scan.table_desc())
+    }
+
+    /// Estimated on-storage byte width of one row of `table_desc`, from per-type size
+    /// estimates. Besides index cost estimation, this drives the chunk-size hint of
+    /// `BatchSeqScan`.
+    pub fn estimate_table_row_size(table_desc: &TableDesc) -> usize {
         // 5 for table_id + 1 for vnode + 8 for epoch
         let row_meta_field_estimate_size = 14_usize;
-        let table_desc = table_scan.table_desc();
         row_meta_field_estimate_size
             + table_desc
                 .columns
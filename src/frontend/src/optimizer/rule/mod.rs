@@ -160,6 +160,7 @@ pub use agg_call_merge_rule::*;
 mod pull_up_correlated_predicate_agg_rule;
 mod source_to_iceberg_scan_rule;
 mod source_to_kafka_scan_rule;
+mod table_function_to_file_scan_metadata_rule;
 mod table_function_to_file_scan_rule;
 mod values_extract_project_rule;
 
@@ -167,6 +168,7 @@ pub use batch::batch_push_limit_to_scan_rule::*;
 pub use pull_up_correlated_predicate_agg_rule::*;
 pub use source_to_iceberg_scan_rule::*;
 pub use source_to_kafka_scan_rule::*;
+pub use table_function_to_file_scan_metadata_rule::*;
 pub use table_function_to_file_scan_rule::*;
 pub use values_extract_project_rule::*;
 
@@ -231,6 +233,7 @@ macro_rules! for_all_rules {
             , { ApplyTopNTransposeRule }
             , { TableFunctionToProjectSetRule }
             , { TableFunctionToFileScanRule }
+            , { TableFunctionToFileScanMetadataRule }
             , { ApplyLimitTransposeRule }
             , { CommonSubExprExtractRule }
             , { BatchProjectMergeRule }
@@ -0,0 +1,69 @@
+// Copyright 2024 RisingWave Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::types::DataType;
+use risingwave_common::util::iter_util::ZipEqDebug;
+
+use super::{BoxedRule, Rule};
+use crate::expr::{Expr, TableFunctionType};
+use crate::optimizer::plan_node::generic::GenericPlanRef;
+use crate::optimizer::plan_node::{LogicalTableFunction, LogicalValues};
+use crate::optimizer::PlanRef;
+
+/// Transform a special `TableFunction` (with `FILE_SCAN_METADATA` table function type) into a
+/// `LogicalValues`. The per-file statistics are already collected at binding time and carried
+/// as literal arguments, one row of fields per file, so this rule only reassembles them.
+pub struct TableFunctionToFileScanMetadataRule {}
+impl Rule for TableFunctionToFileScanMetadataRule {
+    fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
+        let logical_table_function: &LogicalTableFunction = plan.as_logical_table_function()?;
+        if logical_table_function.table_function.function_type != TableFunctionType::FileScanMetadata
+        {
+            return None;
+        }
+        assert!(!logical_table_function.with_ordinality);
+        let table_function_return_type = logical_table_function.table_function().return_type();
+
+        if let DataType::Struct(st) = table_function_return_type.clone() {
+            let fields = st
+                .types()
+                .zip_eq_debug(st.names())
+                .map(|(data_type, name)| Field::with_name(data_type.clone(), name.to_string()))
+                .collect_vec();
+            let schema = Schema::new(fields);
+
+            let args = &logical_table_function.table_function().args;
+            assert_eq!(args.len() % schema.len(), 0);
+            let rows = args
+                .iter()
+                .cloned()
+                .chunks(schema.len())
+                .into_iter()
+                .map(|chunk| chunk.collect_vec())
+                .collect_vec();
+
+            Some(LogicalValues::new(rows, schema, logical_table_function.ctx()).into())
+        } else {
+            unreachable!("TableFunction return type should be struct")
+        }
+    }
+}
+
+impl TableFunctionToFileScanMetadataRule {
+    pub fn create() -> BoxedRule {
+        Box::new(TableFunctionToFileScanMetadataRule {})
+    }
+}
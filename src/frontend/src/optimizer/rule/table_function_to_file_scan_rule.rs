@@ -43,9 +43,21 @@ impl Rule for TableFunctionToFileScanRule {
 
             let schema = Schema::new(fields);
 
-            assert!(logical_table_function.table_function().args.len() >= 6);
+            let mut args = logical_table_function.table_function().args.as_slice();
+            // An optional trailing boolean literal marks the scan as unordered.
+            let mut ordered = true;
+            if let Some(last) = args.last()
+                && last.return_type() == DataType::Boolean
+            {
+                if let Some(ScalarImpl::Bool(b)) = last.try_fold_const().unwrap().unwrap() {
+                    ordered = b;
+                }
+                args = &args[..args.len() - 1];
+            }
+
+            assert!(args.len() >= 6);
             let mut eval_args = vec![];
-            for arg in &logical_table_function.table_function().args {
+            for arg in args {
                 assert_eq!(arg.return_type(), DataType::Varchar);
                 let value = arg.try_fold_const().unwrap().unwrap();
                 match value {
@@ -57,8 +69,14 @@ impl Rule for TableFunctionToFileScanRule {
                     }
                 }
             }
-            assert!("parquet".eq_ignore_ascii_case(&eval_args[0]));
-            assert!("s3".eq_ignore_ascii_case(&eval_args[1]));
+            let file_format = eval_args[0].to_lowercase();
+            assert!(matches!(file_format.as_str(), "parquet" | "jsonl"));
+            // The binder folds an S3 endpoint override and the `_rw_file_path` and
+            // `sample_rows` options into the storage-type argument as
+            // `s3;endpoint=<url>;path_style=<bool>;file_path=<bool>;sample_rows=<n>`,
+            // since the trailing positions are taken by the expanded file locations.
+            let (s3_endpoint, path_style_access, include_file_path, sample_rows) =
+                parse_storage_arg(&eval_args[1]);
             let s3_region = eval_args[2].clone();
             let s3_access_key = eval_args[3].clone();
             let s3_secret_key = eval_args[4].clone();
@@ -68,12 +86,17 @@ impl Rule for TableFunctionToFileScanRule {
                 LogicalFileScan::new(
                     logical_table_function.ctx(),
                     schema,
-                    "parquet".to_string(),
+                    file_format,
                     "s3".to_string(),
                     s3_region,
                     s3_access_key,
                     s3_secret_key,
+                    s3_endpoint,
+                    path_style_access,
+                    include_file_path,
+                    sample_rows,
                     file_location,
+                    ordered,
                 )
                 .into(),
             )
@@ -88,3 +111,72 @@ impl TableFunctionToFileScanRule {
         Box::new(TableFunctionToFileScanRule {})
     }
 }
+
+/// Parses the storage-type argument, either a plain `s3` or the binder's
+/// `s3;endpoint=<url>;path_style=<bool>;file_path=<bool>;sample_rows=<n>` encoding of the
+/// optional endpoint override, `_rw_file_path` flag and row-sample limit.
+fn parse_storage_arg(storage: &str) -> (Option<String>, bool, bool, Option<u64>) {
+    let mut parts = storage.split(';');
+    assert!("s3".eq_ignore_ascii_case(parts.next().expect("split is never empty")));
+    let mut s3_endpoint = None;
+    let mut path_style_access = true;
+    let mut include_file_path = false;
+    let mut sample_rows = None;
+    for part in parts {
+        if let Some(endpoint) = part.strip_prefix("endpoint=") {
+            s3_endpoint = Some(endpoint.to_string());
+        } else if let Some(path_style) = part.strip_prefix("path_style=") {
+            path_style_access = path_style.parse().expect("bound as a boolean");
+        } else if let Some(file_path) = part.strip_prefix("file_path=") {
+            include_file_path = file_path.parse().expect("bound as a boolean");
+        } else if let Some(n) = part.strip_prefix("sample_rows=") {
+            sample_rows = Some(n.parse().expect("bound as a positive integer"));
+        } else {
+            unreachable!("unexpected storage-type encoding: {storage}");
+        }
+    }
+    (s3_endpoint, path_style_access, include_file_path, sample_rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_storage_arg;
+
+    #[test]
+    fn test_parse_storage_arg() {
+        assert_eq!(parse_storage_arg("s3"), (None, true, false, None));
+        assert_eq!(parse_storage_arg("S3"), (None, true, false, None));
+        assert_eq!(
+            parse_storage_arg("s3;endpoint=http://127.0.0.1:9000;path_style=true"),
+            (Some("http://127.0.0.1:9000".to_string()), true, false, None)
+        );
+        assert_eq!(
+            parse_storage_arg("s3;endpoint=https://account.r2.cloudflarestorage.com;path_style=false"),
+            (
+                Some("https://account.r2.cloudflarestorage.com".to_string()),
+                false,
+                false,
+                None
+            )
+        );
+        assert_eq!(
+            parse_storage_arg("s3;file_path=true"),
+            (None, true, true, None)
+        );
+        assert_eq!(
+            parse_storage_arg("s3;sample_rows=10"),
+            (None, true, false, Some(10))
+        );
+        assert_eq!(
+            parse_storage_arg(
+                "s3;endpoint=http://127.0.0.1:9000;path_style=true;file_path=true;sample_rows=1000"
+            ),
+            (
+                Some("http://127.0.0.1:9000".to_string()),
+                true,
+                true,
+                Some(1000)
+            )
+        );
+    }
+}
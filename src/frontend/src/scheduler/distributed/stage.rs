@@ -433,17 +433,15 @@ impl StageRunner {
                 }
             }
         } else if let Some(file_scan_info) = self.stage.file_scan_info.as_ref() {
-            let chunk_size = (file_scan_info.file_location.len() as f32
-                / self.stage.parallelism.unwrap() as f32)
-                .ceil() as usize;
-            for (id, files) in file_scan_info.file_location.chunks(chunk_size).enumerate() {
+            let parallelism = self.stage.parallelism.unwrap() as usize;
+            for (id, files) in file_scan_info.split_files(parallelism).enumerate() {
                 let task_id = PbTaskId {
                     query_id: self.stage.query_id.id.clone(),
                     stage_id: self.stage.id,
                     task_id: id as u64,
                 };
                 let plan_fragment =
-                    self.create_plan_fragment(id as u64, Some(PartitionInfo::File(files.to_vec())));
+                    self.create_plan_fragment(id as u64, Some(PartitionInfo::File(files)));
                 let worker =
                     self.choose_worker(&plan_fragment, id as u32, self.stage.dml_table_id)?;
                 futures.push(self.schedule_task(
@@ -400,14 +400,12 @@ impl LocalQueryExecution {
                         sources.push(exchange_source);
                     }
                 } else if let Some(file_scan_info) = &second_stage.file_scan_info {
-                    let chunk_size = (file_scan_info.file_location.len() as f32
-                        / (self.worker_node_manager.schedule_unit_count()) as f32)
-                        .ceil() as usize;
-                    for (id, files) in file_scan_info.file_location.chunks(chunk_size).enumerate() {
+                    let parallelism = self.worker_node_manager.schedule_unit_count();
+                    for (id, files) in file_scan_info.split_files(parallelism).enumerate() {
                         let second_stage_plan_node = self.convert_plan_node(
                             &second_stage.root,
                             &mut None,
-                            Some(PartitionInfo::File(files.to_vec())),
+                            Some(PartitionInfo::File(files)),
                             next_executor_id.clone(),
                         )?;
                         let second_stage_plan_fragment = PlanFragment {
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std::cmp::min;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::{Debug, Formatter};
 use std::num::NonZeroU64;
 use std::sync::Arc;
@@ -440,6 +440,37 @@ pub struct TablePartitionInfo {
     pub scan_ranges: Vec<ScanRangeProto>,
 }
 
+impl TablePartitionInfo {
+    /// Renders the vnodes this partition is responsible for as `count/total` followed by the
+    /// compressed index ranges, e.g. `3/16: [5, 10-11]`. Shown in the scheduled-plan explain
+    /// output of `EXPLAIN (DISTSQL)`.
+    pub fn vnode_summary(&self) -> String {
+        let mut ranges: Vec<(usize, usize)> = vec![];
+        for i in self.vnode_bitmap.iter_ones() {
+            match ranges.last_mut() {
+                Some((_, end)) if *end + 1 == i => *end = i,
+                _ => ranges.push((i, i)),
+            }
+        }
+        let ranges = ranges
+            .iter()
+            .map(|(start, end)| {
+                if start == end {
+                    format!("{start}")
+                } else {
+                    format!("{start}-{end}")
+                }
+            })
+            .join(", ");
+        format!(
+            "{}/{}: [{}]",
+            self.vnode_bitmap.count_ones(),
+            self.vnode_bitmap.len(),
+            ranges
+        )
+    }
+}
+
 #[derive(Clone, Debug, EnumAsInner)]
 pub enum PartitionInfo {
     Table(TablePartitionInfo),
@@ -450,6 +481,35 @@ pub enum PartitionInfo {
 #[derive(Clone, Debug)]
 pub struct FileScanInfo {
     pub file_location: Vec<String>,
+    /// Whether the scan must preserve the per-file read order. Relaxed by
+    /// `file_scan(..., ordered := false)`.
+    pub ordered: bool,
+}
+
+impl FileScanInfo {
+    /// Partitions the files into at most `parallelism` non-empty groups, so that each group can
+    /// be assigned to a different parallel task. Every file appears in exactly one group.
+    ///
+    /// When the scan is ordered, each group is a contiguous run of files so that concatenating
+    /// the groups in task order restores the original order. Otherwise the files are dealt
+    /// round-robin, which interleaves large and small files more evenly across tasks.
+    pub fn split_files(&self, parallelism: usize) -> impl Iterator<Item = Vec<String>> {
+        let groups = if self.ordered {
+            let chunk_size = (self.file_location.len() as f32 / parallelism as f32).ceil() as usize;
+            self.file_location
+                .chunks(chunk_size.max(1))
+                .map(|files| files.to_vec())
+                .collect_vec()
+        } else {
+            let num_groups = self.file_location.len().min(parallelism).max(1);
+            let mut groups = vec![vec![]; num_groups];
+            for (i, file) in self.file_location.iter().enumerate() {
+                groups[i % num_groups].push(file.clone());
+            }
+            groups
+        };
+        groups.into_iter()
+    }
 }
 
 /// Fragment part of `Query`.
@@ -558,10 +618,29 @@ impl Serialize for QueryStage {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_struct("QueryStage", 3)?;
+        // The vnodes each scan task reads are decided by the scheduler and invisible in the
+        // logical plan, so surface them here in the scheduled-plan explain output.
+        let vnode_assignment: Option<BTreeMap<String, String>> = self
+            .table_scan_info
+            .as_ref()
+            .and_then(|info| info.partitions())
+            .map(|partitions| {
+                partitions
+                    .iter()
+                    .map(|(worker_slot_id, partition)| {
+                        (worker_slot_id.to_string(), partition.vnode_summary())
+                    })
+                    .collect()
+            });
+
+        let mut state =
+            serializer.serialize_struct("QueryStage", 3 + vnode_assignment.is_some() as usize)?;
         state.serialize_field("root", &self.root)?;
         state.serialize_field("parallelism", &self.parallelism)?;
         state.serialize_field("exchange_info", &self.exchange_info)?;
+        if let Some(vnode_assignment) = &vnode_assignment {
+            state.serialize_field("vnode_assignment", vnode_assignment)?;
+        }
         state.end()
     }
 }
@@ -1118,6 +1197,7 @@ impl BatchPlanFragmenter {
         if let Some(batch_file_scan) = node.as_batch_file_scan() {
             return Ok(Some(FileScanInfo {
                 file_location: batch_file_scan.core.file_location.clone(),
+                ordered: batch_file_scan.core.ordered,
             }));
         }
 
@@ -1302,10 +1382,73 @@ fn derive_partitions(
 mod tests {
     use std::collections::{HashMap, HashSet};
 
+    use risingwave_common::bitmap::Bitmap;
+    use risingwave_common::hash::WorkerSlotId;
     use risingwave_pb::batch_plan::plan_node::NodeBody;
 
     use crate::optimizer::plan_node::PlanNodeType;
-    use crate::scheduler::plan_fragmenter::StageId;
+    use crate::scheduler::plan_fragmenter::{FileScanInfo, StageId, TablePartitionInfo};
+
+    #[test]
+    fn test_vnode_summary() {
+        // A scan split across two worker slots: one gets the lower half of 16 vnodes plus a
+        // stray one, the other gets the rest.
+        let first = TablePartitionInfo {
+            vnode_bitmap: (0..16).map(|i| i < 8 || i == 10).collect::<Bitmap>(),
+            scan_ranges: vec![],
+        };
+        let second = TablePartitionInfo {
+            vnode_bitmap: (0..16).map(|i| i >= 8 && i != 10).collect::<Bitmap>(),
+            scan_ranges: vec![],
+        };
+        assert_eq!(first.vnode_summary(), "9/16: [0-7, 10]");
+        assert_eq!(second.vnode_summary(), "7/16: [8-9, 11-15]");
+        assert_eq!(WorkerSlotId::new(1, 0).to_string(), "[1:0]");
+
+        let empty = TablePartitionInfo {
+            vnode_bitmap: Bitmap::zeros(16),
+            scan_ranges: vec![],
+        };
+        assert_eq!(empty.vnode_summary(), "0/16: []");
+    }
+
+    #[test]
+    fn test_file_scan_split_files() {
+        let file_scan_info = FileScanInfo {
+            file_location: (0..10).map(|i| format!("s3://bucket/{}.parquet", i)).collect(),
+            ordered: true,
+        };
+        for parallelism in 1..=12 {
+            let groups: Vec<_> = file_scan_info.split_files(parallelism).collect();
+            assert!(groups.len() <= parallelism);
+            assert!(groups.iter().all(|g| !g.is_empty()));
+            // Every file is assigned to exactly one group, in the original order.
+            let assigned: Vec<_> = groups.iter().flatten().cloned().collect();
+            assert_eq!(assigned, file_scan_info.file_location);
+        }
+    }
+
+    #[test]
+    fn test_file_scan_split_files_unordered() {
+        let file_scan_info = FileScanInfo {
+            file_location: (0..10).map(|i| format!("s3://bucket/{}.parquet", i)).collect(),
+            ordered: false,
+        };
+        for parallelism in 1..=12 {
+            let groups: Vec<_> = file_scan_info.split_files(parallelism).collect();
+            assert!(groups.len() <= parallelism);
+            assert!(groups.iter().all(|g| !g.is_empty()));
+            // Every file is assigned to exactly one group.
+            let mut assigned: Vec<_> = groups.iter().flatten().cloned().collect();
+            assigned.sort();
+            assert_eq!(assigned, file_scan_info.file_location);
+        }
+        // Files are dealt round-robin across the groups.
+        let groups: Vec<_> = file_scan_info.split_files(3).collect();
+        assert_eq!(groups[0], ["s3://bucket/0.parquet", "s3://bucket/3.parquet", "s3://bucket/6.parquet", "s3://bucket/9.parquet"]);
+        assert_eq!(groups[1], ["s3://bucket/1.parquet", "s3://bucket/4.parquet", "s3://bucket/7.parquet"]);
+        assert_eq!(groups[2], ["s3://bucket/2.parquet", "s3://bucket/5.parquet", "s3://bucket/8.parquet"]);
+    }
 
     #[tokio::test]
     async fn test_fragmenter() {
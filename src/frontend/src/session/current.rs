@@ -12,9 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use pgwire::pg_protocol::CURRENT_SESSION;
+use risingwave_common::secret::LocalSecretManager;
+use risingwave_pb::secret::secret_ref::PbRefAsType;
+use risingwave_pb::secret::PbSecretRef;
 
 use super::SessionImpl;
+use crate::error::{ErrorCode, Result};
 
 fn with_current_session<R>(f: impl FnOnce(&SessionImpl) -> R) -> Option<R> {
     CURRENT_SESSION
@@ -27,3 +33,47 @@ fn with_current_session<R>(f: impl FnOnce(&SessionImpl) -> R) -> Option<R> {
 pub(crate) fn notice_to_user(str: impl Into<String>) {
     let _ = with_current_session(|s| s.notice_to_user(str));
 }
+
+/// The time zone of the current session. Returns `"UTC"` if not in the context of a session.
+pub(crate) fn timezone() -> String {
+    with_current_session(|s| s.config().timezone().to_owned())
+        .unwrap_or_else(|| "UTC".to_owned())
+}
+
+/// Whether the current session enables strict struct casting. Returns `false` if not in the
+/// context of a session.
+pub(crate) fn strict_struct_cast() -> bool {
+    with_current_session(|s| s.config().strict_struct_cast()).unwrap_or(false)
+}
+
+/// Resolves a secret by name through the current session's search path and returns its
+/// plaintext value. Errors if not in the context of a session, if the secret does not exist,
+/// or if its value has not been propagated to this frontend yet.
+pub(crate) fn resolve_secret(secret_name: &str) -> Result<Vec<u8>> {
+    with_current_session(|s| {
+        let secret = s.get_secret_by_name(None, secret_name)?;
+        let filled = LocalSecretManager::global()
+            .fill_secrets(
+                BTreeMap::new(),
+                BTreeMap::from([(
+                    secret_name.to_string(),
+                    PbSecretRef {
+                        secret_id: secret.id.secret_id(),
+                        ref_as: PbRefAsType::Text as i32,
+                    },
+                )]),
+            )
+            .map_err(|e| anyhow::anyhow!(e))?;
+        Ok(filled
+            .into_values()
+            .next()
+            .expect("exactly one secret ref was filled")
+            .into_bytes())
+    })
+    .unwrap_or_else(|| {
+        Err(ErrorCode::BindError(
+            "secret references can only be resolved in the context of a session".to_string(),
+        )
+        .into())
+    })
+}
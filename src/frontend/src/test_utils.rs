@@ -37,8 +37,8 @@ use risingwave_hummock_sdk::{HummockVersionId, INVALID_VERSION_ID};
 use risingwave_pb::backup_service::MetaSnapshotMetadata;
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::{
-    PbComment, PbDatabase, PbFunction, PbIndex, PbSchema, PbSink, PbSource, PbStreamJobStatus,
-    PbSubscription, PbTable, PbView, Table,
+    PbComment, PbDatabase, PbFunction, PbIndex, PbSchema, PbSecret, PbSink, PbSource,
+    PbStreamJobStatus, PbSubscription, PbTable, PbView, Table,
 };
 use risingwave_pb::common::WorkerNode;
 use risingwave_pb::ddl_service::alter_owner_request::Object;
@@ -382,13 +382,21 @@ impl CatalogWriter for MockCatalogWriter {
 
     async fn create_secret(
         &self,
-        _secret_name: String,
-        _database_id: u32,
-        _schema_id: u32,
-        _owner_id: u32,
-        _payload: Vec<u8>,
+        secret_name: String,
+        database_id: u32,
+        schema_id: u32,
+        owner_id: u32,
+        payload: Vec<u8>,
     ) -> Result<()> {
-        unreachable!()
+        self.catalog.write().create_secret(&PbSecret {
+            id: self.gen_id(),
+            name: secret_name,
+            database_id,
+            value: payload,
+            owner: owner_id,
+            schema_id,
+        });
+        Ok(())
     }
 
     async fn comment_on(&self, _comment: PbComment) -> Result<()> {
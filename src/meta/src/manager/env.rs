@@ -407,7 +407,7 @@ impl MetaSrvEnv {
             MetaStoreImpl::Kv(meta_store) => {
                 let notification_manager =
                     Arc::new(NotificationManager::new(meta_store_impl.clone()).await);
-                let id_gen_manager = Arc::new(IdGeneratorManager::new(meta_store.clone()).await);
+                let id_gen_manager = Arc::new(IdGeneratorManager::new(meta_store.clone()).await?);
                 let (cluster_id, cluster_first_launch) =
                     if let Some(id) = ClusterId::from_meta_store(meta_store).await? {
                         (id, false)
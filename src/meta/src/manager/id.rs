@@ -12,20 +12,43 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
 
+use prometheus::{register_int_gauge_vec_with_registry, IntGauge, IntGaugeVec};
 use risingwave_common::catalog::NON_RESERVED_USER_ID;
+use risingwave_common::monitor::GLOBAL_METRICS_REGISTRY;
 use risingwave_hummock_sdk::compaction_group::StaticCompactionGroupId;
 use thiserror_ext::AsReport;
 use tokio::sync::RwLock;
 
 use crate::manager::cluster::META_NODE_ID;
-use crate::model::MetadataModelResult;
+use crate::model::{MetadataModelError, MetadataModelResult};
 use crate::storage::{MetaStore, MetaStoreError, MetaStoreRef, DEFAULT_COLUMN_FAMILY};
 
 pub const ID_PREALLOCATE_INTERVAL: u64 = 1000;
 
+/// Upper bound on the `interval` of a single [`IdGenerator::generate_interval`] call. Far above
+/// any legitimate batch (e.g. the actors of the largest streaming job), while keeping the
+/// boundary-bump arithmetic in `generate_interval` far away from `u64` overflow. Requests above
+/// it are rejected as errors instead of silently saturating the id space.
+pub const MAX_GENERATE_INTERVAL: u64 = 1 << 30;
+
+/// Per-category gap between the in-memory id cursor and the persisted preallocation boundary.
+/// These are the ids "lost" if the meta node restarts right now: recovery resumes from the
+/// persisted `next_allocate_id`. A persistently large gap on a high-churn category suggests
+/// tuning [`ID_PREALLOCATE_INTERVAL`].
+static ID_PREALLOCATE_GAP: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    register_int_gauge_vec_with_registry!(
+        "meta_id_preallocate_gap",
+        "per-category gap between the current id cursor and the persisted preallocation boundary",
+        &["category"],
+        &GLOBAL_METRICS_REGISTRY,
+    )
+    .unwrap()
+});
+
 pub type Id = u64;
 
 // TODO: remove unnecessary async trait.
@@ -39,18 +62,35 @@ pub trait IdGenerator: Sync + Send + 'static {
     async fn generate(&self) -> MetadataModelResult<Id> {
         self.generate_interval(1).await
     }
+
+    /// Generate `n` distinct identities as a materialized `Vec`, allocated with a single
+    /// [`Self::generate_interval`] call. Saves callers from re-deriving `[base, base + n)`
+    /// themselves.
+    async fn generate_batch(&self, n: u64) -> MetadataModelResult<Vec<Id>> {
+        let base = self.generate_interval(n).await?;
+        Ok((base..base + n).collect())
+    }
 }
 
 /// [`StoredIdGenerator`] implements id generator using metastore.
 pub struct StoredIdGenerator {
     meta_store: MetaStoreRef,
+    category: String,
     category_gen_key: String,
     current_id: AtomicU64,
     next_allocate_id: RwLock<Id>,
+    /// The [`ID_PREALLOCATE_GAP`] gauge for this category.
+    preallocate_gap: IntGauge,
+    /// See [`Self::with_degraded_mode`].
+    degraded_mode: bool,
 }
 
 impl StoredIdGenerator {
-    pub async fn new(meta_store: MetaStoreRef, category: &str, start: Option<Id>) -> Self {
+    pub async fn new(
+        meta_store: MetaStoreRef,
+        category: &str,
+        start: Option<Id>,
+    ) -> MetadataModelResult<Self> {
         let category_gen_key = format!("{}_id_next_generator", category);
         let res = meta_store
             .get_cf(DEFAULT_COLUMN_FAMILY, category_gen_key.as_bytes())
@@ -58,54 +98,147 @@ impl StoredIdGenerator {
         let current_id = match res {
             Ok(value) => memcomparable::from_slice(&value).unwrap(),
             Err(MetaStoreError::ItemNotFound(_)) => start.unwrap_or(0),
-            Err(e) => panic!("{}", e.as_report()),
+            Err(e) => return Err(e.into()),
         };
 
-        let next_allocate_id = current_id + ID_PREALLOCATE_INTERVAL;
-        if let Err(err) = meta_store
+        let next_allocate_id = current_id.saturating_add(ID_PREALLOCATE_INTERVAL);
+        meta_store
             .put_cf(
                 DEFAULT_COLUMN_FAMILY,
                 category_gen_key.clone().into_bytes(),
                 memcomparable::to_vec(&next_allocate_id).unwrap(),
             )
-            .await
-        {
-            panic!("{}", err.as_report());
-        }
+            .await?;
+
+        let preallocate_gap = ID_PREALLOCATE_GAP.with_label_values(&[category]);
+        preallocate_gap.set(next_allocate_id.saturating_sub(current_id) as i64);
 
-        StoredIdGenerator {
+        Ok(StoredIdGenerator {
             meta_store,
+            category: category.to_string(),
             category_gen_key,
             current_id: AtomicU64::new(current_id),
             next_allocate_id: RwLock::new(next_allocate_id),
+            preallocate_gap,
+            degraded_mode: false,
+        })
+    }
+
+    /// Enables degraded mode: when the preallocation write fails mid-allocation, only the
+    /// allocation that needed to advance the persisted boundary errors, and the generator keeps
+    /// serving ids from the remaining *durable* window instead of failing every request until
+    /// the meta store recovers. Ids past the last successfully persisted `next_allocate_id` are
+    /// never handed out, so the crash-safety invariant is unchanged.
+    pub fn with_degraded_mode(mut self) -> Self {
+        self.degraded_mode = true;
+        self
+    }
+
+    /// Reserves all ids up to `id` (inclusive), so that future allocations never collide with
+    /// them. Both the in-memory cursor and the persisted `next_allocate_id` are bumped to at
+    /// least `id + 1` if they are below it; otherwise this is a no-op. Used e.g. when restoring
+    /// a backup where restored objects must keep their original ids.
+    pub async fn reserve_up_to(&self, id: Id) -> MetadataModelResult<()> {
+        let reserved_end = id.checked_add(1).ok_or_else(|| {
+            MetadataModelError::IdExhausted {
+                category: self.category.clone(),
+            }
+        })?;
+
+        // Hold the write lock while persisting, so a concurrent `generate_interval` cannot
+        // persist a smaller preallocation boundary after ours.
+        let mut next = self.next_allocate_id.write().await;
+        if reserved_end > *next {
+            self.meta_store
+                .put_cf(
+                    DEFAULT_COLUMN_FAMILY,
+                    self.category_gen_key.clone().into_bytes(),
+                    memcomparable::to_vec(&reserved_end).unwrap(),
+                )
+                .await?;
+            *next = reserved_end;
         }
+        // An already-higher cursor must not be rewound.
+        self.current_id.fetch_max(reserved_end, Ordering::Relaxed);
+        self.preallocate_gap
+            .set((*next).saturating_sub(self.current_id.load(Ordering::Relaxed)) as i64);
+        Ok(())
     }
 }
 
 #[async_trait::async_trait]
 impl IdGenerator for StoredIdGenerator {
     async fn generate_interval(&self, interval: u64) -> MetadataModelResult<Id> {
-        let id = self.current_id.fetch_add(interval, Ordering::Relaxed);
-        let next_allocate_id = { *self.next_allocate_id.read().await };
-        let request_id = id.checked_add(interval).unwrap();
+        // Reject pathological intervals up front, before the cursor is touched.
+        if interval > MAX_GENERATE_INTERVAL {
+            return Err(MetadataModelError::InternalError(anyhow::anyhow!(
+                "id allocation interval {} for category {} exceeds the maximum of {}",
+                interval,
+                self.category,
+                MAX_GENERATE_INTERVAL
+            )));
+        }
+        let id_exhausted = || MetadataModelError::IdExhausted {
+            category: self.category.clone(),
+        };
+        // Reject the request instead of wrapping `current_id` around when the id space of the
+        // category is exhausted, so ids are never reused.
+        let id = self
+            .current_id
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |id| {
+                id.checked_add(interval)
+            })
+            .map_err(|_| id_exhausted())?;
+        let mut next_allocate_id = { *self.next_allocate_id.read().await };
+        let request_id = id + interval;
         if request_id > next_allocate_id {
             let mut next = self.next_allocate_id.write().await;
             if request_id > *next {
                 let weight =
                     num_integer::Integer::div_ceil(&(request_id - *next), &ID_PREALLOCATE_INTERVAL);
-                let next_allocate_id = (*next)
-                    .checked_add(ID_PREALLOCATE_INTERVAL * weight)
-                    .unwrap();
-                self.meta_store
+                let next_allocate_id = ID_PREALLOCATE_INTERVAL
+                    .checked_mul(weight)
+                    .and_then(|inc| (*next).checked_add(inc))
+                    .unwrap_or(u64::MAX);
+                let put_res = self
+                    .meta_store
                     .put_cf(
                         DEFAULT_COLUMN_FAMILY,
                         self.category_gen_key.clone().into_bytes(),
                         memcomparable::to_vec(&next_allocate_id).unwrap(),
                     )
-                    .await?;
+                    .await;
+                if let Err(e) = put_res {
+                    if !self.degraded_mode {
+                        return Err(e.into());
+                    }
+                    // The durable boundary was not advanced, so this allocation must fail: its
+                    // ids extend past `*next`. Try to hand the ids back so later allocations
+                    // that fit below the durable boundary keep succeeding while the meta store
+                    // is down. The rollback is only safe if no concurrent allocation moved the
+                    // cursor in between; otherwise the ids are leaked, never reused.
+                    let _ = self.current_id.compare_exchange(
+                        request_id,
+                        id,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    );
+                    tracing::warn!(
+                        category = self.category,
+                        error = %e.as_report(),
+                        "failed to persist id preallocation boundary, serving from the \
+                         remaining durable window"
+                    );
+                    return Err(e.into());
+                }
                 *next = next_allocate_id;
             }
+            next_allocate_id = *next;
         }
+        // The allocation moved the cursor (and possibly the boundary), so the gap changed.
+        // Concurrent allocators may race on the gauge; it is a monitoring approximation.
+        self.preallocate_gap
+            .set(next_allocate_id.saturating_sub(request_id) as i64);
 
         Ok(id)
     }
@@ -138,6 +271,8 @@ pub mod IdCategory {
     pub const Connection: IdCategoryType = 17;
 
     pub const Secret: IdCategoryType = 18;
+    pub const Subscription: IdCategoryType = 19;
+    pub const View: IdCategoryType = 20;
 }
 
 pub type IdGeneratorManagerRef = Arc<IdGeneratorManager>;
@@ -161,40 +296,47 @@ pub struct IdGeneratorManager {
     compaction_group: Arc<StoredIdGenerator>,
     connection: Arc<StoredIdGenerator>,
     secret: Arc<StoredIdGenerator>,
+    subscription: Arc<StoredIdGenerator>,
+    view: Arc<StoredIdGenerator>,
+    /// Generators for categories registered at runtime via [`Self::register_category`], keyed by
+    /// category name. Built-in categories keep the const-generic fast path above.
+    dynamic: RwLock<HashMap<String, Arc<StoredIdGenerator>>>,
+    /// Kept around to lazily create generators for dynamically registered categories.
+    meta_store: MetaStoreRef,
 }
 
 impl IdGeneratorManager {
-    pub async fn new(meta_store: MetaStoreRef) -> Self {
-        Self {
+    pub async fn new(meta_store: MetaStoreRef) -> MetadataModelResult<Self> {
+        Ok(Self {
             #[cfg(test)]
-            test: Arc::new(StoredIdGenerator::new(meta_store.clone(), "test", None).await),
-            database: Arc::new(StoredIdGenerator::new(meta_store.clone(), "database", None).await),
-            schema: Arc::new(StoredIdGenerator::new(meta_store.clone(), "schema", None).await),
-            table: Arc::new(StoredIdGenerator::new(meta_store.clone(), "table", Some(1)).await),
-            function: Arc::new(StoredIdGenerator::new(meta_store.clone(), "function", None).await),
+            test: Arc::new(StoredIdGenerator::new(meta_store.clone(), "test", None).await?),
+            database: Arc::new(StoredIdGenerator::new(meta_store.clone(), "database", None).await?),
+            schema: Arc::new(StoredIdGenerator::new(meta_store.clone(), "schema", None).await?),
+            table: Arc::new(StoredIdGenerator::new(meta_store.clone(), "table", Some(1)).await?),
+            function: Arc::new(StoredIdGenerator::new(meta_store.clone(), "function", None).await?),
             worker: Arc::new(
                 StoredIdGenerator::new(meta_store.clone(), "worker", Some(META_NODE_ID as u64 + 1))
-                    .await,
+                    .await?,
             ),
             fragment: Arc::new(
-                StoredIdGenerator::new(meta_store.clone(), "fragment", Some(1)).await,
+                StoredIdGenerator::new(meta_store.clone(), "fragment", Some(1)).await?,
             ),
-            actor: Arc::new(StoredIdGenerator::new(meta_store.clone(), "actor", Some(1)).await),
+            actor: Arc::new(StoredIdGenerator::new(meta_store.clone(), "actor", Some(1)).await?),
             user: Arc::new(
                 StoredIdGenerator::new(
                     meta_store.clone(),
                     "user",
                     Some(NON_RESERVED_USER_ID as u64),
                 )
-                .await,
+                .await?,
             ),
-            backup: Arc::new(StoredIdGenerator::new(meta_store.clone(), "backup", Some(1)).await),
+            backup: Arc::new(StoredIdGenerator::new(meta_store.clone(), "backup", Some(1)).await?),
             hummock_ss_table_id: Arc::new(
-                StoredIdGenerator::new(meta_store.clone(), "hummock_ss_table_id", Some(1)).await,
+                StoredIdGenerator::new(meta_store.clone(), "hummock_ss_table_id", Some(1)).await?,
             ),
             hummock_compaction_task: Arc::new(
                 StoredIdGenerator::new(meta_store.clone(), "hummock_compaction_task", Some(1))
-                    .await,
+                    .await?,
             ),
             compaction_group: Arc::new(
                 StoredIdGenerator::new(
@@ -202,13 +344,52 @@ impl IdGeneratorManager {
                     "compaction_group",
                     Some(StaticCompactionGroupId::End as u64 + 1),
                 )
-                .await,
+                .await?,
             ),
             connection: Arc::new(
-                StoredIdGenerator::new(meta_store.clone(), "connection", None).await,
+                StoredIdGenerator::new(meta_store.clone(), "connection", None).await?,
             ),
-            secret: Arc::new(StoredIdGenerator::new(meta_store.clone(), "secret", None).await),
+            secret: Arc::new(StoredIdGenerator::new(meta_store.clone(), "secret", None).await?),
+            subscription: Arc::new(
+                StoredIdGenerator::new(meta_store.clone(), "subscription", None).await?,
+            ),
+            view: Arc::new(StoredIdGenerator::new(meta_store.clone(), "view", None).await?),
+            dynamic: RwLock::new(HashMap::new()),
+            meta_store,
+        })
+    }
+
+    /// Registers a category under the given name, lazily creating its [`StoredIdGenerator`] the
+    /// first time the name is seen. Registering an already-registered name is a no-op, so its id
+    /// sequence is never reset. The name must not collide with a built-in category name.
+    pub async fn register_category(&self, name: &str) -> MetadataModelResult<()> {
+        if self.dynamic.read().await.contains_key(name) {
+            return Ok(());
+        }
+        let mut dynamic = self.dynamic.write().await;
+        // Re-check under the write lock in case of a concurrent registration.
+        if !dynamic.contains_key(name) {
+            let generator =
+                Arc::new(StoredIdGenerator::new(self.meta_store.clone(), name, None).await?);
+            dynamic.insert(name.to_string(), generator);
         }
+        Ok(())
+    }
+
+    /// Generates an id from a category registered via [`Self::register_category`].
+    pub async fn generate_dynamic(&self, name: &str) -> MetadataModelResult<Id> {
+        let generator = self
+            .dynamic
+            .read()
+            .await
+            .get(name)
+            .cloned()
+            .ok_or_else(|| {
+                MetadataModelError::InternalError(anyhow::anyhow!(
+                    "id category {name} is not registered"
+                ))
+            })?;
+        generator.generate().await
     }
 
     const fn get<const C: IdCategoryType>(&self) -> &Arc<StoredIdGenerator> {
@@ -229,6 +410,8 @@ impl IdGeneratorManager {
             IdCategory::CompactionGroup => &self.compaction_group,
             IdCategory::Connection => &self.connection,
             IdCategory::Secret => &self.secret,
+            IdCategory::Subscription => &self.subscription,
+            IdCategory::View => &self.view,
             _ => unreachable!(),
         }
     }
@@ -246,19 +429,318 @@ impl IdGeneratorManager {
     ) -> MetadataModelResult<Id> {
         self.get::<C>().generate_interval(interval).await
     }
+
+    /// [`Self::generate_batch`] generates `n` distinct ids as a materialized `Vec`, using a
+    /// single allocation of the underlying generator.
+    pub async fn generate_batch<const C: IdCategoryType>(
+        &self,
+        n: u64,
+    ) -> MetadataModelResult<Vec<Id>> {
+        self.get::<C>().generate_batch(n).await
+    }
+
+    /// [`Self::reserve_up_to`] reserves all ids of the category up to `id` (inclusive), e.g.
+    /// for bulk import of objects that must keep their original ids.
+    pub async fn reserve_up_to<const C: IdCategoryType>(&self, id: Id) -> MetadataModelResult<()> {
+        self.get::<C>().reserve_up_to(id).await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use futures::future;
+    use risingwave_common::config::MetaBackend;
 
     use super::*;
-    use crate::storage::{MemStore, MetaStoreBoxExt};
+    use crate::storage::{Key, MemStore, MetaStoreBoxExt, MetaStoreResult, Transaction, Value};
+
+    /// A meta store that fails every read and write, to simulate transient meta-store errors
+    /// during startup.
+    struct FailingMetaStore(MemStore);
+
+    #[async_trait::async_trait]
+    impl MetaStore for FailingMetaStore {
+        type Snapshot = <MemStore as MetaStore>::Snapshot;
+
+        async fn snapshot(&self) -> Self::Snapshot {
+            self.0.snapshot().await
+        }
+
+        async fn put_cf(&self, _cf: &str, _key: Key, _value: Value) -> MetaStoreResult<()> {
+            Err(anyhow::anyhow!("injected put_cf failure").into())
+        }
+
+        async fn delete_cf(&self, cf: &str, key: &[u8]) -> MetaStoreResult<()> {
+            self.0.delete_cf(cf, key).await
+        }
+
+        async fn txn(&self, trx: Transaction) -> MetaStoreResult<()> {
+            self.0.txn(trx).await
+        }
+
+        async fn get_cf(&self, _cf: &str, _key: &[u8]) -> MetaStoreResult<Vec<u8>> {
+            Err(anyhow::anyhow!("injected get_cf failure").into())
+        }
+
+        fn meta_store_type(&self) -> MetaBackend {
+            self.0.meta_store_type()
+        }
+    }
+
+    /// A meta store whose writes can be made to fail at runtime, to simulate a meta-store blip
+    /// after a healthy startup.
+    struct FlakyMetaStore {
+        inner: MemStore,
+        fail_puts: Arc<std::sync::atomic::AtomicBool>,
+    }
+
+    #[async_trait::async_trait]
+    impl MetaStore for FlakyMetaStore {
+        type Snapshot = <MemStore as MetaStore>::Snapshot;
+
+        async fn snapshot(&self) -> Self::Snapshot {
+            self.inner.snapshot().await
+        }
+
+        async fn put_cf(&self, cf: &str, key: Key, value: Value) -> MetaStoreResult<()> {
+            if self.fail_puts.load(Ordering::Relaxed) {
+                return Err(anyhow::anyhow!("injected put_cf failure").into());
+            }
+            self.inner.put_cf(cf, key, value).await
+        }
+
+        async fn delete_cf(&self, cf: &str, key: &[u8]) -> MetaStoreResult<()> {
+            self.inner.delete_cf(cf, key).await
+        }
+
+        async fn txn(&self, trx: Transaction) -> MetaStoreResult<()> {
+            self.inner.txn(trx).await
+        }
+
+        async fn get_cf(&self, cf: &str, key: &[u8]) -> MetaStoreResult<Vec<u8>> {
+            self.inner.get_cf(cf, key).await
+        }
+
+        fn meta_store_type(&self) -> MetaBackend {
+            self.inner.meta_store_type()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reserve_up_to() -> MetadataModelResult<()> {
+        let meta_store = MemStore::default().into_ref();
+        let id_generator = StoredIdGenerator::new(meta_store.clone(), "default", None).await?;
+        id_generator.reserve_up_to(1_000_000).await?;
+
+        // Subsequent allocations are above the reserved range, also after recovery.
+        let id = id_generator.generate().await?;
+        assert_eq!(id, 1_000_001);
+        let id_generator_two = StoredIdGenerator::new(meta_store.clone(), "default", None).await?;
+        let id = id_generator_two.generate().await?;
+        assert!(id > 1_000_000);
+
+        // Reserving below the current cursor is a no-op and doesn't rewind.
+        id_generator_two.reserve_up_to(10).await?;
+        let next = id_generator_two.generate().await?;
+        assert!(next > id);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_id_generator_exhaustion() -> MetadataModelResult<()> {
+        use rand::{thread_rng, Rng};
+
+        let mut rng = thread_rng();
+        for _ in 0..32 {
+            let start = u64::MAX - rng.gen_range(0..ID_PREALLOCATE_INTERVAL * 4);
+            let meta_store = MemStore::default().into_ref();
+            let id_generator =
+                StoredIdGenerator::new(meta_store, "exhausted", Some(start)).await?;
+
+            let mut last_id = None;
+            loop {
+                let interval = rng.gen_range(1..ID_PREALLOCATE_INTERVAL);
+                match id_generator.generate_interval(interval).await {
+                    Ok(id) => {
+                        // Generated ids stay monotonic up to the boundary.
+                        assert!(id >= start);
+                        if let Some(last_id) = last_id {
+                            assert!(id > last_id);
+                        }
+                        last_id = Some(id);
+                    }
+                    Err(MetadataModelError::IdExhausted { category }) => {
+                        assert_eq!(category, "exhausted");
+                        // Once exhausted, the generator keeps returning an error.
+                        let err = id_generator.generate_interval(interval).await;
+                        assert!(matches!(
+                            err,
+                            Err(MetadataModelError::IdExhausted { .. })
+                        ));
+                        break;
+                    }
+                    Err(e) => panic!("unexpected error: {}", e),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_generate_batch() -> MetadataModelResult<()> {
+        let meta_store = MemStore::default().into_ref();
+        let id_generator = StoredIdGenerator::new(meta_store.clone(), "default", None).await?;
+
+        // The batch is exactly the contiguous range the interval allocation covers.
+        let ids = id_generator.generate_batch(100).await?;
+        assert_eq!(ids, (0..100).collect::<Vec<_>>());
+        let ids = id_generator.generate_batch(10).await?;
+        assert_eq!(ids, (100..110).collect::<Vec<_>>());
+
+        // Also through the manager, and consecutive batches never overlap.
+        let manager = IdGeneratorManager::new(meta_store).await?;
+        let first = manager.generate_batch::<{ IdCategory::Test }>(5).await?;
+        let second = manager.generate_batch::<{ IdCategory::Test }>(5).await?;
+        assert_eq!(first, (0..5).collect::<Vec<_>>());
+        assert_eq!(second, (5..10).collect::<Vec<_>>());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_preallocate_gap_metric() -> MetadataModelResult<()> {
+        let meta_store = MemStore::default().into_ref();
+        // A category name unique to this test: the gauge lives in the global registry.
+        let id_generator =
+            StoredIdGenerator::new(meta_store.clone(), "gap_metric_test", None).await?;
+
+        // A fresh generator has preallocated a whole interval ahead of the cursor.
+        assert_eq!(
+            id_generator.preallocate_gap.get(),
+            ID_PREALLOCATE_INTERVAL as i64
+        );
+
+        // Each allocation narrows the gap by its interval...
+        id_generator.generate().await?;
+        assert_eq!(
+            id_generator.preallocate_gap.get(),
+            ID_PREALLOCATE_INTERVAL as i64 - 1
+        );
+        id_generator.generate_interval(500).await?;
+        assert_eq!(
+            id_generator.preallocate_gap.get(),
+            ID_PREALLOCATE_INTERVAL as i64 - 501
+        );
+
+        // ... until crossing the boundary bumps it by another interval: the cursor is at 1101
+        // and the persisted boundary at 2000.
+        id_generator.generate_interval(600).await?;
+        assert_eq!(
+            id_generator.preallocate_gap.get(),
+            2 * ID_PREALLOCATE_INTERVAL as i64 - 1101
+        );
+
+        // A restart loses exactly the gap: recovery resumes from the persisted boundary.
+        let id_generator = StoredIdGenerator::new(meta_store, "gap_metric_test", None).await?;
+        assert_eq!(id_generator.generate().await?, 2 * ID_PREALLOCATE_INTERVAL);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_generate_interval_upper_bound() -> MetadataModelResult<()> {
+        let meta_store = MemStore::default().into_ref();
+        let id_generator =
+            StoredIdGenerator::new(meta_store.clone(), "interval_bound", None).await?;
+
+        // Pathological intervals are rejected with a clean error, not a panic, and do not
+        // consume any ids.
+        for interval in [MAX_GENERATE_INTERVAL + 1, u64::MAX] {
+            let err = id_generator.generate_interval(interval).await.unwrap_err();
+            assert!(matches!(err, MetadataModelError::InternalError(_)));
+        }
+        assert_eq!(id_generator.generate().await?, 0);
+
+        // The maximum itself is still allocatable.
+        assert_eq!(
+            id_generator.generate_interval(MAX_GENERATE_INTERVAL).await?,
+            1
+        );
+        assert_eq!(id_generator.generate().await?, MAX_GENERATE_INTERVAL + 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_degraded_mode_tolerates_write_failure() -> MetadataModelResult<()> {
+        let fail_puts = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let meta_store = FlakyMetaStore {
+            inner: MemStore::default(),
+            fail_puts: fail_puts.clone(),
+        }
+        .into_ref();
+
+        // Startup persists the boundary at `ID_PREALLOCATE_INTERVAL` (= 1000).
+        let id_generator = StoredIdGenerator::new(meta_store.clone(), "degraded", None)
+            .await?
+            .with_degraded_mode();
+        assert_eq!(id_generator.generate_interval(500).await?, 0);
+
+        // The meta store starts failing writes. Allocations that fit below the durable
+        // boundary keep succeeding without writing...
+        fail_puts.store(true, Ordering::Relaxed);
+        assert_eq!(id_generator.generate_interval(400).await?, 500);
+
+        // ... a crossing allocation errors, but does not burn the remaining durable window...
+        assert!(id_generator.generate_interval(200).await.is_err());
+        assert_eq!(id_generator.generate_interval(100).await?, 900);
+
+        // ... and with the window genuinely exhausted, every allocation errors.
+        assert!(id_generator.generate().await.is_err());
+        assert!(id_generator.generate().await.is_err());
+
+        // Once the meta store recovers, allocation resumes right where it left off.
+        fail_puts.store(false, Ordering::Relaxed);
+        assert_eq!(id_generator.generate().await?, 1000);
+
+        // Recovery from the persisted state never reuses a handed-out id.
+        let id_generator_two = StoredIdGenerator::new(meta_store, "degraded", None).await?;
+        assert!(id_generator_two.generate().await? > 1000);
+
+        // Without degraded mode, the same blip fails allocations until recovery (the
+        // pre-existing behavior).
+        let meta_store = FlakyMetaStore {
+            inner: MemStore::default(),
+            fail_puts: fail_puts.clone(),
+        }
+        .into_ref();
+        let strict_generator =
+            StoredIdGenerator::new(meta_store, "degraded_strict", None).await?;
+        fail_puts.store(true, Ordering::Relaxed);
+        assert!(strict_generator
+            .generate_interval(ID_PREALLOCATE_INTERVAL + 1)
+            .await
+            .is_err());
+        fail_puts.store(false, Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_id_generator_meta_store_error() {
+        let meta_store = FailingMetaStore(MemStore::default()).into_ref();
+        // A meta-store error other than `ItemNotFound` should surface as an `Err` instead of
+        // panicking the meta node.
+        let res = StoredIdGenerator::new(meta_store, "default", None).await;
+        assert!(res.is_err());
+    }
 
     #[tokio::test]
     async fn test_id_generator() -> MetadataModelResult<()> {
         let meta_store = MemStore::default().into_ref();
-        let id_generator = StoredIdGenerator::new(meta_store.clone(), "default", None).await;
+        let id_generator = StoredIdGenerator::new(meta_store.clone(), "default", None).await?;
         let ids = future::join_all((0..10000).map(|_i| {
             let id_generator = &id_generator;
             async move { id_generator.generate().await }
@@ -268,7 +750,7 @@ mod tests {
         .collect::<MetadataModelResult<Vec<_>>>()?;
         assert_eq!(ids, (0..10000).collect::<Vec<_>>());
 
-        let id_generator_two = StoredIdGenerator::new(meta_store.clone(), "default", None).await;
+        let id_generator_two = StoredIdGenerator::new(meta_store.clone(), "default", None).await?;
         let ids = future::join_all((0..10000).map(|_i| {
             let id_generator = &id_generator_two;
             async move { id_generator.generate().await }
@@ -278,7 +760,7 @@ mod tests {
         .collect::<MetadataModelResult<Vec<_>>>()?;
         assert_eq!(ids, (10000..20000).collect::<Vec<_>>());
 
-        let id_generator_three = StoredIdGenerator::new(meta_store.clone(), "table", None).await;
+        let id_generator_three = StoredIdGenerator::new(meta_store.clone(), "table", None).await?;
         let ids = future::join_all((0..10000).map(|_i| {
             let id_generator = &id_generator_three;
             async move { id_generator.generate().await }
@@ -288,7 +770,8 @@ mod tests {
         .collect::<MetadataModelResult<Vec<_>>>()?;
         assert_eq!(ids, (0..10000).collect::<Vec<_>>());
 
-        let actor_id_generator = StoredIdGenerator::new(meta_store.clone(), "actor", Some(1)).await;
+        let actor_id_generator =
+            StoredIdGenerator::new(meta_store.clone(), "actor", Some(1)).await?;
         let ids = future::join_all((0..100).map(|_i| {
             let id_generator = &actor_id_generator;
             async move { id_generator.generate_interval(100).await }
@@ -300,7 +783,7 @@ mod tests {
         let vec_expect = (0..100).map(|e| e * 100 + 1).collect::<Vec<_>>();
         assert_eq!(ids, vec_expect);
 
-        let actor_id_generator_two = StoredIdGenerator::new(meta_store, "actor", None).await;
+        let actor_id_generator_two = StoredIdGenerator::new(meta_store, "actor", None).await?;
         let ids = future::join_all((0..100).map(|_i| {
             let id_generator = &actor_id_generator_two;
             async move { id_generator.generate_interval(10).await }
@@ -318,7 +801,7 @@ mod tests {
     #[tokio::test]
     async fn test_id_generator_manager() -> MetadataModelResult<()> {
         let meta_store = MemStore::default().into_ref();
-        let manager = IdGeneratorManager::new(meta_store.clone()).await;
+        let manager = IdGeneratorManager::new(meta_store.clone()).await?;
         let ids = future::join_all((0..10000).map(|_i| {
             let manager = &manager;
             async move { manager.generate::<{ IdCategory::Test }>().await }
@@ -342,7 +825,7 @@ mod tests {
         let vec_expect = (0..100).map(|e| e * 9999 + 1).collect::<Vec<_>>();
         assert_eq!(ids, vec_expect);
 
-        let manager = IdGeneratorManager::new(meta_store).await;
+        let manager = IdGeneratorManager::new(meta_store).await?;
         let id = manager
             .generate_interval::<{ IdCategory::Actor }>(10)
             .await?;
@@ -350,4 +833,67 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_dynamic_category() -> MetadataModelResult<()> {
+        let meta_store = MemStore::default().into_ref();
+        let manager = IdGeneratorManager::new(meta_store.clone()).await?;
+
+        // Generating from an unregistered category is an error.
+        assert!(manager.generate_dynamic("plugin_object").await.is_err());
+
+        manager.register_category("plugin_object").await?;
+        let ids = future::join_all((0..100).map(|_i| {
+            let manager = &manager;
+            async move { manager.generate_dynamic("plugin_object").await }
+        }))
+        .await
+        .into_iter()
+        .collect::<MetadataModelResult<Vec<_>>>()?;
+        assert_eq!(ids, (0..100).collect::<Vec<_>>());
+
+        // Re-registering is a no-op and doesn't reset the sequence.
+        manager.register_category("plugin_object").await?;
+        assert_eq!(manager.generate_dynamic("plugin_object").await?, 100);
+
+        // The sequence also survives a manager restart.
+        let manager = IdGeneratorManager::new(meta_store).await?;
+        manager.register_category("plugin_object").await?;
+        assert!(manager.generate_dynamic("plugin_object").await? > 100);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_subscription_and_view_categories() -> MetadataModelResult<()> {
+        let meta_store = MemStore::default().into_ref();
+        let manager = IdGeneratorManager::new(meta_store.clone()).await?;
+
+        // Both categories start from their own sequence.
+        assert_eq!(
+            manager.generate::<{ IdCategory::Subscription }>().await?,
+            0
+        );
+        assert_eq!(manager.generate::<{ IdCategory::View }>().await?, 0);
+
+        // Allocations in one category don't advance the others.
+        for _ in 0..10 {
+            manager.generate::<{ IdCategory::Subscription }>().await?;
+        }
+        assert_eq!(manager.generate::<{ IdCategory::View }>().await?, 1);
+        assert_eq!(manager.generate::<{ IdCategory::Table }>().await?, 1);
+        assert_eq!(
+            manager.generate::<{ IdCategory::Subscription }>().await?,
+            11
+        );
+
+        // The sequences survive a manager restart independently.
+        let manager = IdGeneratorManager::new(meta_store).await?;
+        let subscription_id = manager.generate::<{ IdCategory::Subscription }>().await?;
+        let view_id = manager.generate::<{ IdCategory::View }>().await?;
+        assert!(subscription_id > 11);
+        assert!(view_id > 1);
+
+        Ok(())
+    }
 }
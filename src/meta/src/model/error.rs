@@ -33,6 +33,9 @@ pub enum MetadataModelError {
     #[error("Pb decode error: {0}")]
     PbDecode(#[from] prost::DecodeError),
 
+    #[error("id space for category {category} is exhausted")]
+    IdExhausted { category: String },
+
     #[error(transparent)]
     InternalError(
         #[from]
@@ -1139,6 +1139,9 @@ impl fmt::Display for ExplainType {
 pub struct ExplainOptions {
     /// Display additional information regarding the plan.
     pub verbose: bool,
+    /// Additionally display storage-level details, e.g. the physical column order of scanned
+    /// tables. Implies more output than `verbose`.
+    pub verbose_storage: bool,
     // Trace plan transformation of the optimizer step by step
     pub trace: bool,
     // explain's plan type
@@ -1149,6 +1152,7 @@ impl Default for ExplainOptions {
     fn default() -> Self {
         Self {
             verbose: false,
+            verbose_storage: false,
             trace: false,
             explain_type: ExplainType::Physical,
         }
@@ -1165,6 +1169,9 @@ impl fmt::Display for ExplainOptions {
             if self.verbose {
                 option_strs.push("VERBOSE".to_string());
             }
+            if self.verbose_storage {
+                option_strs.push("STORAGE".to_string());
+            }
             if self.trace {
                 option_strs.push("TRACE".to_string());
             }
@@ -492,6 +492,7 @@ define_keywords!(
     STDDEV_POP,
     STDDEV_SAMP,
     STDIN,
+    STORAGE,
     STORED,
     STRING,
     STRUCT,
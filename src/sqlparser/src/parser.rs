@@ -3990,6 +3990,7 @@ impl Parser<'_> {
 
         let explain_key_words = [
             Keyword::VERBOSE,
+            Keyword::STORAGE,
             Keyword::TRACE,
             Keyword::TYPE,
             Keyword::LOGICAL,
@@ -4001,6 +4002,7 @@ impl Parser<'_> {
             let keyword = parser.expect_one_of_keywords(&explain_key_words)?;
             match keyword {
                 Keyword::VERBOSE => options.verbose = parser.parse_optional_boolean(true),
+                Keyword::STORAGE => options.verbose_storage = parser.parse_optional_boolean(true),
                 Keyword::TRACE => options.trace = parser.parse_optional_boolean(true),
                 Keyword::TYPE => {
                     let explain_type = parser.expect_one_of_keywords(&[
@@ -1820,6 +1820,15 @@ fn parse_explain_analyze_with_simple_select() {
             ..Default::default()
         },
     );
+    run_explain_analyze(
+        "EXPLAIN (VERBOSE, STORAGE) SELECT sqrt(id) FROM foo",
+        false,
+        ExplainOptions {
+            verbose: true,
+            verbose_storage: true,
+            ..Default::default()
+        },
+    );
     run_explain_analyze(
         "EXPLAIN ANALYZE SELECT sqrt(id) FROM foo",
         true,
@@ -1858,6 +1867,7 @@ fn parse_explain_analyze_with_simple_select() {
             trace: true,
             verbose: true,
             explain_type: ExplainType::DistSql,
+            ..Default::default()
         },
     );
     run_explain_analyze(
@@ -1867,6 +1877,7 @@ fn parse_explain_analyze_with_simple_select() {
             trace: false,
             verbose: true,
             explain_type: ExplainType::DistSql,
+            ..Default::default()
         },
     );
     run_explain_analyze(
@@ -1876,6 +1887,7 @@ fn parse_explain_analyze_with_simple_select() {
             trace: false,
             verbose: true,
             explain_type: ExplainType::DistSql,
+            ..Default::default()
         },
     );
 }
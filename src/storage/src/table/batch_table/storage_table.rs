@@ -51,7 +51,7 @@ use crate::store::{
     PrefetchOptions, ReadLogOptions, ReadOptions, StateStoreIter, StateStoreIterExt,
     TryWaitEpochOptions,
 };
-use crate::table::merge_sort::merge_sort;
+use crate::table::merge_sort::{merge_sort, merge_sort_desc};
 use crate::table::{ChangeLogRow, KeyedRow, TableDistribution, TableIter};
 use crate::StateStore;
 
@@ -460,7 +460,8 @@ impl<S: PkAndRowStream + Unpin> TableIter for S {
 /// Iterators
 impl<S: StateStore, SD: ValueRowSerde> StorageTableInner<S, SD> {
     /// Get multiple stream item `StorageResult<KeyedRow<Bytes>>` based on the specified vnodes of this table with
-    /// `vnode_hint`, and merge or concat them by given `ordered`.
+    /// `vnode_hint`, and merge or concat them by given `ordered`. With `rev`, each vnode is
+    /// iterated backward and an ordered result is merged in descending key order.
     async fn iter_with_encoded_key_range(
         &self,
         prefix_hint: Option<Bytes>,
@@ -468,6 +469,7 @@ impl<S: StateStore, SD: ValueRowSerde> StorageTableInner<S, SD> {
         wait_epoch: HummockReadEpoch,
         vnode_hint: Option<VirtualNode>,
         ordered: bool,
+        rev: bool,
         prefetch_options: PrefetchOptions,
     ) -> StorageResult<impl Stream<Item = StorageResult<KeyedRow<Bytes>>> + Send> {
         let cache_policy = match (
@@ -510,21 +512,45 @@ impl<S: StateStore, SD: ValueRowSerde> StorageTableInner<S, SD> {
                     true => None,
                     false => Some(Arc::new(self.pk_serializer.clone())),
                 };
-                let iter = StorageTableInnerIterInner::<S, SD>::new(
-                    &self.store,
-                    self.mapping.clone(),
-                    pk_serializer,
-                    self.output_indices.clone(),
-                    self.key_output_indices.clone(),
-                    self.value_output_indices.clone(),
-                    self.output_row_in_key_indices.clone(),
-                    self.row_serde.clone(),
-                    table_key_range,
-                    read_options,
-                    wait_epoch,
-                )
-                .await?
-                .into_stream();
+                self.store
+                    .try_wait_epoch(
+                        wait_epoch,
+                        TryWaitEpochOptions {
+                            table_id: read_options.table_id,
+                        },
+                    )
+                    .await?;
+                let raw_epoch = wait_epoch.get_epoch();
+                #[auto_enum(futures03::Stream)]
+                let iter = if rev {
+                    StorageTableInnerIterInner::new(
+                        self.store
+                            .rev_iter(table_key_range, raw_epoch, read_options)
+                            .await?,
+                        self.mapping.clone(),
+                        pk_serializer,
+                        self.output_indices.clone(),
+                        self.key_output_indices.clone(),
+                        self.value_output_indices.clone(),
+                        self.output_row_in_key_indices.clone(),
+                        self.row_serde.clone(),
+                    )
+                    .into_stream()
+                } else {
+                    StorageTableInnerIterInner::new(
+                        self.store
+                            .iter(table_key_range, raw_epoch, read_options)
+                            .await?,
+                        self.mapping.clone(),
+                        pk_serializer,
+                        self.output_indices.clone(),
+                        self.key_output_indices.clone(),
+                        self.value_output_indices.clone(),
+                        self.output_row_in_key_indices.clone(),
+                        self.row_serde.clone(),
+                    )
+                    .into_stream()
+                };
 
                 Ok::<_, StorageError>(iter)
             }
@@ -540,7 +566,9 @@ impl<S: StateStore, SD: ValueRowSerde> StorageTableInner<S, SD> {
                 futures::stream::iter(iterators.into_iter().map(Box::pin).collect_vec())
                     .flatten_unordered(1024)
             }
-            // Merge all iterators if to preserve order.
+            // Merge all iterators if to preserve order, in descending key order for a reverse
+            // scan since each per-vnode iterator already yields keys descending.
+            _ if rev => merge_sort_desc(iterators.into_iter().map(Box::pin).collect()),
             _ => merge_sort(iterators.into_iter().map(Box::pin).collect()),
         };
 
@@ -604,6 +632,7 @@ impl<S: StateStore, SD: ValueRowSerde> StorageTableInner<S, SD> {
         pk_prefix: impl Row,
         range_bounds: impl RangeBounds<OwnedRow>,
         ordered: bool,
+        rev: bool,
         prefetch_options: PrefetchOptions,
     ) -> StorageResult<impl Stream<Item = StorageResult<KeyedRow<Bytes>>> + Send> {
         let start_key = self.serialize_pk_bound(&pk_prefix, range_bounds.start_bound(), true);
@@ -652,6 +681,7 @@ impl<S: StateStore, SD: ValueRowSerde> StorageTableInner<S, SD> {
             epoch,
             self.distribution.try_compute_vnode_by_pk_prefix(pk_prefix),
             ordered,
+            rev,
             prefetch_options,
         )
         .await
@@ -712,11 +742,12 @@ impl<S: StateStore, SD: ValueRowSerde> StorageTableInner<S, SD> {
         pk_prefix: impl Row,
         range_bounds: impl RangeBounds<OwnedRow>,
         ordered: bool,
+        rev: bool,
         chunk_size: usize,
         prefetch_options: PrefetchOptions,
     ) -> StorageResult<impl Stream<Item = StorageResult<(Vec<ArrayRef>, usize)>> + Send> {
         let iter = self
-            .iter_with_pk_bounds(epoch, pk_prefix, range_bounds, ordered, prefetch_options)
+            .iter_with_pk_bounds(epoch, pk_prefix, range_bounds, ordered, rev, prefetch_options)
             .await?;
 
         Ok(Self::convert_row_stream_to_array_vec_stream(
@@ -736,7 +767,7 @@ impl<S: StateStore, SD: ValueRowSerde> StorageTableInner<S, SD> {
         ordered: bool,
         prefetch_options: PrefetchOptions,
     ) -> StorageResult<impl Stream<Item = StorageResult<KeyedRow<Bytes>>> + Send> {
-        self.iter_with_pk_bounds(epoch, pk_prefix, range_bounds, ordered, prefetch_options)
+        self.iter_with_pk_bounds(epoch, pk_prefix, range_bounds, ordered, false, prefetch_options)
             .await
     }
 
@@ -821,6 +852,36 @@ impl<S: StateStore, SD: ValueRowSerde> StorageTableInner<S, SD> {
                 pk_prefix,
                 range_bounds,
                 ordered,
+                false,
+                chunk_size,
+                prefetch_options,
+            )
+            .await?;
+
+        Ok(iter.map(|item| {
+            let (columns, row_count) = item?;
+            Ok(DataChunk::new(columns, row_count))
+        }))
+    }
+
+    /// Like [`Self::batch_chunk_iter_with_pk_bounds`], but iterates the range backward, yielding
+    /// rows in descending primary-key order. The range bounds keep their forward meaning.
+    pub async fn batch_rev_chunk_iter_with_pk_bounds(
+        &self,
+        epoch: HummockReadEpoch,
+        pk_prefix: impl Row,
+        range_bounds: impl RangeBounds<OwnedRow>,
+        ordered: bool,
+        chunk_size: usize,
+        prefetch_options: PrefetchOptions,
+    ) -> StorageResult<impl Stream<Item = StorageResult<DataChunk>> + Send> {
+        let iter = self
+            .chunk_iter_with_pk_bounds(
+                epoch,
+                pk_prefix,
+                range_bounds,
+                ordered,
+                true,
                 chunk_size,
                 prefetch_options,
             )
@@ -833,10 +894,12 @@ impl<S: StateStore, SD: ValueRowSerde> StorageTableInner<S, SD> {
     }
 }
 
-/// [`StorageTableInnerIterInner`] iterates on the storage table.
-struct StorageTableInnerIterInner<S: StateStore, SD: ValueRowSerde> {
+/// [`StorageTableInnerIterInner`] iterates on the storage table. Generic over the raw
+/// state-store iterator so that the same deserialization logic serves both forward and reverse
+/// scans.
+struct StorageTableInnerIterInner<I: StateStoreIter, SD: ValueRowSerde> {
     /// An iterator that returns raw bytes from storage.
-    iter: S::Iter,
+    iter: I,
 
     mapping: Arc<ColumnMapping>,
 
@@ -857,11 +920,12 @@ struct StorageTableInnerIterInner<S: StateStore, SD: ValueRowSerde> {
     output_row_in_key_indices: Vec<usize>,
 }
 
-impl<S: StateStore, SD: ValueRowSerde> StorageTableInnerIterInner<S, SD> {
-    /// If `wait_epoch` is true, it will wait for the given epoch to be committed before iteration.
+impl<I: StateStoreIter, SD: ValueRowSerde> StorageTableInnerIterInner<I, SD> {
+    /// Wraps an already-opened state-store iterator; the caller is responsible for waiting for
+    /// the epoch to be committed beforehand.
     #[allow(clippy::too_many_arguments)]
-    async fn new(
-        store: &S,
+    fn new(
+        iter: I,
         mapping: Arc<ColumnMapping>,
         pk_serializer: Option<Arc<OrderedRowSerde>>,
         output_indices: Vec<usize>,
@@ -869,21 +933,8 @@ impl<S: StateStore, SD: ValueRowSerde> StorageTableInnerIterInner<S, SD> {
         value_output_indices: Vec<usize>,
         output_row_in_key_indices: Vec<usize>,
         row_deserializer: Arc<SD>,
-        table_key_range: TableKeyRange,
-        read_options: ReadOptions,
-        epoch: HummockReadEpoch,
-    ) -> StorageResult<Self> {
-        let raw_epoch = epoch.get_epoch();
-        store
-            .try_wait_epoch(
-                epoch,
-                TryWaitEpochOptions {
-                    table_id: read_options.table_id,
-                },
-            )
-            .await?;
-        let iter = store.iter(table_key_range, raw_epoch, read_options).await?;
-        let iter = Self {
+    ) -> Self {
+        Self {
             iter,
             mapping,
             row_deserializer,
@@ -892,8 +943,7 @@ impl<S: StateStore, SD: ValueRowSerde> StorageTableInnerIterInner<S, SD> {
             key_output_indices,
             value_output_indices,
             output_row_in_key_indices,
-        };
-        Ok(iter)
+        }
     }
 
     /// Yield a row with its primary key.
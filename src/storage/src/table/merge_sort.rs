@@ -21,7 +21,7 @@ use futures_async_stream::try_stream;
 
 use super::KeyedRow;
 
-struct Node<K: AsRef<[u8]>, S> {
+struct Node<K: AsRef<[u8]>, S, const DESC: bool> {
     stream: S,
 
     /// The next item polled from `stream` previously. Since the `eq` and `cmp` must be synchronous
@@ -29,7 +29,7 @@ struct Node<K: AsRef<[u8]>, S> {
     peeked: KeyedRow<K>,
 }
 
-impl<K: AsRef<[u8]>, S> PartialEq for Node<K, S> {
+impl<K: AsRef<[u8]>, S, const DESC: bool> PartialEq for Node<K, S, DESC> {
     fn eq(&self, other: &Self) -> bool {
         match self.peeked.key() == other.peeked.key() {
             true => unreachable!("primary key from different iters should be unique"),
@@ -37,23 +37,53 @@ impl<K: AsRef<[u8]>, S> PartialEq for Node<K, S> {
         }
     }
 }
-impl<K: AsRef<[u8]>, S> Eq for Node<K, S> {}
+impl<K: AsRef<[u8]>, S, const DESC: bool> Eq for Node<K, S, DESC> {}
 
-impl<K: AsRef<[u8]>, S> PartialOrd for Node<K, S> {
+impl<K: AsRef<[u8]>, S, const DESC: bool> PartialOrd for Node<K, S, DESC> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<K: AsRef<[u8]>, S> Ord for Node<K, S> {
+impl<K: AsRef<[u8]>, S, const DESC: bool> Ord for Node<K, S, DESC> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        // The heap is a max heap, so we need to reverse the order.
-        self.peeked.key().cmp(other.peeked.key()).reverse()
+        let ord = self.peeked.key().cmp(other.peeked.key());
+        // The heap is a max heap: reverse the order for an ascending merge, keep it for a
+        // descending one.
+        if DESC {
+            ord
+        } else {
+            ord.reverse()
+        }
     }
 }
 
+/// Merges streams that are individually sorted by key in ascending order into one ascending
+/// stream.
+pub fn merge_sort<'a, K, E, R>(streams: Vec<R>) -> impl Stream<Item = Result<KeyedRow<K>, E>> + 'a
+where
+    K: AsRef<[u8]> + 'a,
+    E: Error + 'a,
+    R: Stream<Item = Result<KeyedRow<K>, E>> + 'a + Unpin,
+{
+    merge_sort_inner::<K, E, R, false>(streams)
+}
+
+/// Merges streams that are individually sorted by key in descending order into one descending
+/// stream. The counterpart of [`merge_sort`] for reverse iteration.
+pub fn merge_sort_desc<'a, K, E, R>(
+    streams: Vec<R>,
+) -> impl Stream<Item = Result<KeyedRow<K>, E>> + 'a
+where
+    K: AsRef<[u8]> + 'a,
+    E: Error + 'a,
+    R: Stream<Item = Result<KeyedRow<K>, E>> + 'a + Unpin,
+{
+    merge_sort_inner::<K, E, R, true>(streams)
+}
+
 #[try_stream(ok=KeyedRow<K>, error=E)]
-pub async fn merge_sort<'a, K, E, R>(streams: Vec<R>)
+async fn merge_sort_inner<'a, K, E, R, const DESC: bool>(streams: Vec<R>)
 where
     K: AsRef<[u8]> + 'a,
     E: Error + 'a,
@@ -62,7 +92,7 @@ where
     let mut heap = BinaryHeap::new();
     for mut stream in streams {
         if let Some(peeked) = stream.next().await.transpose()? {
-            heap.push(Node { stream, peeked });
+            heap.push(Node::<_, _, DESC> { stream, peeked });
         }
     }
     while let Some(mut node) = heap.peek_mut() {
@@ -129,4 +159,38 @@ mod tests {
             assert_eq!(actual.into_owned_row(), expected.into_owned_row());
         }
     }
+
+    #[tokio::test]
+    async fn test_merge_sort_desc() {
+        let streams = vec![
+            futures::stream::iter(vec![
+                gen_pk_and_row(9),
+                gen_pk_and_row(6),
+                gen_pk_and_row(3),
+                gen_pk_and_row(0),
+            ]),
+            futures::stream::iter(vec![
+                gen_pk_and_row(10),
+                gen_pk_and_row(7),
+                gen_pk_and_row(4),
+                gen_pk_and_row(1),
+            ]),
+            futures::stream::iter(vec![
+                gen_pk_and_row(8),
+                gen_pk_and_row(5),
+                gen_pk_and_row(2),
+            ]),
+            futures::stream::iter(vec![]), // empty stream
+        ];
+
+        let merge_sorted = merge_sort_desc(streams);
+
+        #[for_await]
+        for (i, result) in merge_sorted.enumerate() {
+            let expected = gen_pk_and_row(10 - i as u8).unwrap();
+            let actual = result.unwrap();
+            assert_eq!(actual.key(), expected.key());
+            assert_eq!(actual.into_owned_row(), expected.into_owned_row());
+        }
+    }
 }
@@ -14,20 +14,29 @@
 
 use std::alloc::{Allocator, Global};
 use std::borrow::Borrow;
+use std::collections::HashSet;
 use std::hash::{BuildHasher, Hash};
 use std::ops::{Deref, DerefMut};
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use risingwave_common::lru::{LruCache, RandomState};
-use risingwave_common::metrics::LabelGuardedIntGauge;
-use risingwave_common::sequence::AtomicSequence;
+use risingwave_common::metrics::{LabelGuardedIntCounter, LabelGuardedIntGauge};
+use risingwave_common::sequence::{AtomicSequence, Sequence};
 use risingwave_common_estimate_size::EstimateSize;
 
 use crate::common::metrics::MetricsInfo;
 
 const REPORT_SIZE_EVERY_N_KB_CHANGE: usize = 4096;
 
+/// Overrides [`EstimateSize`] based entry size accounting. See
+/// [`ManagedLruCache::unbounded_with_size_fn`].
+type SizeFn<K, V> = Arc<dyn Fn(&K, &V) -> usize + Send + Sync>;
+
+/// Callback fired when the heap size crosses the soft cap. See
+/// [`ManagedLruCache::set_pressure_callback`].
+type PressureCallback<K, V, S, A> = Box<dyn FnMut(&mut ManagedLruCache<K, V, S, A>) + Send>;
+
 /// The managed cache is a lru cache that bounds the memory usage by epoch.
 /// Should be used with `MemoryManager`.
 pub struct ManagedLruCache<K, V, S = RandomState, A = Global>
@@ -42,10 +51,28 @@ where
     /// `watermark_sequence` should only be updated by `MemoryManager`.
     watermark_sequence: Arc<AtomicSequence>,
 
+    /// Keys whose entries must survive watermark-driven eviction. See [`Self::pin`].
+    pinned: HashSet<K>,
+
     // Metrics info
     _metrics_info: MetricsInfo,
 
     reporter: HeapSizeReporter,
+
+    /// Cumulative number of entries evicted from this cache.
+    evicted_entry_count: LabelGuardedIntCounter<3>,
+    /// Cumulative estimated bytes freed by evictions from this cache.
+    evicted_bytes: LabelGuardedIntCounter<3>,
+
+    /// When set, used instead of [`EstimateSize`] to charge entries.
+    size_fn: Option<SizeFn<K, V>>,
+
+    /// Soft cap in bytes above which [`Self::put`] fires the pressure callback.
+    soft_cap: Option<usize>,
+    /// Whether the heap size was above the soft cap after the last `put`, to fire the
+    /// callback only once per upward crossing.
+    above_soft_cap: bool,
+    pressure_callback: Option<PressureCallback<K, V, S, A>>,
 }
 
 impl<K, V, S, A> ManagedLruCache<K, V, S, A>
@@ -73,34 +100,206 @@ where
             ]);
         memory_usage_metrics.set(0.into());
 
+        let evicted_entry_count = metrics_info
+            .metrics
+            .lru_evicted_entry_count
+            .with_guarded_label_values(&[
+                &metrics_info.actor_id,
+                &metrics_info.table_id,
+                &metrics_info.desc,
+            ]);
+        let evicted_bytes = metrics_info
+            .metrics
+            .lru_evicted_bytes
+            .with_guarded_label_values(&[
+                &metrics_info.actor_id,
+                &metrics_info.table_id,
+                &metrics_info.desc,
+            ]);
+
         let reporter = HeapSizeReporter::new(memory_usage_metrics, 0, 0);
 
         Self {
             inner,
             watermark_sequence,
+            pinned: HashSet::new(),
             _metrics_info: metrics_info,
             reporter,
+            evicted_entry_count,
+            evicted_bytes,
+            size_fn: None,
+            soft_cap: None,
+            above_soft_cap: false,
+            pressure_callback: None,
         }
     }
 
+    /// Registers a callback fired when the estimated heap size exceeds `soft_cap` bytes during
+    /// [`Self::put`] (and thus [`Self::push`]), to react to memory pressure faster than the
+    /// periodic `MemoryManager` sweep.
+    ///
+    /// The callback receives the cache itself, with no internal borrow held, so it may
+    /// immediately [`Self::evict`] or otherwise shed load. It fires exactly once per upward
+    /// crossing: once the size is above the cap no further calls are made until it drops back
+    /// to or below the cap (whether through the callback's own eviction or a later sweep).
+    pub fn set_pressure_callback(
+        &mut self,
+        soft_cap: usize,
+        callback: impl FnMut(&mut Self) + Send + 'static,
+    ) {
+        self.soft_cap = Some(soft_cap);
+        self.above_soft_cap = self.reporter.heap_size > soft_cap;
+        self.pressure_callback = Some(Box::new(callback));
+    }
+
+    /// Fires the pressure callback if the heap size has just crossed the soft cap. Called at
+    /// the end of `put`, after all size accounting, with no internal borrow outstanding.
+    fn maybe_fire_pressure_callback(&mut self) {
+        let Some(soft_cap) = self.soft_cap else {
+            return;
+        };
+        if self.reporter.heap_size <= soft_cap {
+            self.above_soft_cap = false;
+            return;
+        }
+        if self.above_soft_cap {
+            return;
+        }
+        self.above_soft_cap = true;
+        if let Some(mut callback) = self.pressure_callback.take() {
+            callback(self);
+            self.pressure_callback = Some(callback);
+            // Re-arm right away if the callback shed enough load.
+            self.above_soft_cap = self.reporter.heap_size > soft_cap;
+        }
+    }
+
+    fn entry_size(&self, k: &K, v: &V) -> usize {
+        match &self.size_fn {
+            Some(f) => f(k, v),
+            None => k.estimated_size() + v.estimated_size(),
+        }
+    }
+
+    /// Pins `k` so watermark-driven eviction ([`Self::evict`], [`Self::evict_all_below`] and
+    /// [`Self::evict_capped`]) keeps its entry resident even once its sequence falls below the
+    /// watermark, e.g. for hot join keys that must not be dropped between touches. The entry's
+    /// memory stays charged to the heap size while pinned. Pinning a key with no entry is a
+    /// no-op.
+    ///
+    /// Pinning does not shield the entry from the explicit footprint operations
+    /// [`Self::shrink_to`] and [`Self::clear`].
+    pub fn pin(&mut self, k: &K)
+    where
+        K: Clone,
+    {
+        if self.inner.contains(k) {
+            self.pinned.insert(k.clone());
+        }
+    }
+
+    /// Removes the pin from `k`, if any. The entry keeps its original sequence through pinned
+    /// sweeps, so it becomes evictable again on the next sweep whose watermark is past it.
+    pub fn unpin(&mut self, k: &K) {
+        self.pinned.remove(k);
+    }
+
     /// Evict epochs lower than the watermark
     pub fn evict(&mut self) {
         let evict_start = std::time::Instant::now();
         let sequence = self.watermark_sequence.load(Ordering::Relaxed);
-        while let Some((key, value, _)) = self.inner.pop_with_sequence(sequence) {
-            let charge = key.estimated_size() + value.estimated_size();
+        self.evict_all_below(sequence);
+        println!("MICROBENCH:EVICT:{:.2?}", evict_start.elapsed());
+    }
+
+    /// Evicts all entries whose sequence is strictly below `sequence`, with the same size and
+    /// metric accounting as [`Self::evict`]. Unlike `evict`, the boundary is passed explicitly
+    /// instead of being loaded from the shared watermark sequence, so it can be driven by a
+    /// centralized memory manager or a test.
+    pub fn evict_all_below(&mut self, sequence: Sequence) {
+        let mut pinned_entries = vec![];
+        while let Some((key, value, entry_sequence)) = self.inner.pop_with_sequence(sequence) {
+            if self.pinned.contains(&key) {
+                pinned_entries.push((key, value, entry_sequence));
+                continue;
+            }
+            let charge = self.entry_size(&key, &value);
             self.reporter.dec(charge);
+            self.evicted_entry_count.inc();
+            self.evicted_bytes.inc_by(charge as _);
+        }
+        // Re-attach pinned survivors under their original sequence: their memory stays charged,
+        // and keeping the age means an unpinned entry is evictable on the very next sweep.
+        for (key, value, entry_sequence) in pinned_entries {
+            self.inner.put_with_sequence(key, value, entry_sequence);
         }
-        println!("MICROBENCH:EVICT:{:.2?}", evict_start.elapsed());
+    }
+
+    /// Like [`Self::evict`], but pops at most `max_evictions` entries, so that a huge watermark
+    /// jump does not stall the caller (e.g. the barrier-processing loop). Returns whether more
+    /// evictable entries remain; a follow-up call continues where this one left off.
+    pub fn evict_capped(&mut self, max_evictions: usize) -> bool {
+        let sequence = self.watermark_sequence.load(Ordering::Relaxed);
+        let mut pinned_entries = vec![];
+        let mut evicted = 0;
+        let more = loop {
+            if evicted >= max_evictions {
+                // Popped pinned entries are set aside below, so the head here is a genuine
+                // eviction candidate.
+                break matches!(self.inner.peek_lru_sequence(), Some(s) if s < sequence);
+            }
+            let Some((key, value, entry_sequence)) = self.inner.pop_with_sequence(sequence) else {
+                break false;
+            };
+            if self.pinned.contains(&key) {
+                // Skipping a pinned entry is not an eviction, so it does not count towards
+                // the cap.
+                pinned_entries.push((key, value, entry_sequence));
+                continue;
+            }
+            let charge = self.entry_size(&key, &value);
+            self.reporter.dec(charge);
+            self.evicted_entry_count.inc();
+            self.evicted_bytes.inc_by(charge as _);
+            evicted += 1;
+        };
+        for (key, value, entry_sequence) in pinned_entries {
+            self.inner.put_with_sequence(key, value, entry_sequence);
+        }
+        more
+    }
+
+    /// Evicts true-LRU entries — regardless of their sequence — until at most `target_entries`
+    /// remain, then releases the underlying table's spare capacity back to the allocator. This
+    /// is a memory-footprint operation, distinct from the watermark-driven [`Self::evict`]:
+    /// after a load spike the table keeps its grown capacity even once entries are evicted, and
+    /// this hands that reserved memory back. The freed bytes count towards the eviction
+    /// counters, and the memory usage gauge is updated immediately.
+    pub fn shrink_to(&mut self, target_entries: usize) {
+        while self.inner.len() > target_entries {
+            let Some((key, value, _)) = self.inner.pop_with_sequence(Sequence::MAX) else {
+                break;
+            };
+            let charge = self.entry_size(&key, &value);
+            self.reporter.dec(charge);
+            self.evicted_entry_count.inc();
+            self.evicted_bytes.inc_by(charge as _);
+        }
+        self.inner.shrink_to_fit();
+        // A deliberate footprint release should be visible right away, not after the
+        // hysteresis threshold.
+        self.reporter.force_report();
     }
 
     pub fn put(&mut self, k: K, v: V) -> Option<V> {
-        let key_size = k.estimated_size();
-        self.reporter.inc(key_size + v.estimated_size());
+        let new_charge = self.entry_size(&k, &v);
+        let old_charge = self.inner.peek(&k).map(|old_val| self.entry_size(&k, old_val));
         let old_val = self.inner.put(k, v);
-        if let Some(old_val) = &old_val {
-            self.reporter.dec(key_size + old_val.estimated_size());
+        self.reporter.inc(new_charge);
+        if let Some(old_charge) = old_charge {
+            self.reporter.dec(old_charge);
         }
+        self.maybe_fire_pressure_callback();
         old_val
     }
 
@@ -109,9 +308,132 @@ where
         self.put(k, v)
     }
 
-    pub fn get_mut(&mut self, k: &K) -> Option<MutGuard<'_, V>> {
+    /// Like [`Self::put`], but inserts the entry at the caller-provided sequence instead of
+    /// the current one. Meant for rehydrating entries that were originally cached at an older
+    /// epoch: keeping their original sequence makes watermark-driven eviction treat them as
+    /// the old entries they are, instead of as freshly touched ones.
+    pub fn put_with_sequence(&mut self, k: K, v: V, sequence: Sequence) -> Option<V> {
+        let new_charge = self.entry_size(&k, &v);
+        let old_charge = self.inner.peek(&k).map(|old_val| self.entry_size(&k, old_val));
+        let old_val = self.inner.put_with_sequence(k, v, sequence);
+        self.reporter.inc(new_charge);
+        if let Some(old_charge) = old_charge {
+            self.reporter.dec(old_charge);
+        }
+        self.maybe_fire_pressure_callback();
+        old_val
+    }
+
+    /// [`Self::push`] counterpart of [`Self::put_with_sequence`].
+    pub fn push_with_sequence(&mut self, k: K, v: V, sequence: Sequence) -> Option<V> {
+        self.put_with_sequence(k, v, sequence)
+    }
+
+    /// Insert all items from `iter`, accumulating the size delta and reporting memory usage
+    /// once at the end instead of per entry. Useful when rehydrating many entries at once.
+    pub fn extend(&mut self, iter: impl IntoIterator<Item = (K, V)>) {
+        self.reporter.apply(|heap_size| {
+            for (k, v) in iter {
+                let new_charge = match &self.size_fn {
+                    Some(f) => f(&k, &v),
+                    None => k.estimated_size() + v.estimated_size(),
+                };
+                let old_charge = self.inner.peek(&k).map(|old_val| match &self.size_fn {
+                    Some(f) => f(&k, old_val),
+                    None => k.estimated_size() + old_val.estimated_size(),
+                });
+                self.inner.put(k, v);
+                *heap_size = heap_size.saturating_add(new_charge);
+                if let Some(old_charge) = old_charge {
+                    *heap_size = heap_size.saturating_sub(old_charge);
+                }
+            }
+        });
+    }
+
+    /// Collects all entries from LRU to MRU without updating recency, e.g. for persisting the
+    /// cache contents across recovery. The order carries the recency information: feeding it
+    /// back to [`Self::restore`] re-seeds the relative LRU order.
+    pub fn snapshot(&self) -> Vec<(K, V)>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        self.inner
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect()
+    }
+
+    /// Repopulates the cache from a [`Self::snapshot`], replacing any current contents.
+    ///
+    /// Entries are inserted in iteration (LRU to MRU) order and receive fresh, ascending
+    /// sequences, so their relative eviction order is preserved even though the absolute
+    /// sequences are not; the watermark-based eviction only compares against the current
+    /// sequencer anyway. Sizes are re-measured on insertion and memory usage is reported once
+    /// at the end, bypassing the usual hysteresis.
+    pub fn restore(&mut self, iter: impl IntoIterator<Item = (K, V)>) {
+        self.inner.clear();
+        self.reporter.reset();
+        self.extend(iter);
+        self.reporter.force_report();
+    }
+
+    /// Iterates over all values from LRU to MRU, without updating recency.
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.inner.iter().map(|(_, v)| v)
+    }
+
+    /// Sweeps all entries from LRU to MRU, handing each value to `f` for in-place mutation
+    /// (e.g. decaying counters). Recency is not updated. Every entry is re-measured around the
+    /// mutation — the same accounting as [`Self::get_mut`] — but the accumulated size delta is
+    /// reported once at the end of the sweep instead of through a per-entry [`MutGuard`].
+    pub fn for_each_mut(&mut self, mut f: impl FnMut(&K, &mut V)) {
+        let inner = &mut self.inner;
+        let size_fn = &self.size_fn;
+        self.reporter.apply(|heap_size| {
+            for (k, v) in inner.iter_mut() {
+                let old_charge = match size_fn {
+                    Some(sf) => sf(k, v),
+                    None => k.estimated_size() + v.estimated_size(),
+                };
+                f(k, v);
+                let new_charge = match size_fn {
+                    Some(sf) => sf(k, v),
+                    None => k.estimated_size() + v.estimated_size(),
+                };
+                *heap_size = heap_size
+                    .saturating_add(new_charge)
+                    .saturating_sub(old_charge);
+            }
+        });
+    }
+
+    pub fn get_mut<'a>(&'a mut self, k: &'a K) -> Option<MutGuard<'a, V>> {
+        let size_fn = self.size_fn.clone();
         let v = self.inner.get_mut(k);
-        v.map(|inner| MutGuard::new(inner, &mut self.reporter))
+        v.map(|inner| {
+            let compute_size = size_fn
+                .map(|f| Box::new(move |v: &V| f(k, v)) as Box<dyn Fn(&V) -> usize + 'a>);
+            MutGuard::new(inner, &mut self.reporter, compute_size)
+        })
+    }
+
+    /// Like [`Self::get_mut`], but returns a plain mutable reference instead of a [`MutGuard`],
+    /// skipping the drop-time size recompute. Intended for values whose charged size never
+    /// changes under mutation (e.g. fixed-size counters, or caches using a constant
+    /// [`Self::unbounded_with_size_fn`] charge).
+    ///
+    /// Misuse caveat: mutating the value in a way that changes its estimated size through this
+    /// reference silently corrupts the size accounting — the delta is never observed, so the
+    /// reported memory usage drifts until the entry is evicted or overwritten. If the size may
+    /// change, use [`Self::get_mut`].
+    pub fn get_mut_const_size<Q>(&mut self, k: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.inner.get_mut(k)
     }
 
     pub fn get<Q>(&mut self, k: &Q) -> Option<&V>
@@ -130,9 +452,34 @@ where
         self.inner.peek(k)
     }
 
-    pub fn peek_mut(&mut self, k: &K) -> Option<MutGuard<'_, V>> {
+    /// Peek the entry at the LRU end without updating its recency.
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        self.inner.peek_lru()
+    }
+
+    /// Peek the entry at the MRU end without updating its recency.
+    pub fn peek_mru(&self) -> Option<(&K, &V)> {
+        self.inner.peek_mru()
+    }
+
+    /// Like [`Self::get_mut`], but without bumping the entry's recency: the full size
+    /// accounting of the returned [`MutGuard`] still applies, while the entry keeps its place
+    /// in the eviction order. Intended for maintenance sweeps that mutate values without
+    /// wanting to shield them from the very eviction being prepared — a plain [`Self::get_mut`]
+    /// there would keep every swept entry artificially hot. This is [`Self::peek_mut`] under a
+    /// name that states the intent at the call site.
+    pub fn get_mut_no_update<'a>(&'a mut self, k: &'a K) -> Option<MutGuard<'a, V>> {
+        self.peek_mut(k)
+    }
+
+    pub fn peek_mut<'a>(&'a mut self, k: &'a K) -> Option<MutGuard<'a, V>> {
+        let size_fn = self.size_fn.clone();
         let v = self.inner.peek_mut(k);
-        v.map(|inner| MutGuard::new(inner, &mut self.reporter))
+        v.map(|inner| {
+            let compute_size = size_fn
+                .map(|f| Box::new(move |v: &V| f(k, v)) as Box<dyn Fn(&V) -> usize + 'a>);
+            MutGuard::new(inner, &mut self.reporter, compute_size)
+        })
     }
 
     pub fn contains<Q>(&self, k: &Q) -> bool
@@ -153,6 +500,20 @@ where
 
     pub fn clear(&mut self) {
         self.inner.clear();
+        self.pinned.clear();
+        self.reporter.reset();
+    }
+
+    /// Estimated heap size of all entries currently in the cache.
+    pub fn heap_size(&self) -> usize {
+        self.reporter.heap_size
+    }
+
+    /// Sets the memory usage gauge to the current [`Self::heap_size`] immediately, bypassing
+    /// the `REPORT_SIZE_EVERY_N_KB_CHANGE` hysteresis. Useful when the exposed gauge must be
+    /// deterministic on demand, e.g. for the memory manager or in tests.
+    pub fn flush_memory_metric(&mut self) {
+        self.reporter.force_report();
     }
 }
 
@@ -164,6 +525,33 @@ where
     pub fn unbounded(watermark_sequence: Arc<AtomicSequence>, metrics_info: MetricsInfo) -> Self {
         Self::unbounded_with_hasher(watermark_sequence, metrics_info, RandomState::default())
     }
+
+    /// Same as [`Self::unbounded`], but charges each entry with `size_fn` instead of
+    /// [`EstimateSize`]. Useful when the default estimate would double-count bytes shared
+    /// across entries (e.g. values holding an `Arc` to shared data).
+    pub fn unbounded_with_size_fn(
+        watermark_sequence: Arc<AtomicSequence>,
+        metrics_info: MetricsInfo,
+        size_fn: impl Fn(&K, &V) -> usize + Send + Sync + 'static,
+    ) -> Self {
+        let mut this = Self::unbounded(watermark_sequence, metrics_info);
+        this.size_fn = Some(Arc::new(size_fn));
+        this
+    }
+
+    /// Same as [`Self::unbounded`], but treats values as weakly sized: only the key's estimated
+    /// size is charged per entry, the value counts for nothing. For values like `Arc`s to data
+    /// owned (and already accounted for) elsewhere, charging their full `estimated_size` here
+    /// would double-count that memory and trigger over-eager eviction.
+    ///
+    /// All entry paths (`put`/`push`/`extend`/eviction/`get_mut`) share this accounting, since
+    /// it is just a fixed [`Self::unbounded_with_size_fn`] charge.
+    pub fn unbounded_weakly_sized(
+        watermark_sequence: Arc<AtomicSequence>,
+        metrics_info: MetricsInfo,
+    ) -> Self {
+        Self::unbounded_with_size_fn(watermark_sequence, metrics_info, |k, _v| k.estimated_size())
+    }
 }
 
 impl<K, V, S> ManagedLruCache<K, V, S>
@@ -185,22 +573,35 @@ pub struct MutGuard<'a, V: EstimateSize> {
     inner: &'a mut V,
     reporter: &'a mut HeapSizeReporter,
     old_value_size: usize,
+    /// When set, used instead of [`EstimateSize`] to re-measure the value on drop.
+    compute_size: Option<Box<dyn Fn(&V) -> usize + 'a>>,
 }
 
 impl<'a, V: EstimateSize> MutGuard<'a, V> {
-    fn new(inner: &'a mut V, reporter: &'a mut HeapSizeReporter) -> Self {
-        let old_value_size = inner.estimated_size();
+    fn new(
+        inner: &'a mut V,
+        reporter: &'a mut HeapSizeReporter,
+        compute_size: Option<Box<dyn Fn(&V) -> usize + 'a>>,
+    ) -> Self {
+        let old_value_size = match &compute_size {
+            Some(f) => f(inner),
+            None => inner.estimated_size(),
+        };
         Self {
             inner,
             reporter,
             old_value_size,
+            compute_size,
         }
     }
 }
 
 impl<'a, V: EstimateSize> Drop for MutGuard<'a, V> {
     fn drop(&mut self) {
-        let new_value_size = self.inner.estimated_size();
+        let new_value_size = match &self.compute_size {
+            Some(f) => f(self.inner),
+            None => self.inner.estimated_size(),
+        };
         if new_value_size != self.old_value_size {
             self.reporter.apply(|size| {
                 *size = size
@@ -262,6 +663,21 @@ impl HeapSizeReporter {
         self.try_report();
     }
 
+    /// Reset the tracked heap size to zero and report it immediately, bypassing the
+    /// `REPORT_SIZE_EVERY_N_KB_CHANGE` hysteresis.
+    fn reset(&mut self) {
+        self.heap_size = 0;
+        self.last_reported = 0;
+        self.metrics.set(0);
+    }
+
+    /// Report the current heap size immediately, bypassing the
+    /// `REPORT_SIZE_EVERY_N_KB_CHANGE` hysteresis.
+    fn force_report(&mut self) {
+        self.metrics.set(self.heap_size as _);
+        self.last_reported = self.heap_size;
+    }
+
     fn try_report(&mut self) -> bool {
         if self.heap_size.abs_diff(self.last_reported) >= REPORT_SIZE_EVERY_N_KB_CHANGE << 10 {
             self.metrics.set(self.heap_size as _);
@@ -278,3 +694,568 @@ impl Drop for HeapSizeReporter {
         self.metrics.set(0);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clear_resets_size_accounting() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let mut cache: ManagedLruCache<i32, String> =
+            ManagedLruCache::unbounded(watermark_sequence, MetricsInfo::for_test());
+
+        for i in 0..1024 {
+            cache.put(i, "x".repeat(8192));
+        }
+        assert!(cache.heap_size() > 0);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.heap_size(), 0);
+        assert_eq!(cache.reporter.metrics.get(), 0);
+    }
+
+    #[test]
+    fn test_flush_memory_metric() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let mut cache: ManagedLruCache<i32, String> =
+            ManagedLruCache::unbounded(watermark_sequence, MetricsInfo::for_test());
+
+        // A sub-threshold change is tracked but not reported to the gauge yet.
+        cache.put(1, "x".repeat(1024));
+        assert!(cache.heap_size() > 0);
+        assert!(cache.heap_size() < REPORT_SIZE_EVERY_N_KB_CHANGE << 10);
+        assert_eq!(cache.reporter.metrics.get(), 0);
+
+        cache.flush_memory_metric();
+        assert_eq!(cache.reporter.metrics.get(), cache.heap_size() as i64);
+
+        // Another sub-threshold change: the gauge stays at the last flushed value until the
+        // next flush.
+        let flushed = cache.reporter.metrics.get();
+        cache.put(2, "y".repeat(2048));
+        assert_eq!(cache.reporter.metrics.get(), flushed);
+        cache.flush_memory_metric();
+        assert_eq!(cache.reporter.metrics.get(), cache.heap_size() as i64);
+    }
+
+    #[test]
+    fn test_evict_all_below() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let mut cache: ManagedLruCache<i32, String> =
+            ManagedLruCache::unbounded(watermark_sequence, MetricsInfo::for_test());
+
+        // Insert entries in three batches ("epochs"). After each batch, put a marker into a
+        // plain `LruCache`: sequences on the same thread are strictly ascending, so each
+        // marker's sequence separates one batch from the next.
+        let mut markers = LruCache::<i32, ()>::unbounded();
+        for epoch in 0..3 {
+            for i in 0..3 {
+                cache.put(epoch * 3 + i, "x".repeat(64));
+            }
+            markers.put(epoch, ());
+        }
+        let mut boundaries = vec![];
+        while let Some((_, _, sequence)) = markers.pop_with_sequence(Sequence::MAX) {
+            boundaries.push(sequence);
+        }
+
+        // Evicting below the first batch is a no-op.
+        let heap_size_full = cache.heap_size();
+        cache.evict_all_below(cache.inner.peek_lru_sequence().unwrap());
+        assert_eq!(cache.len(), 9);
+
+        // Evicting below the chosen boundary drops the first two batches, with proportional
+        // size accounting (all entries have the same size).
+        cache.evict_all_below(boundaries[1]);
+        assert_eq!(cache.len(), 3);
+        assert!((6..9).all(|k| cache.contains(&k)));
+        assert_eq!(cache.heap_size() * 3, heap_size_full);
+
+        // Evicting below the last boundary drains the cache.
+        cache.evict_all_below(boundaries[2]);
+        assert!(cache.is_empty());
+        assert_eq!(cache.heap_size(), 0);
+        assert_eq!(cache.reporter.metrics.get(), 0);
+    }
+
+    #[test]
+    fn test_put_with_sequence_mixed_epochs() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let mut cache: ManagedLruCache<i32, String> =
+            ManagedLruCache::unbounded(watermark_sequence, MetricsInfo::for_test());
+
+        // Rehydrate entries from three "epochs", deliberately out of order.
+        cache.put_with_sequence(3, "x".repeat(64), 300);
+        cache.put_with_sequence(1, "x".repeat(64), 100);
+        cache.put_with_sequence(2, "x".repeat(64), 200);
+        let heap_size_full = cache.heap_size();
+
+        // Evicting below the middle epoch removes exactly the older entry, regardless of the
+        // insertion order above, with proportional size accounting.
+        cache.evict_all_below(200);
+        assert_eq!(cache.len(), 2);
+        assert!(!cache.contains(&1));
+        assert!(cache.contains(&2) && cache.contains(&3));
+        assert_eq!(cache.heap_size() * 3, heap_size_full * 2);
+
+        // The rest goes once the watermark passes them.
+        cache.evict_all_below(301);
+        assert!(cache.is_empty());
+        assert_eq!(cache.heap_size(), 0);
+    }
+
+    #[test]
+    fn test_evict_capped() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let mut cache: ManagedLruCache<i32, String> =
+            ManagedLruCache::unbounded(watermark_sequence.clone(), MetricsInfo::for_test());
+
+        for i in 0..10 {
+            cache.put(i, "x".repeat(64));
+        }
+        // Make every entry evictable.
+        watermark_sequence.store(Sequence::MAX, Ordering::Relaxed);
+
+        // The cap is honored and more work is reported to remain.
+        assert!(cache.evict_capped(4));
+        assert_eq!(cache.len(), 6);
+        assert!(cache.evict_capped(4));
+        assert_eq!(cache.len(), 2);
+
+        // The last batch drains the cache, leaving the size accounting at zero.
+        assert!(!cache.evict_capped(4));
+        assert!(cache.is_empty());
+        assert_eq!(cache.heap_size(), 0);
+        assert_eq!(cache.reporter.metrics.get(), 0);
+    }
+
+    #[test]
+    fn test_pin_survives_eviction_unpin_then_evict() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let mut cache: ManagedLruCache<i32, String> =
+            ManagedLruCache::unbounded(watermark_sequence.clone(), MetricsInfo::for_test());
+
+        for i in 0..4 {
+            cache.put(i, "x".repeat(64));
+        }
+        cache.pin(&1);
+        let entry_size = cache.heap_size() / 4;
+
+        // Everything is below the watermark, but the pinned entry survives the sweep with its
+        // memory still charged; only the other three count as evicted.
+        watermark_sequence.store(Sequence::MAX, Ordering::Relaxed);
+        cache.evict();
+        assert_eq!(cache.len(), 1);
+        assert!(cache.contains(&1));
+        assert_eq!(cache.heap_size(), entry_size);
+        assert_eq!(cache.evicted_entry_count.get(), 3);
+
+        // Repeated sweeps keep skipping it.
+        cache.evict();
+        assert!(cache.contains(&1));
+
+        // Once unpinned, the next sweep takes it.
+        cache.unpin(&1);
+        cache.evict();
+        assert!(cache.is_empty());
+        assert_eq!(cache.heap_size(), 0);
+        assert_eq!(cache.evicted_entry_count.get(), 4);
+    }
+
+    #[test]
+    fn test_pinned_entry_keeps_original_age() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let mut cache: ManagedLruCache<i32, String> =
+            ManagedLruCache::unbounded(watermark_sequence, MetricsInfo::for_test());
+
+        cache.put_with_sequence(1, "x".repeat(64), 100);
+        cache.put_with_sequence(2, "x".repeat(64), 200);
+        cache.pin(&1);
+
+        // The sweep drops the unpinned entry and re-attaches the pinned one.
+        cache.evict_all_below(300);
+        assert!(cache.contains(&1));
+        assert!(!cache.contains(&2));
+
+        // Surviving a sweep did not rejuvenate the pinned entry: once unpinned, a watermark
+        // past its original sequence takes it while the newer entry stays.
+        cache.put_with_sequence(3, "x".repeat(64), 400);
+        cache.unpin(&1);
+        cache.evict_all_below(150);
+        assert!(!cache.contains(&1));
+        assert!(cache.contains(&3));
+    }
+
+    #[test]
+    fn test_evict_capped_skips_pinned() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let mut cache: ManagedLruCache<i32, String> =
+            ManagedLruCache::unbounded(watermark_sequence.clone(), MetricsInfo::for_test());
+
+        for i in 0..6 {
+            cache.put(i, "x".repeat(64));
+        }
+        cache.pin(&0);
+        cache.pin(&3);
+        watermark_sequence.store(Sequence::MAX, Ordering::Relaxed);
+
+        // Pinned entries do not count towards the cap: two real evictions happen and the
+        // pinned survivors stay resident.
+        assert!(cache.evict_capped(2));
+        assert_eq!(cache.evicted_entry_count.get(), 2);
+        assert!(cache.contains(&0) && cache.contains(&3));
+
+        // Draining the rest leaves exactly the pinned entries and reports no more work.
+        assert!(!cache.evict_capped(usize::MAX));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.contains(&0) && cache.contains(&3));
+        assert_eq!(cache.evicted_entry_count.get(), 4);
+    }
+
+    #[test]
+    fn test_eviction_counters() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        // A flat charge per entry makes the expected evicted bytes exact.
+        let mut cache: ManagedLruCache<i32, String> = ManagedLruCache::unbounded_with_size_fn(
+            watermark_sequence.clone(),
+            MetricsInfo::for_test(),
+            |_k, _v| 1000,
+        );
+
+        for i in 0..10 {
+            cache.put(i, "x".repeat(64));
+        }
+        assert_eq!(cache.evicted_entry_count.get(), 0);
+        assert_eq!(cache.evicted_bytes.get(), 0);
+
+        watermark_sequence.store(Sequence::MAX, Ordering::Relaxed);
+        cache.evict_capped(4);
+        assert_eq!(cache.evicted_entry_count.get(), 4);
+        assert_eq!(cache.evicted_bytes.get(), 4000);
+
+        cache.evict();
+        assert!(cache.is_empty());
+        assert_eq!(cache.evicted_entry_count.get(), 10);
+        assert_eq!(cache.evicted_bytes.get(), 10_000);
+    }
+
+    #[test]
+    fn test_extend_matches_put_loop() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let mut put_cache: ManagedLruCache<i32, String> =
+            ManagedLruCache::unbounded(watermark_sequence.clone(), MetricsInfo::for_test());
+        let mut extend_cache: ManagedLruCache<i32, String> =
+            ManagedLruCache::unbounded(watermark_sequence, MetricsInfo::for_test());
+
+        // Pre-existing entries, some of which are replaced by the batch below.
+        for cache in [&mut put_cache, &mut extend_cache] {
+            cache.put(1, "old".repeat(100));
+            cache.put(2, "old".repeat(200));
+        }
+
+        let items: Vec<(i32, String)> = (0..64).map(|i| (i % 8, "x".repeat(i as usize))).collect();
+        for (k, v) in items.clone() {
+            put_cache.put(k, v);
+        }
+        extend_cache.extend(items);
+
+        assert_eq!(extend_cache.len(), put_cache.len());
+        assert_eq!(extend_cache.heap_size(), put_cache.heap_size());
+        for k in 0..8 {
+            assert_eq!(extend_cache.peek(&k), put_cache.peek(&k));
+        }
+    }
+
+    #[test]
+    fn test_for_each_mut_size_accounting() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let mut cache: ManagedLruCache<i32, String> =
+            ManagedLruCache::unbounded(watermark_sequence, MetricsInfo::for_test());
+
+        for i in 0..8 {
+            cache.put(i, "x".repeat(64));
+        }
+        cache.get(&0);
+        let heap_size_before = cache.heap_size();
+
+        cache.for_each_mut(|_k, v| v.push_str(&"y".repeat(256)));
+
+        // The reported size reflects the grown values exactly.
+        assert!(cache.heap_size() > heap_size_before);
+        let expected: usize = cache.values().map(|v| v.estimated_size()).sum();
+        assert_eq!(cache.heap_size(), expected);
+
+        // The sweep does not update recency: 1 is still the eviction candidate after the
+        // `get(&0)` above.
+        assert_eq!(cache.peek_lru().map(|(k, _)| *k), Some(1));
+
+        // Shrinking values is accounted for as well.
+        cache.for_each_mut(|_k, v| {
+            *v = "z".to_string();
+        });
+        let expected: usize = cache.values().map(|v| v.estimated_size()).sum();
+        assert_eq!(cache.heap_size(), expected);
+    }
+
+    #[test]
+    fn test_snapshot_restore_round_trip() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let mut cache: ManagedLruCache<i32, String> =
+            ManagedLruCache::unbounded(watermark_sequence.clone(), MetricsInfo::for_test());
+
+        for i in 0..8 {
+            cache.put(i, "x".repeat(64));
+        }
+        // Shuffle recency so the snapshot order is not just insertion order.
+        cache.get(&0);
+        cache.get(&3);
+
+        let snapshot = cache.snapshot();
+        assert_eq!(snapshot.len(), 8);
+        // Snapshotting does not disturb the cache itself.
+        assert_eq!(cache.len(), 8);
+
+        let mut restored: ManagedLruCache<i32, String> =
+            ManagedLruCache::unbounded(watermark_sequence, MetricsInfo::for_test());
+        restored.put(42, "stale".to_string()); // replaced by the restore
+        restored.restore(snapshot);
+
+        // Membership and size accounting match the original.
+        assert_eq!(restored.len(), cache.len());
+        assert!(!restored.contains(&42));
+        for k in 0..8 {
+            assert_eq!(restored.peek(&k), cache.peek(&k));
+        }
+        assert_eq!(restored.heap_size(), cache.heap_size());
+        // The restore reported the usage once, bypassing the hysteresis.
+        assert_eq!(restored.reporter.metrics.get(), restored.heap_size() as i64);
+
+        // The relative LRU order survives the round trip: 1 is the eviction candidate after
+        // the `get`s above, and the MRU end is the last-touched key.
+        assert_eq!(restored.peek_lru().map(|(k, _)| *k), Some(1));
+        assert_eq!(restored.peek_mru().map(|(k, _)| *k), Some(3));
+    }
+
+    #[test]
+    fn test_weakly_sized_values() {
+        /// A value sharing data owned elsewhere, whose [`EstimateSize`] would double-count it.
+        struct SharedValue(Arc<String>);
+
+        impl EstimateSize for SharedValue {
+            fn estimated_heap_size(&self) -> usize {
+                self.0.estimated_heap_size()
+            }
+        }
+
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let shared = Arc::new("x".repeat(1 << 20));
+        let mut cache: ManagedLruCache<i32, SharedValue> = ManagedLruCache::unbounded_weakly_sized(
+            watermark_sequence.clone(),
+            MetricsInfo::for_test(),
+        );
+
+        for i in 0..64 {
+            cache.put(i, SharedValue(shared.clone()));
+        }
+        // Only the keys are charged; the shared megabyte is not counted 64 times.
+        assert!(cache.heap_size() < 1 << 20);
+
+        // Eviction keeps the accounting balanced under the same charge.
+        watermark_sequence.store(Sequence::MAX, Ordering::Relaxed);
+        cache.evict();
+        assert!(cache.is_empty());
+        assert_eq!(cache.heap_size(), 0);
+    }
+
+    #[test]
+    fn test_get_mut_const_size_skips_recompute() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let mut cache: ManagedLruCache<i32, u64> =
+            ManagedLruCache::unbounded(watermark_sequence, MetricsInfo::for_test());
+
+        for i in 0..4 {
+            cache.put(i, 0);
+        }
+        let heap_size_before = cache.heap_size();
+
+        // Mutating a const-size value reports no size change...
+        *cache.get_mut_const_size(&2).unwrap() += 1;
+        assert_eq!(cache.peek(&2), Some(&1));
+        assert_eq!(cache.heap_size(), heap_size_before);
+
+        // ...but still updates recency, like `get_mut` does.
+        assert_eq!(cache.peek_mru().map(|(k, _)| *k), Some(2));
+        assert_eq!(cache.peek_lru().map(|(k, _)| *k), Some(0));
+    }
+
+    #[test]
+    fn test_pressure_callback_fires_once_per_crossing() {
+        use std::sync::atomic::AtomicUsize;
+
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        // A flat charge per entry makes the crossing point exact.
+        let mut cache: ManagedLruCache<i32, String> = ManagedLruCache::unbounded_with_size_fn(
+            watermark_sequence,
+            MetricsInfo::for_test(),
+            |_k, _v| 1000,
+        );
+
+        let fired = Arc::new(AtomicUsize::new(0));
+        let fired_in_callback = fired.clone();
+        cache.set_pressure_callback(2500, move |_cache| {
+            fired_in_callback.fetch_add(1, Ordering::Relaxed);
+        });
+
+        // The cap is not exceeded until the third entry.
+        cache.put(1, "x".to_string());
+        cache.put(2, "x".to_string());
+        assert_eq!(fired.load(Ordering::Relaxed), 0);
+        cache.put(3, "x".to_string());
+        assert_eq!(fired.load(Ordering::Relaxed), 1);
+
+        // Staying above the cap does not re-fire.
+        cache.put(4, "x".to_string());
+        cache.put(5, "x".to_string());
+        assert_eq!(fired.load(Ordering::Relaxed), 1);
+
+        // Dropping back below the cap re-arms the callback for the next crossing.
+        cache.clear();
+        cache.put(1, "x".to_string());
+        assert_eq!(fired.load(Ordering::Relaxed), 1);
+        cache.put(2, "x".to_string());
+        cache.put(3, "x".to_string());
+        assert_eq!(fired.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_pressure_callback_can_evict() {
+        use std::sync::atomic::AtomicUsize;
+
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let mut cache: ManagedLruCache<i32, String> = ManagedLruCache::unbounded_with_size_fn(
+            watermark_sequence.clone(),
+            MetricsInfo::for_test(),
+            |_k, _v| 1000,
+        );
+        // Every entry is immediately evictable.
+        watermark_sequence.store(Sequence::MAX, Ordering::Relaxed);
+
+        let fired = Arc::new(AtomicUsize::new(0));
+        let fired_in_callback = fired.clone();
+        // The callback is handed the cache with no internal borrow held, so it can evict.
+        cache.set_pressure_callback(2500, move |cache| {
+            fired_in_callback.fetch_add(1, Ordering::Relaxed);
+            cache.evict();
+        });
+
+        for i in 0..3 {
+            cache.put(i, "x".to_string());
+        }
+        // The third put crossed the cap; the callback drained the cache on the spot.
+        assert_eq!(fired.load(Ordering::Relaxed), 1);
+        assert!(cache.is_empty());
+        assert_eq!(cache.heap_size(), 0);
+
+        // Having shed the load, the callback is re-armed for the next crossing.
+        for i in 0..3 {
+            cache.put(i, "x".to_string());
+        }
+        assert_eq!(fired.load(Ordering::Relaxed), 2);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_get_mut_no_update_keeps_recency() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let mut cache: ManagedLruCache<i32, String> =
+            ManagedLruCache::unbounded(watermark_sequence, MetricsInfo::for_test());
+
+        for i in 0..4 {
+            cache.put(i, "x".repeat(64));
+        }
+
+        // Mutating through the guard updates the size accounting...
+        let heap_size_before = cache.heap_size();
+        {
+            let mut entry = cache.get_mut_no_update(&0).unwrap();
+            entry.push_str(&"y".repeat(256));
+        }
+        assert!(cache.heap_size() > heap_size_before);
+
+        // ... but the recency order is unchanged: 0 is still the eviction candidate, unlike
+        // after a `get_mut`.
+        assert_eq!(cache.peek_lru().map(|(k, _)| *k), Some(0));
+        assert_eq!(cache.peek_mru().map(|(k, _)| *k), Some(3));
+        cache.get_mut(&0).unwrap();
+        assert_eq!(cache.peek_lru().map(|(k, _)| *k), Some(1));
+        assert_eq!(cache.peek_mru().map(|(k, _)| *k), Some(0));
+    }
+
+    #[test]
+    fn test_shrink_to() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        let mut cache: ManagedLruCache<i32, String> =
+            ManagedLruCache::unbounded(watermark_sequence, MetricsInfo::for_test());
+
+        for i in 0..1024 {
+            cache.put(i, "x".repeat(64));
+        }
+        // Make 0 recently used so the survivors are not just the tail of the insertion order.
+        cache.get(&0);
+        let capacity_grown = cache.inner.capacity();
+        let heap_size_grown = cache.heap_size();
+
+        cache.shrink_to(16);
+
+        // Entry count, reported size and table capacity all dropped.
+        assert_eq!(cache.len(), 16);
+        assert!(cache.heap_size() < heap_size_grown);
+        assert!(cache.inner.capacity() < capacity_grown);
+        // The survivors are the MRU end: the touched key plus the last inserted ones.
+        assert!(cache.contains(&0));
+        assert!((1009..1024).all(|k| cache.contains(&k)));
+        // The gauge was updated immediately, bypassing the hysteresis.
+        assert_eq!(cache.reporter.metrics.get(), cache.heap_size() as i64);
+        // The freed entries count towards the eviction counters.
+        assert_eq!(cache.evicted_entry_count.get(), 1024 - 16);
+
+        // Already at or below the target: a no-op for the entries.
+        cache.shrink_to(16);
+        assert_eq!(cache.len(), 16);
+
+        // Shrinking to zero drains the cache and zeroes the accounting.
+        cache.shrink_to(0);
+        assert!(cache.is_empty());
+        assert_eq!(cache.heap_size(), 0);
+        assert_eq!(cache.reporter.metrics.get(), 0);
+    }
+
+    #[test]
+    fn test_custom_size_fn() {
+        let watermark_sequence = Arc::new(AtomicSequence::new(0));
+        // Charge a flat 1000 bytes per entry regardless of the value contents.
+        let mut cache: ManagedLruCache<i32, String> = ManagedLruCache::unbounded_with_size_fn(
+            watermark_sequence,
+            MetricsInfo::for_test(),
+            |_k, _v| 1000,
+        );
+
+        cache.put(1, "x".repeat(8192));
+        cache.put(2, "y".repeat(8192));
+        assert_eq!(cache.heap_size(), 2000);
+
+        // Overwriting an entry keeps the accounting balanced.
+        cache.put(1, "z".repeat(16384));
+        assert_eq!(cache.heap_size(), 2000);
+
+        // Mutation through the guard re-measures with the closure, not `EstimateSize`.
+        let key = 2;
+        {
+            let mut entry = cache.get_mut(&key).unwrap();
+            entry.push_str("more");
+        }
+        assert_eq!(cache.heap_size(), 2000);
+    }
+}
@@ -181,6 +181,8 @@ pub struct StreamingMetrics {
     pub jvm_allocated_bytes: IntGauge,
     pub jvm_active_bytes: IntGauge,
     pub stream_memory_usage: RelabeledGuardedIntGaugeVec<3>,
+    pub lru_evicted_entry_count: RelabeledGuardedIntCounterVec<3>,
+    pub lru_evicted_bytes: RelabeledGuardedIntCounterVec<3>,
 
     // Materialized view
     materialize_cache_hit_count: RelabeledGuardedIntCounterVec<3>,
@@ -989,6 +991,24 @@ impl StreamingMetrics {
         .unwrap()
         .relabel_debug_1(level);
 
+        let lru_evicted_entry_count = register_guarded_int_counter_vec_with_registry!(
+            "stream_lru_evicted_entry_count",
+            "Cumulative number of entries evicted from managed LRU caches",
+            &["actor_id", "table_id", "desc"],
+            registry
+        )
+        .unwrap()
+        .relabel_debug_1(level);
+
+        let lru_evicted_bytes = register_guarded_int_counter_vec_with_registry!(
+            "stream_lru_evicted_bytes",
+            "Cumulative estimated bytes freed by evictions from managed LRU caches",
+            &["actor_id", "table_id", "desc"],
+            registry
+        )
+        .unwrap()
+        .relabel_debug_1(level);
+
         Self {
             level,
             executor_row_count,
@@ -1082,6 +1102,8 @@ impl StreamingMetrics {
             jvm_allocated_bytes,
             jvm_active_bytes,
             stream_memory_usage,
+            lru_evicted_entry_count,
+            lru_evicted_bytes,
             materialize_cache_hit_count,
             materialize_cache_total_count,
             materialize_input_row_count,